@@ -0,0 +1,25 @@
+-- Table Definition
+CREATE TABLE "audit_log" (
+    "auditlogid" BIGSERIAL NOT NULL,
+    "actor" varchar NOT NULL,
+    "resource" varchar NOT NULL,
+    "resourceid" int4 NOT NULL,
+    "action" varchar NOT NULL,
+    "before" jsonb,
+    "after" jsonb,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY ("auditlogid")
+);
+
+CREATE INDEX "audit_log_resource_idx" ON "audit_log" ("resource", "resourceid");
+CREATE INDEX "audit_log_actor_idx" ON "audit_log" ("actor");
+
+-- Column Comment
+COMMENT ON COLUMN "audit_log"."auditlogid" IS 'Primary key';
+COMMENT ON COLUMN "audit_log"."actor" IS 'Who made the change, e.g. api_key:<name> or jwt:<sub>';
+COMMENT ON COLUMN "audit_log"."resource" IS 'Resource type, e.g. event or city';
+COMMENT ON COLUMN "audit_log"."resourceid" IS 'Id of the changed resource';
+COMMENT ON COLUMN "audit_log"."action" IS 'create, update or delete';
+COMMENT ON COLUMN "audit_log"."before" IS 'Resource before the change';
+COMMENT ON COLUMN "audit_log"."after" IS 'Resource after the change';
+COMMENT ON COLUMN "audit_log"."created_at" IS 'Creation timestamp';
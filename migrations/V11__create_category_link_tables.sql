@@ -0,0 +1,38 @@
+-- Table Definition
+CREATE TABLE "event_category" (
+    "eventid" INTEGER NOT NULL REFERENCES "event" ("eventid") ON DELETE CASCADE,
+    "categoryid" INTEGER NOT NULL REFERENCES "category" ("id") ON DELETE CASCADE,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY ("eventid", "categoryid")
+);
+
+CREATE INDEX "event_category_categoryid_idx" ON "event_category" ("categoryid");
+
+CREATE TABLE "group_category" (
+    "groupid" INTEGER NOT NULL REFERENCES "group" ("groupid") ON DELETE CASCADE,
+    "categoryid" INTEGER NOT NULL REFERENCES "category" ("id") ON DELETE CASCADE,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY ("groupid", "categoryid")
+);
+
+CREATE INDEX "group_category_categoryid_idx" ON "group_category" ("categoryid");
+
+CREATE TABLE "article_category" (
+    "articleid" INTEGER NOT NULL REFERENCES "article" ("articleid") ON DELETE CASCADE,
+    "categoryid" INTEGER NOT NULL REFERENCES "category" ("id") ON DELETE CASCADE,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY ("articleid", "categoryid")
+);
+
+CREATE INDEX "article_category_categoryid_idx" ON "article_category" ("categoryid");
+
+-- Column Comment
+COMMENT ON COLUMN "event_category"."eventid" IS 'Event ID';
+COMMENT ON COLUMN "event_category"."categoryid" IS 'Category ID';
+COMMENT ON COLUMN "event_category"."created_at" IS 'Creation timestamp';
+COMMENT ON COLUMN "group_category"."groupid" IS 'Group ID';
+COMMENT ON COLUMN "group_category"."categoryid" IS 'Category ID';
+COMMENT ON COLUMN "group_category"."created_at" IS 'Creation timestamp';
+COMMENT ON COLUMN "article_category"."articleid" IS 'Article ID';
+COMMENT ON COLUMN "article_category"."categoryid" IS 'Category ID';
+COMMENT ON COLUMN "article_category"."created_at" IS 'Creation timestamp';
@@ -0,0 +1,36 @@
+-- Table Definition
+ALTER TABLE "category"
+    ADD COLUMN "parent_id" INTEGER REFERENCES "category" ("id") ON DELETE SET NULL,
+    ADD COLUMN "slug" varchar(100);
+
+CREATE INDEX "category_parent_id_idx" ON "category" ("parent_id");
+
+-- Backfill slugs from names, suffixing the id when two names collide
+UPDATE "category" c
+SET "slug" = s.slug
+FROM (
+    SELECT
+        id,
+        base || CASE WHEN row_number() OVER (PARTITION BY base ORDER BY id) > 1 THEN '-' || id ELSE '' END AS slug
+    FROM (
+        SELECT
+            id,
+            coalesce(nullif(btrim(regexp_replace(lower(name), '[^a-z0-9]+', '-', 'g'), '-'), ''), 'category') AS base
+        FROM
+            "category"
+    ) b
+) s
+WHERE
+    c.id = s.id;
+
+ALTER TABLE "category"
+    ALTER COLUMN "slug" SET NOT NULL,
+    ADD CONSTRAINT "category_slug_key" UNIQUE ("slug");
+
+UPDATE "category" SET "is_active" = true WHERE "is_active" IS NULL;
+
+ALTER TABLE "category" ALTER COLUMN "is_active" SET NOT NULL;
+
+-- Column Comment
+COMMENT ON COLUMN "category"."parent_id" IS 'Parent category ID';
+COMMENT ON COLUMN "category"."slug" IS 'Unique URL slug';
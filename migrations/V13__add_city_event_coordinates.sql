@@ -0,0 +1,20 @@
+-- Table Definition
+ALTER TABLE "city"
+    ADD COLUMN "latitude" double precision,
+    ADD COLUMN "longitude" double precision,
+    ADD CONSTRAINT "city_latitude_check" CHECK ("latitude" BETWEEN -90 AND 90),
+    ADD CONSTRAINT "city_longitude_check" CHECK ("longitude" BETWEEN -180 AND 180),
+    ADD CONSTRAINT "city_coordinates_check" CHECK (("latitude" IS NULL) = ("longitude" IS NULL));
+
+ALTER TABLE "event"
+    ADD COLUMN "latitude" double precision,
+    ADD COLUMN "longitude" double precision,
+    ADD CONSTRAINT "event_latitude_check" CHECK ("latitude" BETWEEN -90 AND 90),
+    ADD CONSTRAINT "event_longitude_check" CHECK ("longitude" BETWEEN -180 AND 180),
+    ADD CONSTRAINT "event_coordinates_check" CHECK (("latitude" IS NULL) = ("longitude" IS NULL));
+
+-- Column Comment
+COMMENT ON COLUMN "city"."latitude" IS 'Latitude in decimal degrees (WGS 84)';
+COMMENT ON COLUMN "city"."longitude" IS 'Longitude in decimal degrees (WGS 84)';
+COMMENT ON COLUMN "event"."latitude" IS 'Latitude in decimal degrees (WGS 84)';
+COMMENT ON COLUMN "event"."longitude" IS 'Longitude in decimal degrees (WGS 84)';
@@ -0,0 +1,42 @@
+-- Table Definition
+ALTER TABLE "city" ADD COLUMN "time_zone" varchar(64);
+
+-- Backfill from the province; cities straddling a zone boundary can be corrected by hand
+UPDATE "city" c
+SET "time_zone" = CASE lower(s.symbol)
+    WHEN 'bc' THEN 'America/Vancouver'
+    WHEN 'ab' THEN 'America/Edmonton'
+    WHEN 'sk' THEN 'America/Regina'
+    WHEN 'mb' THEN 'America/Winnipeg'
+    WHEN 'on' THEN 'America/Toronto'
+    WHEN 'qc' THEN 'America/Toronto'
+    WHEN 'nb' THEN 'America/Moncton'
+    WHEN 'ns' THEN 'America/Halifax'
+    WHEN 'pe' THEN 'America/Halifax'
+    WHEN 'nl' THEN 'America/St_Johns'
+    WHEN 'yt' THEN 'America/Whitehorse'
+    WHEN 'nt' THEN 'America/Edmonton'
+    WHEN 'nu' THEN 'America/Iqaluit'
+END
+FROM "state" s
+WHERE
+    s.stateid = c.stateid;
+
+-- Stop rather than leave a city to fall back to UTC
+DO $$
+DECLARE
+    unmapped text;
+BEGIN
+    SELECT string_agg(DISTINCT coalesce(s.symbol, '(none)'), ', ')
+    INTO unmapped
+    FROM "city" c
+    LEFT JOIN "state" s ON s.stateid = c.stateid
+    WHERE c."time_zone" IS NULL;
+
+    IF unmapped IS NOT NULL THEN
+        RAISE EXCEPTION 'No time zone for the cities of province(s) %', unmapped;
+    END IF;
+END $$;
+
+-- Column Comment
+COMMENT ON COLUMN "city"."time_zone" IS 'IANA time zone name, e.g. America/Toronto';
@@ -0,0 +1,44 @@
+-- Table Definition
+CREATE TABLE "event_series" (
+    "seriesid" SERIAL NOT NULL,
+    "eventid" INTEGER NOT NULL UNIQUE REFERENCES "event"("eventid") ON DELETE CASCADE,
+    "rrule" varchar NOT NULL,
+    "exdates" timestamptz[] NOT NULL DEFAULT '{}',
+    "time_zone" varchar(64) NOT NULL DEFAULT 'UTC',
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    "updated_at" timestamptz,
+    PRIMARY KEY ("seriesid")
+);
+
+CREATE TABLE "event_occurrence" (
+    "seriesid" INTEGER NOT NULL REFERENCES "event_series"("seriesid") ON DELETE CASCADE,
+    "occurrence" timestamptz NOT NULL,
+    "cancelled" bool NOT NULL DEFAULT false,
+    "time" timestamptz,
+    "duration" int4,
+    "name" varchar,
+    "description" varchar,
+    "location" varchar,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    "updated_at" timestamptz,
+    PRIMARY KEY ("seriesid", "occurrence")
+);
+
+-- Column Comment
+COMMENT ON COLUMN "event_series"."seriesid" IS 'Primary key';
+COMMENT ON COLUMN "event_series"."eventid" IS 'Event holding the details and first occurrence of the series';
+COMMENT ON COLUMN "event_series"."rrule" IS 'RFC 5545 recurrence rule, e.g. FREQ=WEEKLY;BYDAY=TU';
+COMMENT ON COLUMN "event_series"."exdates" IS 'Occurrence starts excluded from the series';
+COMMENT ON COLUMN "event_series"."time_zone" IS 'IANA time zone the rule is expanded in';
+COMMENT ON COLUMN "event_series"."created_at" IS 'Creation timestamp';
+COMMENT ON COLUMN "event_series"."updated_at" IS 'Last update timestamp';
+COMMENT ON COLUMN "event_occurrence"."seriesid" IS 'Series the occurrence belongs to';
+COMMENT ON COLUMN "event_occurrence"."occurrence" IS 'Start of the occurrence as generated by the rule';
+COMMENT ON COLUMN "event_occurrence"."cancelled" IS 'Occurrence is cancelled';
+COMMENT ON COLUMN "event_occurrence"."time" IS 'Rescheduled start time';
+COMMENT ON COLUMN "event_occurrence"."duration" IS 'Overridden duration';
+COMMENT ON COLUMN "event_occurrence"."name" IS 'Overridden title';
+COMMENT ON COLUMN "event_occurrence"."description" IS 'Overridden description';
+COMMENT ON COLUMN "event_occurrence"."location" IS 'Overridden location';
+COMMENT ON COLUMN "event_occurrence"."created_at" IS 'Creation timestamp';
+COMMENT ON COLUMN "event_occurrence"."updated_at" IS 'Last update timestamp';
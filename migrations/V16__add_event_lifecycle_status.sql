@@ -0,0 +1,14 @@
+-- Table Definition
+ALTER TABLE "event"
+    ADD COLUMN "lifecycle_status" varchar(16) NOT NULL DEFAULT 'scheduled',
+    ADD COLUMN "lifecycle_reason" varchar(512),
+    ADD COLUMN "rescheduled_to" int4 REFERENCES "event"("eventid") ON DELETE SET NULL,
+    ADD CONSTRAINT "event_lifecycle_status_check" CHECK ("lifecycle_status" IN ('scheduled', 'cancelled', 'postponed', 'rescheduled')),
+    ADD CONSTRAINT "event_rescheduled_to_check" CHECK ("rescheduled_to" IS NULL OR "lifecycle_status" = 'rescheduled');
+
+CREATE INDEX "event_lifecycle_status_idx" ON "event" ("lifecycle_status");
+
+-- Column Comment
+COMMENT ON COLUMN "event"."lifecycle_status" IS 'scheduled, cancelled, postponed or rescheduled';
+COMMENT ON COLUMN "event"."lifecycle_reason" IS 'Reason given when the event was cancelled, postponed or rescheduled';
+COMMENT ON COLUMN "event"."rescheduled_to" IS 'Event replacing a rescheduled event';
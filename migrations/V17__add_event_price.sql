@@ -0,0 +1,18 @@
+-- Table Definition
+ALTER TABLE "event"
+    ADD COLUMN "price_min" int4,
+    ADD COLUMN "price_max" int4,
+    ADD COLUMN "currency" char(3) NOT NULL DEFAULT 'CAD',
+    ADD COLUMN "ticket_url" varchar(512),
+    ADD CONSTRAINT "event_price_min_check" CHECK ("price_min" IS NULL OR "price_min" >= 0),
+    ADD CONSTRAINT "event_price_max_check" CHECK ("price_max" IS NULL OR ("price_min" IS NOT NULL AND "price_max" >= "price_min"));
+
+UPDATE "event" SET "price_min" = 0, "price_max" = 0 WHERE "fee" = false;
+
+CREATE INDEX "event_price_min_idx" ON "event" ("price_min");
+
+-- Column Comment
+COMMENT ON COLUMN "event"."price_min" IS 'Lowest ticket price in cents of currency, 0 for free events';
+COMMENT ON COLUMN "event"."price_max" IS 'Highest ticket price in cents of currency';
+COMMENT ON COLUMN "event"."currency" IS 'ISO 4217 currency code of the prices';
+COMMENT ON COLUMN "event"."ticket_url" IS 'Page where tickets are sold';
@@ -0,0 +1,20 @@
+-- Extensions
+CREATE EXTENSION IF NOT EXISTS unaccent;
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- unaccent() is only stable as its dictionary can be swapped; pinning the
+-- dictionary makes it immutable so expressions using it can be indexed
+CREATE OR REPLACE FUNCTION f_unaccent(text) RETURNS text
+    LANGUAGE sql IMMUTABLE PARALLEL SAFE STRICT
+    AS $$ SELECT public.unaccent('public.unaccent'::regdictionary, $1) $$;
+
+-- Trigram indexes serving the case and accent insensitive name filters
+CREATE INDEX "event_name_trgm_idx" ON "event" USING gin (f_unaccent("name") gin_trgm_ops);
+CREATE INDEX "group_name_trgm_idx" ON "group" USING gin (f_unaccent("name") gin_trgm_ops);
+CREATE INDEX "city_name_trgm_idx" ON "city" USING gin (f_unaccent("name") gin_trgm_ops);
+CREATE INDEX "article_name_trgm_idx" ON "article" USING gin (f_unaccent("name") gin_trgm_ops);
+CREATE INDEX "state_name_trgm_idx" ON "state" USING gin (f_unaccent("name") gin_trgm_ops);
+CREATE INDEX "category_name_trgm_idx" ON "category" USING gin (f_unaccent("name") gin_trgm_ops);
+
+-- Function Comment
+COMMENT ON FUNCTION f_unaccent(text) IS 'Immutable unaccent() for name indexes and filters';
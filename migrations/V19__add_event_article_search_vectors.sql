@@ -0,0 +1,32 @@
+-- Table Definition
+-- Names weigh more than descriptions when ranking matches
+ALTER TABLE "event"
+    ADD COLUMN "search_en" tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', "name"), 'A')
+        || setweight(to_tsvector('english', coalesce("description", '')), 'B')
+    ) STORED,
+    ADD COLUMN "search_fr" tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('french', "name"), 'A')
+        || setweight(to_tsvector('french', coalesce("description", '')), 'B')
+    ) STORED;
+
+ALTER TABLE "article"
+    ADD COLUMN "search_en" tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', "name"), 'A')
+        || setweight(to_tsvector('english', "description"), 'B')
+    ) STORED,
+    ADD COLUMN "search_fr" tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('french', "name"), 'A')
+        || setweight(to_tsvector('french', "description"), 'B')
+    ) STORED;
+
+CREATE INDEX "event_search_en_idx" ON "event" USING gin ("search_en");
+CREATE INDEX "event_search_fr_idx" ON "event" USING gin ("search_fr");
+CREATE INDEX "article_search_en_idx" ON "article" USING gin ("search_en");
+CREATE INDEX "article_search_fr_idx" ON "article" USING gin ("search_fr");
+
+-- Column Comment
+COMMENT ON COLUMN "event"."search_en" IS 'Name and description parsed for English full-text search';
+COMMENT ON COLUMN "event"."search_fr" IS 'Name and description parsed for French full-text search';
+COMMENT ON COLUMN "article"."search_en" IS 'Name and description parsed for English full-text search';
+COMMENT ON COLUMN "article"."search_fr" IS 'Name and description parsed for French full-text search';
@@ -0,0 +1,53 @@
+-- Table Definition
+CREATE TABLE "group_organizer" (
+    "groupid" INTEGER NOT NULL REFERENCES "group" ("groupid") ON DELETE CASCADE,
+    "organizerid" INTEGER NOT NULL REFERENCES "organizer" ("organizerid") ON DELETE CASCADE,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY ("groupid", "organizerid")
+);
+
+CREATE INDEX "group_organizer_organizerid_idx" ON "group_organizer" ("organizerid");
+
+-- Column Comment
+COMMENT ON COLUMN "group_organizer"."groupid" IS 'Group ID';
+COMMENT ON COLUMN "group_organizer"."organizerid" IS 'Organizer ID';
+COMMENT ON COLUMN "group_organizer"."created_at" IS 'Creation timestamp';
+
+-- Backfill organizers from the free-text column, which may list several
+-- co-organizers separated by ",", ";", "&" or "and". Each name gets its own
+-- organizer keyed by extid, since names are not unique
+CREATE TEMPORARY TABLE "group_organizer_name" AS
+SELECT DISTINCT
+    g.groupid,
+    btrim(n.name) AS name
+FROM
+    "group" g
+    CROSS JOIN LATERAL regexp_split_to_table(g.organizer, '\s*(,|;|&|\mand\M)\s*', 'i') AS n(name)
+WHERE
+    btrim(n.name) <> '';
+
+INSERT INTO "organizer" ("firstname", "lastname", "bio", "extid")
+SELECT DISTINCT
+    split_part(gon.name, ' ', 1),
+    btrim(substr(gon.name, length(split_part(gon.name, ' ', 1)) + 1)),
+    '',
+    'group-organizer-' || md5(gon.name)
+FROM
+    "group_organizer_name" gon
+WHERE
+    NOT EXISTS (
+        SELECT 1 FROM "organizer" o
+        WHERE o.extid = 'group-organizer-' || md5(gon.name)
+    );
+
+INSERT INTO "group_organizer" ("groupid", "organizerid")
+SELECT DISTINCT
+    gon.groupid,
+    o.organizerid
+FROM
+    "group_organizer_name" gon
+    JOIN "organizer" o ON o.extid = 'group-organizer-' || md5(gon.name);
+
+DROP TABLE "group_organizer_name";
+
+ALTER TABLE "group" DROP COLUMN "organizer";
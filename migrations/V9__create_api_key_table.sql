@@ -0,0 +1,18 @@
+-- Table Definition
+CREATE TABLE "api_key" (
+    "apikeyid" SERIAL NOT NULL,
+    "name" varchar NOT NULL,
+    "key_hash" varchar NOT NULL UNIQUE,
+    "active" bool NOT NULL DEFAULT true,
+    "created_at" timestamptz NOT NULL DEFAULT now(),
+    "updated_at" timestamptz,
+    PRIMARY KEY ("apikeyid")
+);
+
+-- Column Comment
+COMMENT ON COLUMN "api_key"."apikeyid" IS 'Primary key';
+COMMENT ON COLUMN "api_key"."name" IS 'Key owner description';
+COMMENT ON COLUMN "api_key"."key_hash" IS 'Hex encoded SHA-256 of the raw key';
+COMMENT ON COLUMN "api_key"."active" IS 'Active status';
+COMMENT ON COLUMN "api_key"."created_at" IS 'Creation timestamp';
+COMMENT ON COLUMN "api_key"."updated_at" IS 'Last update timestamp';
@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use std::env;
+use std::{env, net::IpAddr};
 
 lazy_static! {
     static ref CONFIG: Config = Config::from_env();
@@ -14,6 +14,18 @@ pub struct Config {
     pub web_addr: String,
     pub page_size_default: u32,
     pub page_size_max: u32,
+    pub jwt_algorithm: String,
+    pub jwt_secret: Option<String>,
+    pub jwt_public_key_file: Option<String>,
+    pub rate_limit_window_seconds: u64,
+    pub rate_limit_routes: Vec<(String, u32)>,
+    pub rate_limit_trusted_proxies: Vec<IpAddr>,
+    pub cors_read_origins: Vec<String>,
+    pub cors_write_origins: Vec<String>,
+    pub cors_allowed_methods: Vec<String>,
+    pub cors_allowed_headers: Vec<String>,
+    pub cors_max_age: usize,
+    pub ics_uid_domain: String,
 }
 
 impl Config {
@@ -28,6 +40,78 @@ impl Config {
                 .expect("PAGE_SIZE_MAX must be set")
                 .parse::<u32>()
                 .expect("PAGE_SIZE_MAX must be u32"),
+            jwt_algorithm: env::var("JWT_ALGORITHM").unwrap_or_else(|_| String::from("HS256")),
+            jwt_secret: env::var("JWT_SECRET").ok(),
+            jwt_public_key_file: env::var("JWT_PUBLIC_KEY_FILE").ok(),
+            rate_limit_window_seconds: env::var("RATE_LIMIT_WINDOW_SECONDS")
+                .map(|value| {
+                    value
+                        .parse()
+                        .expect("RATE_LIMIT_WINDOW_SECONDS must be u64")
+                })
+                .unwrap_or(60),
+            rate_limit_routes: parse_rate_limit_routes(
+                &env::var("RATE_LIMIT_ROUTES")
+                    .unwrap_or_else(|_| String::from("/event=120,/group=120,/article=120")),
+            ),
+            rate_limit_trusted_proxies: parse_list(
+                &env::var("RATE_LIMIT_TRUSTED_PROXIES").unwrap_or_default(),
+            )
+            .iter()
+            .map(|proxy| {
+                proxy
+                    .parse()
+                    .expect("RATE_LIMIT_TRUSTED_PROXIES must be a list of IP addresses")
+            })
+            .collect(),
+            cors_read_origins: parse_list(
+                &env::var("CORS_READ_ORIGINS").unwrap_or_else(|_| String::from("*")),
+            ),
+            cors_write_origins: parse_list(
+                &env::var("CORS_WRITE_ORIGINS")
+                    .unwrap_or_else(|_| String::from("https://dataroot.ca,https://*.dataroot.ca")),
+            ),
+            cors_allowed_methods: parse_list(
+                &env::var("CORS_ALLOWED_METHODS")
+                    .unwrap_or_else(|_| String::from("GET,HEAD,POST,PUT,DELETE,OPTIONS")),
+            ),
+            cors_allowed_headers: parse_list(
+                &env::var("CORS_ALLOWED_HEADERS")
+                    .unwrap_or_else(|_| String::from("Authorization,Content-Type,X-API-Key,X-Request-Id")),
+            ),
+            cors_max_age: env::var("CORS_MAX_AGE")
+                .map(|value| value.parse().expect("CORS_MAX_AGE must be usize"))
+                .unwrap_or(3600),
+            ics_uid_domain: env::var("ICS_UID_DOMAIN").unwrap_or_else(|_| String::from("dataroot.ca")),
         }
     }
 }
+
+/// Parses a comma separated list, ignoring blank entries.
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Parses `RATE_LIMIT_ROUTES`, a comma separated list of `<path prefix>=<limit>`.
+fn parse_rate_limit_routes(value: &str) -> Vec<(String, u32)> {
+    parse_list(value)
+        .iter()
+        .map(|route| {
+            let (path, limit) = route
+                .split_once('=')
+                .expect("RATE_LIMIT_ROUTES must be a list of <path>=<limit>");
+            let limit = limit
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|limit| *limit > 0)
+                .expect("RATE_LIMIT_ROUTES limits must be a u32 greater than 0");
+            (path.trim().to_owned(), limit)
+        })
+        .collect()
+}
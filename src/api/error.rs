@@ -2,16 +2,19 @@ use actix_web::{http::StatusCode, HttpResponse};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::error::DomainError;
+use crate::{api::middleware::request_id, domain::error::DomainError};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
     pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 impl ErrorResponse {
     pub fn new(message: &str) -> Self {
         Self {
             message: message.to_owned(),
+            request_id: request_id::current(),
         }
     }
 }
@@ -23,6 +26,12 @@ impl actix_web::error::ResponseError for DomainError {
             DomainError::BadRequest(msg) => {
                 HttpResponse::BadRequest().json(ErrorResponse::new(msg))
             }
+
+            DomainError::Unauthorized(msg) => {
+                HttpResponse::Unauthorized().json(ErrorResponse::new(msg))
+            }
+
+            DomainError::Forbidden(msg) => HttpResponse::Forbidden().json(ErrorResponse::new(msg)),
             err => {
                 log::error!("{}", err);
                 HttpResponse::InternalServerError()
@@ -35,6 +44,8 @@ impl actix_web::error::ResponseError for DomainError {
         match self {
             DomainError::NotFound(_) => StatusCode::NOT_FOUND,
             DomainError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -7,27 +7,57 @@ use actix_web::{
 use deadpool_postgres::Pool;
 use redis::Client;
 use serde_qs::actix::QsQueryConfig;
-use std::{error::Error, sync::Arc};
+use std::{error::Error, io::Write, sync::Arc};
 
 use crate::{
     api::{
         config,
         error::ErrorResponse,
         middleware,
-        resources::{health, swagger, categories,state,city,article,group,event},
+        resources::{health, swagger, categories,state,city,article,group,event,event_series,organizer,audit_log},
     },
-    domain::{categories::repository::CategoryRepository, health::repository::HealthRepository, state::repository::StateRepository, city::repository::CityRepository, article::repository::ArticleRepository, group::repository::GroupRepository, event::repository::EventRepository},
-    repository::{categories::PgCategoryRepository, health::PgHealthRepository,state::PgStateRepository, postgres, city::PgCityRepository, article::PgArticleRepository, event::PgEventRepository, group::PgGroupRepository},
+    domain::{api_key::{self, repository::ApiKeyRepository}, audit_log::repository::AuditLogRepository, categories::repository::CategoryRepository, health::repository::HealthRepository, state::repository::StateRepository, city::repository::CityRepository, article::repository::ArticleRepository, group::repository::GroupRepository, event::repository::EventRepository, event_series::repository::EventSeriesRepository, organizer::repository::OrganizerRepository, rate_limit::repository::RateLimitRepository},
+    repository::{api_key::PgApiKeyRepository, audit_log::PgAuditLogRepository, categories::PgCategoryRepository, health::PgHealthRepository,state::PgStateRepository, postgres, city::PgCityRepository, article::PgArticleRepository, event::PgEventRepository, event_series::PgEventSeriesRepository, group::PgGroupRepository, organizer::PgOrganizerRepository, rate_limit::RedisRateLimitRepository},
 };
 
+pub(crate) const LOG_FORMAT: &str =
+    r#"%a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T request_id=%{x-request-id}i"#;
+
 pub struct AppState {
     pub health_repository: Arc<dyn HealthRepository>,
+    pub api_key_repository: Arc<dyn ApiKeyRepository>,
     pub category_repository: Arc<dyn CategoryRepository>,
     pub state_repository: Arc<dyn StateRepository>,
     pub city_repository: Arc<dyn CityRepository>,
     pub article_repository: Arc<dyn ArticleRepository>,
     pub group_repository: Arc<dyn GroupRepository>,
     pub event_repository: Arc<dyn EventRepository>,
+    pub event_series_repository: Arc<dyn EventSeriesRepository>,
+    pub organizer_repository: Arc<dyn OrganizerRepository>,
+    pub rate_limit_repository: Arc<dyn RateLimitRepository>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+/// Initializes `env_logger`, tagging records emitted while a request is
+/// handled with its request id.
+pub fn init_logger() {
+    env_logger::Builder::from_default_env()
+        .format(|buf, record| {
+            let request_id = middleware::request_id::current()
+                .map(|request_id| format!(" request_id={request_id}"))
+                .unwrap_or_default();
+
+            writeln!(
+                buf,
+                "[{} {} {}{}] {}",
+                buf.timestamp(),
+                buf.default_styled_level(record.level()),
+                record.target(),
+                request_id,
+                record.args()
+            )
+        })
+        .init();
 }
 
 pub async fn run(pg_pool: Arc<Pool>, redis_client: Arc<Client>) -> Result<(), Box<dyn Error>> {
@@ -56,12 +86,20 @@ pub async fn run(pg_pool: Arc<Pool>, redis_client: Arc<Client>) -> Result<(), Bo
             pg_pool.clone(),
             redis_client.clone(),
         )),
+        api_key_repository: Arc::new(PgApiKeyRepository::new(
+            pg_pool.clone(),
+            redis_client.clone(),
+        )),
         category_repository: Arc::new(PgCategoryRepository::new(pg_pool.clone())),
-        state_repository: Arc::new(PgStateRepository::new(pg_pool.clone())),
+        state_repository: Arc::new(PgStateRepository::new(pg_pool.clone(), redis_client.clone())),
         city_repository: Arc::new(PgCityRepository::new(pg_pool.clone(),redis_client.clone())),
         article_repository: Arc::new(PgArticleRepository::new(pg_pool.clone())),
         group_repository: Arc::new(PgGroupRepository::new(pg_pool.clone())),
         event_repository: Arc::new(PgEventRepository::new(pg_pool.clone())),
+        event_series_repository: Arc::new(PgEventSeriesRepository::new(pg_pool.clone())),
+        organizer_repository: Arc::new(PgOrganizerRepository::new(pg_pool.clone())),
+        rate_limit_repository: Arc::new(RedisRateLimitRepository::new(redis_client.clone())),
+        audit_log_repository: Arc::new(PgAuditLogRepository::new(pg_pool.clone())),
     });
 
     let web_addr = &config::get_config().web_addr;
@@ -77,7 +115,11 @@ pub async fn run(pg_pool: Arc<Pool>, redis_client: Arc<Client>) -> Result<(), Bo
             .qs_config(serde_qs::Config::new(5, false));
 
         App::new()
-            .wrap(Logger::default())
+            .wrap(middleware::api_key::default())
+            .wrap(middleware::jwt::default())
+            .wrap(middleware::rate_limit::default())
+            .wrap(Logger::new(LOG_FORMAT))
+            .wrap(middleware::request_id::default())
             .wrap(middleware::cors::default())
             .app_data(json_config.to_owned())
             .app_data(qs_config)
@@ -92,6 +134,9 @@ pub async fn run(pg_pool: Arc<Pool>, redis_client: Arc<Client>) -> Result<(), Bo
             .configure(article::routes::init_routes)
             .configure(group::routes::init_routes)
             .configure(event::routes::init_routes)
+            .configure(event_series::routes::init_routes)
+            .configure(organizer::routes::init_routes)
+            .configure(audit_log::routes::init_routes)
     })
     .bind(web_addr)?
     .run()
@@ -99,3 +144,19 @@ pub async fn run(pg_pool: Arc<Pool>, redis_client: Arc<Client>) -> Result<(), Bo
 
     Ok(())
 }
+
+pub async fn create_api_key(
+    pg_pool: Arc<Pool>,
+    redis_client: Arc<Client>,
+    name: String,
+) -> Result<String, Box<dyn Error>> {
+    postgres::run_migrations().await?;
+
+    let (_, key) = api_key::resources::create::execute(
+        Arc::new(PgApiKeyRepository::new(pg_pool, redis_client)),
+        name,
+    )
+    .await?;
+
+    Ok(key)
+}
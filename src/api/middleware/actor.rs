@@ -0,0 +1,27 @@
+use std::{
+    convert::Infallible,
+    future::{ready, Ready},
+};
+
+use actix_web::{dev::Payload, FromRequest, HttpMessage, HttpRequest};
+
+/// Who is making the request, as stored in the request extensions by the
+/// api_key (`api_key:<name>`) or jwt (`jwt:<sub>`) middleware. Recorded in
+/// the audit log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Actor(pub String);
+
+impl FromRequest for Actor {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let actor = req
+            .extensions()
+            .get::<Actor>()
+            .cloned()
+            .unwrap_or_else(|| Actor(String::from("anonymous")));
+
+        ready(Ok(actor))
+    }
+}
@@ -0,0 +1,266 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::Method,
+    web::Data,
+    Error, HttpMessage, ResponseError,
+};
+use futures::future::LocalBoxFuture;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::{actor::Actor, role::Role},
+    },
+    domain::{api_key, error::DomainError},
+};
+
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+/// Requires a valid `X-API-Key` header on every request that is not a
+/// GET, HEAD or OPTIONS, unless the jwt middleware already authenticated it.
+/// Read requests are only checked when they carry a key. A valid key is
+/// granted the admin role.
+pub fn default() -> ApiKeyAuth {
+    ApiKeyAuth
+}
+
+pub struct ApiKeyAuth;
+
+impl<S, B> Transform<S, ServiceRequest> for ApiKeyAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = ApiKeyAuthMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(ApiKeyAuthMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct ApiKeyAuthMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for ApiKeyAuthMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        let key = req
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_owned());
+
+        if (key.is_none() && matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS))
+            || req.extensions().contains::<Role>()
+        {
+            return Box::pin(async move {
+                service.call(req).await.map(|res| res.map_into_left_body())
+            });
+        }
+
+        let app_state = req.app_data::<Data<AppState>>().cloned();
+
+        Box::pin(async move {
+            let result = match app_state {
+                Some(app_state) => {
+                    api_key::resources::authenticate::execute(
+                        app_state.api_key_repository.clone(),
+                        key,
+                    )
+                    .await
+                }
+                None => Err(DomainError::InternalServerError(String::from(
+                    "AppState is not configured",
+                ))),
+            };
+
+            match result {
+                Ok(api_key) => {
+                    req.extensions_mut().insert(Role::Admin);
+                    req.extensions_mut()
+                        .insert(Actor(format!("api_key:{}", api_key.name)));
+                    service.call(req).await.map(|res| res.map_into_left_body())
+                }
+                Err(err) => Ok(req.into_response(err.error_response().map_into_right_body())),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::{
+        api::{
+            lib::AppState,
+            middleware,
+            resources::organizer::{dto, routes::init_routes},
+            tests::utils::get_repositories,
+            utils::random_string,
+        },
+        domain::api_key::{model::ApiKeyCreateModel, repository::ApiKeyRepository},
+    };
+
+    use super::API_KEY_HEADER;
+
+    #[actix_web::test]
+    async fn it_should_allow_get_without_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/organizer").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_on_get_with_unknown_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .insert_header((API_KEY_HEADER, random_string(32)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_without_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .set_json(dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_with_unknown_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .insert_header((API_KEY_HEADER, random_string(32)))
+            .set_json(dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_forbidden_with_disabled_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        //Seed
+        let key = random_string(32);
+        repositories
+            .api_key_repository
+            .insert(&ApiKeyCreateModel::new(random_string(10), &key, false))
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .insert_header((API_KEY_HEADER, key))
+            .set_json(dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn it_should_pass_with_valid_api_key() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        //Seed
+        let key = random_string(32);
+        repositories
+            .api_key_repository
+            .insert(&ApiKeyCreateModel::new(random_string(10), &key, true))
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .insert_header((API_KEY_HEADER, key))
+            .set_json(dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+    }
+}
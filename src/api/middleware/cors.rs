@@ -1,5 +1,226 @@
+use std::rc::Rc;
+
 use actix_cors::Cors;
+use actix_web::http::{
+    header::{ACCESS_CONTROL_REQUEST_METHOD, RETRY_AFTER},
+    Method,
+};
+
+use crate::api::{
+    config,
+    middleware::{
+        rate_limit::{
+            RATE_LIMIT_LIMIT_HEADER, RATE_LIMIT_REMAINING_HEADER, RATE_LIMIT_RESET_HEADER,
+        },
+        request_id::REQUEST_ID_HEADER,
+    },
+};
 
+/// Builds the CORS policy from `Config`: GET and HEAD requests are accepted
+/// from `CORS_READ_ORIGINS`, every other method only from
+/// `CORS_WRITE_ORIGINS`. Origins may be `*` or use a wildcard subdomain such
+/// as `https://*.dataroot.ca`.
 pub fn default() -> Cors {
-    Cors::permissive().max_age(3600)
+    let config = config::get_config();
+
+    CorsPolicy {
+        read_origins: config.cors_read_origins.clone(),
+        write_origins: config.cors_write_origins.clone(),
+        allowed_methods: config.cors_allowed_methods.clone(),
+        allowed_headers: config.cors_allowed_headers.clone(),
+        max_age: config.cors_max_age,
+    }
+    .into_cors()
+}
+
+#[derive(Debug, Clone)]
+pub struct CorsPolicy {
+    pub read_origins: Vec<String>,
+    pub write_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub max_age: usize,
+}
+
+impl CorsPolicy {
+    pub fn into_cors(self) -> Cors {
+        let read_origins = Rc::new(self.read_origins);
+        let write_origins = Rc::new(self.write_origins);
+
+        Cors::default()
+            .allowed_origin_fn(move |origin, req_head| {
+                let Ok(origin) = origin.to_str() else {
+                    return false;
+                };
+
+                // Preflight requests carry the method of the actual request.
+                let method = match req_head.method {
+                    Method::OPTIONS => req_head
+                        .headers
+                        .get(ACCESS_CONTROL_REQUEST_METHOD)
+                        .and_then(|value| Method::from_bytes(value.as_bytes()).ok())
+                        .unwrap_or(Method::OPTIONS),
+                    ref method => method.clone(),
+                };
+
+                let origins = match method {
+                    Method::GET | Method::HEAD => &read_origins,
+                    _ => &write_origins,
+                };
+
+                origins
+                    .iter()
+                    .any(|pattern| origin_matches(pattern, origin))
+            })
+            .allowed_methods(self.allowed_methods.iter().map(String::as_str))
+            .allowed_headers(self.allowed_headers.iter().map(String::as_str))
+            .expose_headers([
+                RETRY_AFTER.as_str(),
+                RATE_LIMIT_LIMIT_HEADER,
+                RATE_LIMIT_REMAINING_HEADER,
+                RATE_LIMIT_RESET_HEADER,
+                REQUEST_ID_HEADER,
+            ])
+            .max_age(self.max_age)
+    }
+}
+
+/// Matches `origin` against `*`, an exact origin or a wildcard subdomain
+/// pattern like `https://*.dataroot.ca`, which does not match the apex domain.
+fn origin_matches(pattern: &str, origin: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+
+    match pattern.split_once("*.") {
+        Some((scheme, domain)) => origin
+            .strip_prefix(scheme)
+            .and_then(|host| host.strip_suffix(domain))
+            .and_then(|subdomain| subdomain.strip_suffix('.'))
+            .map(|subdomain| {
+                !subdomain.is_empty()
+                    && subdomain
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+            })
+            .unwrap_or(false),
+        None => pattern == origin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        http::header::{ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN},
+        test, web, App, HttpResponse,
+    };
+
+    use super::{origin_matches, CorsPolicy};
+
+    fn policy() -> CorsPolicy {
+        CorsPolicy {
+            read_origins: vec![String::from("*")],
+            write_origins: vec![
+                String::from("https://dataroot.ca"),
+                String::from("https://*.dataroot.ca"),
+            ],
+            allowed_methods: vec![String::from("GET"), String::from("POST")],
+            allowed_headers: vec![String::from("Content-Type"), String::from("X-API-Key")],
+            max_age: 3600,
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_should_match_wildcard_subdomains() {
+        assert!(origin_matches(
+            "https://*.dataroot.ca",
+            "https://app.dataroot.ca"
+        ));
+        assert!(origin_matches(
+            "https://*.dataroot.ca",
+            "https://a.b.dataroot.ca"
+        ));
+        assert!(!origin_matches(
+            "https://*.dataroot.ca",
+            "https://dataroot.ca"
+        ));
+        assert!(!origin_matches(
+            "https://*.dataroot.ca",
+            "https://evildataroot.ca"
+        ));
+        assert!(!origin_matches(
+            "https://*.dataroot.ca",
+            "http://app.dataroot.ca"
+        ));
+        assert!(!origin_matches(
+            "https://*.dataroot.ca",
+            "https://app.dataroot.ca.evil.com"
+        ));
+        assert!(origin_matches("https://dataroot.ca", "https://dataroot.ca"));
+    }
+
+    #[actix_web::test]
+    async fn it_should_allow_reads_from_any_origin() {
+        let app = test::init_service(
+            App::new()
+                .wrap(policy().into_cors())
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((ORIGIN, "https://example.com"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+        assert_eq!(
+            res.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://example.com"
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_allow_writes_from_configured_subdomain() {
+        let app = test::init_service(
+            App::new()
+                .wrap(policy().into_cors())
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let req = test::TestRequest::default()
+            .method(actix_web::http::Method::OPTIONS)
+            .uri("/")
+            .insert_header((ORIGIN, "https://app.dataroot.ca"))
+            .insert_header((ACCESS_CONTROL_REQUEST_METHOD, "POST"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+        assert_eq!(
+            res.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+            "https://app.dataroot.ca"
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_reject_writes_from_unknown_origin() {
+        let app = test::init_service(
+            App::new()
+                .wrap(policy().into_cors())
+                .route("/", web::post().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/")
+            .insert_header((ORIGIN, "https://example.com"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(!res.status().is_success());
+        assert!(!res.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN));
+    }
 }
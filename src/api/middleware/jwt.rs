@@ -0,0 +1,283 @@
+use std::{
+    future::{ready, Ready},
+    sync::Arc,
+};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::AUTHORIZATION,
+    Error, HttpMessage, ResponseError,
+};
+use futures::future::LocalBoxFuture;
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        config,
+        middleware::{actor::Actor, role::Role},
+    },
+    domain::error::DomainError,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub role: Role,
+    pub exp: usize,
+}
+
+/// Verifies `Authorization: Bearer` tokens with the key configured through
+/// `JWT_ALGORITHM` and `JWT_SECRET` (HS256) or `JWT_PUBLIC_KEY_FILE` (RS256),
+/// and stores the role claim in the request extensions. Requests without a
+/// bearer token pass through untouched.
+pub fn default() -> JwtAuth {
+    let config = config::get_config();
+
+    let algorithm: Algorithm = config
+        .jwt_algorithm
+        .parse()
+        .expect("JWT_ALGORITHM must be HS256 or RS256");
+
+    let key = match algorithm {
+        Algorithm::HS256 => config
+            .jwt_secret
+            .as_ref()
+            .map(|secret| DecodingKey::from_secret(secret.as_bytes())),
+        Algorithm::RS256 => config.jwt_public_key_file.as_ref().map(|path| {
+            let pem = std::fs::read(path).expect("JWT_PUBLIC_KEY_FILE must be readable");
+            DecodingKey::from_rsa_pem(&pem).expect("JWT_PUBLIC_KEY_FILE must be a RSA public key")
+        }),
+        _ => panic!("JWT_ALGORITHM must be HS256 or RS256"),
+    };
+
+    JwtAuth::new(algorithm, key)
+}
+
+#[derive(Clone)]
+pub struct JwtAuth {
+    decoder: Option<Arc<(DecodingKey, Validation)>>,
+}
+
+impl JwtAuth {
+    pub fn new(algorithm: Algorithm, key: Option<DecodingKey>) -> Self {
+        Self {
+            decoder: key.map(|key| Arc::new((key, Validation::new(algorithm)))),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for JwtAuth
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = JwtAuthMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(JwtAuthMiddleware {
+            service,
+            decoder: self.decoder.clone(),
+        }))
+    }
+}
+
+pub struct JwtAuthMiddleware<S> {
+    service: S,
+    decoder: Option<Arc<(DecodingKey, Validation)>>,
+}
+
+impl<S> JwtAuthMiddleware<S> {
+    fn verify(&self, token: &str) -> Result<Claims, DomainError> {
+        let Some(decoder) = self.decoder.as_ref() else {
+            return Err(DomainError::Unauthorized(String::from(
+                "Bearer tokens are not accepted",
+            )));
+        };
+
+        let (key, validation) = decoder.as_ref();
+        decode::<Claims>(token, key, validation)
+            .map(|data| data.claims)
+            .map_err(|err| DomainError::Unauthorized(format!("Invalid bearer token: {err}")))
+    }
+}
+
+impl<S, B> Service<ServiceRequest> for JwtAuthMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let token = req
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|value| value.trim().to_owned());
+
+        if let Some(token) = token {
+            match self.verify(&token) {
+                Ok(claims) => {
+                    req.extensions_mut().insert(claims.role);
+                    req.extensions_mut()
+                        .insert(Actor(format!("jwt:{}", claims.sub)));
+                }
+                Err(err) => {
+                    let response = err.error_response().map_into_right_body();
+                    return Box::pin(ready(Ok(req.into_response(response))));
+                }
+            }
+        }
+
+        let fut = self.service.call(req);
+        Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test, App};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    use crate::api::{
+        lib::AppState,
+        middleware,
+        resources::{organizer, state},
+        tests::utils::get_repositories,
+        utils::random_string,
+    };
+
+    use super::*;
+
+    const SECRET: &str = "secret";
+
+    fn token(role: Role, secret: &str) -> String {
+        let claims = Claims {
+            sub: random_string(10),
+            role,
+            exp: (chrono::Utc::now().timestamp() + 60) as usize,
+        };
+
+        encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_with_invalid_token() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .wrap(JwtAuth::new(
+                    Algorithm::HS256,
+                    Some(DecodingKey::from_secret(SECRET.as_bytes())),
+                ))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(organizer::routes::init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token(Role::Admin, "other"))))
+            .set_json(organizer::dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn it_should_allow_ingest_role_to_create() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .wrap(JwtAuth::new(
+                    Algorithm::HS256,
+                    Some(DecodingKey::from_secret(SECRET.as_bytes())),
+                ))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(organizer::routes::init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token(Role::Ingest, SECRET))))
+            .set_json(organizer::dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_forbidden_when_role_is_not_allowed() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .wrap(JwtAuth::new(
+                    Algorithm::HS256,
+                    Some(DecodingKey::from_secret(SECRET.as_bytes())),
+                ))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(state::routes::init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri("/state/1")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token(Role::Editor, SECRET))))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::put()
+            .uri("/state/1")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token(Role::Ingest, SECRET))))
+            .set_json(state::dto::RequestUpdateState::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_when_tokens_are_disabled() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::api_key::default())
+                .wrap(JwtAuth::new(Algorithm::HS256, None))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(organizer::routes::init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token(Role::Admin, SECRET))))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+}
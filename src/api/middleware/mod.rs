@@ -1 +1,7 @@
+pub mod actor;
+pub mod api_key;
 pub mod cors;
+pub mod jwt;
+pub mod rate_limit;
+pub mod request_id;
+pub mod role;
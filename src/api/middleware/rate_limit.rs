@@ -0,0 +1,444 @@
+use std::{
+    future::{ready, Ready},
+    net::IpAddr,
+    rc::Rc,
+    sync::Arc,
+};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER},
+    web::Data,
+    Error, HttpResponse,
+};
+use futures::future::LocalBoxFuture;
+
+use crate::{
+    api::{config, error::ErrorResponse, lib::AppState, middleware::api_key::API_KEY_HEADER},
+    domain::{
+        api_key,
+        rate_limit::{self, model::RateLimitModel},
+    },
+};
+
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Limits requests per client on the routes configured through
+/// `RATE_LIMIT_ROUTES`, counting hits in a sliding window of
+/// `RATE_LIMIT_WINDOW_SECONDS`. Clients are identified by their API key when
+/// they send a valid one and by their IP address otherwise.
+pub fn default() -> RateLimit {
+    let config = config::get_config();
+
+    RateLimit::new(
+        config.rate_limit_window_seconds,
+        config.rate_limit_routes.clone(),
+    )
+    .with_trusted_proxies(config.rate_limit_trusted_proxies.clone())
+}
+
+#[derive(Clone)]
+pub struct RateLimit {
+    window_ms: u64,
+    routes: Arc<Vec<(String, u32)>>,
+    trusted_proxies: Arc<Vec<IpAddr>>,
+}
+
+impl RateLimit {
+    pub fn new(window_seconds: u64, routes: Vec<(String, u32)>) -> Self {
+        Self {
+            window_ms: window_seconds * 1000,
+            routes: Arc::new(routes),
+            trusted_proxies: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Honors `X-Forwarded-For` and `Forwarded` for connections from these
+    /// addresses; anyone else is identified by the peer address alone.
+    pub fn with_trusted_proxies(mut self, trusted_proxies: Vec<IpAddr>) -> Self {
+        self.trusted_proxies = Arc::new(trusted_proxies);
+        self
+    }
+
+    /// Returns the client IP address, read from the forwarding headers only
+    /// when the peer is a trusted proxy.
+    fn client_ip(&self, req: &ServiceRequest) -> String {
+        match req.peer_addr().map(|addr| addr.ip()) {
+            Some(ip) if self.trusted_proxies.contains(&ip) => req
+                .connection_info()
+                .realip_remote_addr()
+                .map(str::to_owned)
+                .unwrap_or_else(|| ip.to_string()),
+            Some(ip) => ip.to_string(),
+            None => String::from("unknown"),
+        }
+    }
+
+    /// Returns the longest configured prefix matching `path` with its limit.
+    fn route(&self, path: &str) -> Option<(String, u32)> {
+        self.routes
+            .iter()
+            .filter(|(prefix, _)| {
+                path.strip_prefix(prefix.as_str())
+                    .map(|rest| rest.is_empty() || rest.starts_with('/'))
+                    .unwrap_or(false)
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .cloned()
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service: Rc::new(service),
+            rate_limit: self.clone(),
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: Rc<S>,
+    rate_limit: RateLimit,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        let (route, app_state) = match (
+            self.rate_limit.route(req.path()),
+            req.app_data::<Data<AppState>>().cloned(),
+        ) {
+            (Some(route), Some(app_state)) => (route, app_state),
+            _ => {
+                return Box::pin(async move {
+                    service.call(req).await.map(|res| res.map_into_left_body())
+                })
+            }
+        };
+
+        let api_key = req
+            .headers()
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let client_ip = self.rate_limit.client_ip(&req);
+        let (prefix, limit) = route;
+        let window_ms = self.rate_limit.window_ms;
+
+        Box::pin(async move {
+            // Only an authenticated key gets its own bucket, otherwise sending
+            // a random key with each request would bypass the limit.
+            let client = match api_key {
+                Some(api_key) => match api_key::resources::authenticate::execute(
+                    app_state.api_key_repository.clone(),
+                    Some(api_key),
+                )
+                .await
+                {
+                    Ok(api_key) => format!("key:{}", api_key.apikeyid),
+                    Err(_) => format!("ip:{client_ip}"),
+                },
+                None => format!("ip:{client_ip}"),
+            };
+            let key = format!("rate_limit:{prefix}:{client}");
+
+            let result = rate_limit::check::execute(
+                app_state.rate_limit_repository.clone(),
+                key,
+                limit,
+                window_ms,
+            )
+            .await;
+
+            // Fail open: an unavailable Redis must not take the API down.
+            let rate_limit = match result {
+                Ok(rate_limit) => rate_limit,
+                Err(err) => {
+                    log::error!("{}", err);
+                    return service.call(req).await.map(|res| res.map_into_left_body());
+                }
+            };
+
+            if !rate_limit.allowed {
+                let mut response = HttpResponse::TooManyRequests()
+                    .insert_header((RETRY_AFTER, seconds(rate_limit.reset_after_ms)))
+                    .json(ErrorResponse::new("Too many requests"));
+                insert_headers(response.headers_mut(), &rate_limit);
+
+                return Ok(req.into_response(response.map_into_right_body()));
+            }
+
+            let mut res = service.call(req).await?;
+            insert_headers(res.headers_mut(), &rate_limit);
+
+            Ok(res.map_into_left_body())
+        })
+    }
+}
+
+fn seconds(ms: u64) -> u64 {
+    ms.div_ceil(1000)
+}
+
+fn insert_headers(headers: &mut HeaderMap, rate_limit: &RateLimitModel) {
+    for (name, value) in [
+        (RATE_LIMIT_LIMIT_HEADER, u64::from(rate_limit.limit)),
+        (RATE_LIMIT_REMAINING_HEADER, u64::from(rate_limit.remaining)),
+        (RATE_LIMIT_RESET_HEADER, seconds(rate_limit.reset_after_ms)),
+    ] {
+        headers.insert(HeaderName::from_static(name), HeaderValue::from(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        http::{header::RETRY_AFTER, StatusCode},
+        test, App,
+    };
+    use rand::Rng;
+
+    use crate::{
+        api::{
+            lib::AppState, middleware::api_key::API_KEY_HEADER,
+            resources::organizer::routes::init_routes, tests::utils::get_repositories,
+            utils::random_string,
+        },
+        domain::api_key::{model::ApiKeyCreateModel, repository::ApiKeyRepository},
+    };
+
+    use super::{RateLimit, RATE_LIMIT_REMAINING_HEADER};
+
+    fn random_peer_addr() -> std::net::SocketAddr {
+        let mut rng = rand::thread_rng();
+        std::net::SocketAddr::from(([10, rng.gen(), rng.gen(), rng.gen()], 8080))
+    }
+
+    #[actix_web::test]
+    async fn it_should_add_rate_limit_headers() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizer"), 5)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(random_peer_addr())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+        assert_eq!(res.headers().get(RATE_LIMIT_REMAINING_HEADER).unwrap(), "4");
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_too_many_requests_when_limit_is_exceeded() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizer"), 2)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let peer_addr = random_peer_addr();
+        for _ in 0..2 {
+            let req = test::TestRequest::get()
+                .uri("/organizer")
+                .peer_addr(peer_addr)
+                .to_request();
+            let res = test::call_service(&app, req).await;
+            assert!(res.status().is_success());
+        }
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(res.headers().contains_key(RETRY_AFTER));
+        assert_eq!(res.headers().get(RATE_LIMIT_REMAINING_HEADER).unwrap(), "0");
+    }
+
+    #[actix_web::test]
+    async fn it_should_limit_api_keys_separately_from_ip() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizer"), 1)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let peer_addr = random_peer_addr();
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+
+        //Seed
+        let key = random_string(32);
+        repositories
+            .api_key_repository
+            .insert(&ApiKeyCreateModel::new(random_string(10), &key, true))
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .insert_header((API_KEY_HEADER, key))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn it_should_limit_unknown_api_keys_by_ip() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizer"), 1)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let peer_addr = random_peer_addr();
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .insert_header((API_KEY_HEADER, random_string(32)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.headers().get(RATE_LIMIT_REMAINING_HEADER).unwrap(), "0");
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .insert_header((API_KEY_HEADER, random_string(32)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[actix_web::test]
+    async fn it_should_ignore_forwarded_for_from_untrusted_peers() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizer"), 1)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let peer_addr = random_peer_addr();
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .insert_header(("x-forwarded-for", "203.0.113.1"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .peer_addr(peer_addr)
+            .insert_header(("x-forwarded-for", "203.0.113.2"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[actix_web::test]
+    async fn it_should_honor_forwarded_for_from_trusted_proxies() {
+        let repositories = get_repositories().await;
+        let peer_addr = random_peer_addr();
+        let app = test::init_service(
+            App::new()
+                .wrap(
+                    RateLimit::new(60, vec![(String::from("/organizer"), 1)])
+                        .with_trusted_proxies(vec![peer_addr.ip()]),
+                )
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..2 {
+            let forwarded_for = format!("198.51.{}.{}", rng.gen::<u8>(), rng.gen::<u8>());
+            let req = test::TestRequest::get()
+                .uri("/organizer")
+                .peer_addr(peer_addr)
+                .insert_header(("x-forwarded-for", forwarded_for))
+                .to_request();
+            let res = test::call_service(&app, req).await;
+
+            assert!(res.status().is_success());
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_should_not_limit_unconfigured_routes() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(RateLimit::new(60, vec![(String::from("/organizers"), 1)]))
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let peer_addr = random_peer_addr();
+        for _ in 0..2 {
+            let req = test::TestRequest::get()
+                .uri("/organizer")
+                .peer_addr(peer_addr)
+                .to_request();
+            let res = test::call_service(&app, req).await;
+
+            assert!(res.status().is_success());
+            assert!(!res.headers().contains_key(RATE_LIMIT_REMAINING_HEADER));
+        }
+    }
+}
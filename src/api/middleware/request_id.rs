@@ -0,0 +1,260 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    http::header::{HeaderName, HeaderValue},
+    Error,
+};
+use futures::future::LocalBoxFuture;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Id of the request being handled by the current task, if any.
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
+/// Accepts the client's `X-Request-Id` or generates one, makes it available
+/// to log records and error bodies through [`current`] and echoes it in the
+/// response. The request header is overwritten with the id in use so inner
+/// middlewares, such as the access log, can read it with `%{x-request-id}i`.
+pub fn default() -> RequestIdPropagation {
+    RequestIdPropagation
+}
+
+pub struct RequestIdPropagation;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdPropagation
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestIdMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestIdMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestIdMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| is_valid(value))
+            .map(|value| value.to_owned())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        if let Ok(value) = HeaderValue::from_str(&request_id) {
+            req.headers_mut()
+                .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+        }
+
+        Box::pin(REQUEST_ID.scope(request_id.clone(), async move {
+            let mut res = service.call(req).await?;
+
+            if let Ok(value) = HeaderValue::from_str(&request_id) {
+                res.headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+
+            Ok(res)
+        }))
+    }
+}
+
+fn is_valid(request_id: &str) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= 128
+        && request_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, Once};
+
+    use actix_web::{middleware::Logger, test, App};
+
+    use crate::api::{
+        error::ErrorResponse,
+        lib::{AppState, LOG_FORMAT},
+        middleware,
+        resources::organizer::routes::init_routes,
+        tests::utils::get_repositories,
+    };
+
+    use super::REQUEST_ID_HEADER;
+
+    static ACCESS_LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct AccessLogCapture;
+
+    impl log::Log for AccessLogCapture {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "actix_web::middleware::logger"
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                ACCESS_LOGS.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    fn capture_access_logs() {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&AccessLogCapture).unwrap();
+            log::set_max_level(log::LevelFilter::Info);
+        });
+    }
+
+    #[actix_web::test]
+    async fn it_should_generate_request_id() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::request_id::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/organizer").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+        assert_eq!(res.headers().get(REQUEST_ID_HEADER).unwrap().len(), 36);
+    }
+
+    #[actix_web::test]
+    async fn it_should_echo_client_request_id() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::request_id::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .insert_header((REQUEST_ID_HEADER, "client-id-1"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.headers().get(REQUEST_ID_HEADER).unwrap(), "client-id-1");
+    }
+
+    #[actix_web::test]
+    async fn it_should_replace_invalid_client_request_id() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::request_id::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/organizer")
+            .insert_header((REQUEST_ID_HEADER, "bad id"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_ne!(res.headers().get(REQUEST_ID_HEADER).unwrap(), "bad id");
+    }
+
+    #[actix_web::test]
+    async fn it_should_include_request_id_in_error_body() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(middleware::request_id::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer?page_size={}", u32::MAX))
+            .insert_header((REQUEST_ID_HEADER, "client-id-2"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_client_error());
+
+        let body = test::read_body(res).await;
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.request_id.as_deref(), Some("client-id-2"));
+    }
+
+    #[actix_web::test]
+    async fn it_should_include_request_id_in_access_log() {
+        capture_access_logs();
+
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .wrap(Logger::new(LOG_FORMAT))
+                .wrap(middleware::request_id::default())
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/organizer").to_request();
+        let res = test::call_service(&app, req).await;
+        let request_id = res
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_owned();
+        test::read_body(res).await;
+
+        assert!(ACCESS_LOGS
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|line| line.ends_with(&format!("request_id={request_id}"))));
+    }
+}
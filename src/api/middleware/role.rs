@@ -0,0 +1,97 @@
+use std::future::ready;
+
+use actix_web::{
+    body::EitherBody,
+    dev::{Service, ServiceRequest, ServiceResponse},
+    Error, HttpMessage, ResponseError,
+};
+use futures::future::LocalBoxFuture;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Editor,
+    Ingest,
+}
+
+/// Declares a unit struct middleware that only lets requests through when the
+/// role stored in the request extensions (by the jwt or api_key middleware) is
+/// one of the listed roles. Used as `wrap = "super::Policy"` on handlers.
+macro_rules! role_policy {
+    ($name:ident, [$($role:expr),+ $(,)?]) => {
+        pub struct $name;
+
+        impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for $name
+        where
+            S: actix_web::dev::Service<
+                    actix_web::dev::ServiceRequest,
+                    Response = actix_web::dev::ServiceResponse<B>,
+                    Error = actix_web::Error,
+                > + 'static,
+            B: 'static,
+        {
+            type Response = actix_web::dev::ServiceResponse<actix_web::body::EitherBody<B>>;
+            type Error = actix_web::Error;
+            type Transform = $crate::api::middleware::role::RequireRoleMiddleware<S>;
+            type InitError = ();
+            type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+            fn new_transform(&self, service: S) -> Self::Future {
+                std::future::ready(Ok(
+                    $crate::api::middleware::role::RequireRoleMiddleware::new(
+                        service,
+                        &[$($role),+],
+                    ),
+                ))
+            }
+        }
+    };
+}
+pub(crate) use role_policy;
+
+pub struct RequireRoleMiddleware<S> {
+    service: S,
+    roles: &'static [Role],
+}
+
+impl<S> RequireRoleMiddleware<S> {
+    pub fn new(service: S, roles: &'static [Role]) -> Self {
+        Self { service, roles }
+    }
+}
+
+impl<S, B> Service<ServiceRequest> for RequireRoleMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let role = req.extensions().get::<Role>().copied();
+
+        let error = match role {
+            Some(role) if self.roles.contains(&role) => None,
+            Some(_) => Some(DomainError::Forbidden(String::from(
+                "Role is not allowed to perform this action",
+            ))),
+            None => Some(DomainError::Unauthorized(String::from("Missing credentials"))),
+        };
+
+        if let Some(err) = error {
+            let response = err.error_response().map_into_right_body();
+            return Box::pin(ready(Ok(req.into_response(response))));
+        }
+
+        let fut = self.service.call(req);
+        Box::pin(async move { fut.await.map(|res| res.map_into_left_body()) })
+    }
+}
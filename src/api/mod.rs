@@ -7,4 +7,4 @@ mod resources;
 pub mod utils;
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
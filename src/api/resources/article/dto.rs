@@ -5,8 +5,10 @@ use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 use crate::{
+    api::resources::categories::dto::ResponseCategory,
     api::utils::{validator::validate_page_size_max},
     domain::article::model::{ArticleCreateModel, ArticleModel, ArticleUpdateModel},
+    repository::article::ArticleSortOption,
 };
 
 #[cfg(test)]
@@ -27,6 +29,9 @@ pub struct RequestCreateArticle {
     pub author: String,
     #[validate(length(max = 64))]
     pub link: String,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 64))]
     pub extid: String,
     #[validate(length(max = 512))]
@@ -47,6 +52,7 @@ impl From<RequestCreateArticle> for ArticleCreateModel {
             value.link,
             value.author,
             value.publish_at,
+            value.categoryids,
             value.highres_link,
             value.photo_link,
             value.thumb_link,
@@ -65,6 +71,7 @@ impl RequestCreateArticle {
             source: "source".to_string(),
             author: "author".to_string(),
             publish_at: DateTime::default(),
+            categoryids: vec![],
             highres_link: Some("The img".to_string()),
             photo_link: Some("The img".to_string()),
             thumb_link: Some("The img".to_string()),
@@ -87,6 +94,9 @@ pub struct RequestUpdateArticle {
     pub author: String,
     #[validate(length(max = 64))]
     pub link: String,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -104,6 +114,7 @@ impl From<RequestUpdateArticle> for ArticleUpdateModel {
             value.source,
             value.author,
             value.publish_at,
+            value.categoryids,
             value.highres_link,
             value.photo_link,
             value.thumb_link,
@@ -121,6 +132,7 @@ impl RequestUpdateArticle {
             source: "source".to_string(),
             author: "author".to_string(),
             publish_at: DateTime::default(),
+            categoryids: vec![],
             highres_link: Some("The img".to_string()),
             photo_link: Some("The img".to_string()),
             thumb_link: Some("The img".to_string()),
@@ -137,6 +149,17 @@ impl RequestUpdateArticle {
 pub struct RequestFindArticle {
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
+    /// Full-text search over name and description, in English or French,
+    /// best matches first. Supports quoted phrases, `or` and `-` exclusions.
+    #[validate(length(min = 1, max = 256))]
+    pub q: Option<String>,
+    pub category: Option<i32>,
+    /// One of `publish_at` or `name`, prefixed with `-` for descending order.
+    /// Defaults to `-publish_at`.
+    pub sort: Option<ArticleSortOption>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
@@ -160,10 +183,14 @@ pub struct ResponseArticle {
     pub photo_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_link: Option<String>,
+    pub categories: Vec<ResponseCategory>,
     pub publish_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateTime<Utc>>,
+    /// Description excerpt with the matches of `q` wrapped in `<mark>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 impl From<ArticleModel> for ResponseArticle {
     fn from(value: ArticleModel) -> Self {
@@ -179,9 +206,11 @@ impl From<ArticleModel> for ResponseArticle {
             highres_link: value.highres_link,
             photo_link: value.photo_link,
             thumb_link: value.thumb_link,
+            categories: value.categories.into_iter().map(|i| i.into()).collect(),
             publish_at: value.publish_at,
             created_at: value.created_at,
             updated_at: value.updated_at,
+            snippet: value.snippet,
         }
     }
 }
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::article::dto::{self, ResponseArticle},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,33 @@ use crate::{
     operation_id = "create_article",
     path = "/article",
     tag = "article",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateState,
     responses(
          (status = 201, description = "article created",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/article")]
+#[post("/article", wrap = "super::CreatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateArticle>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let article =
-        article::resources::create::execute(state.article_repository.clone(), body.0.into())
-            .await?;
+    let article = article::resources::create::execute(
+        state.article_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseArticle>::new(vec![article.into()], None, None, None);
 
@@ -64,4 +76,21 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::CREATED);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_category_not_found() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut article = dto::RequestCreateArticle::mock_default();
+        article.categoryids = vec![i32::MAX];
+
+        let req = test::TestRequest::post()
+            .uri("/article")
+            .set_json(article)
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -6,7 +6,7 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{article, error::DomainError},
 };
 
@@ -15,6 +15,10 @@ use crate::{
     operation_id = "delete_article",
     path = "/article/{article_id}",
     tag = "article",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("article_id" = i32, Path, description = "article uuid"),
     ),
@@ -23,15 +27,19 @@ use crate::{
          (status = 400, description = "Invalid article id",  body = ErrorResponse),
          (status = 404, description = "article not found",  body = ErrorResponse),
          (status = 409, description = "article is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/article/{article_id}")]
+#[delete("/article/{article_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     article::resources::delete_by_articleid::execute(
         state.article_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -43,7 +51,7 @@ mod tests {
     use actix_web::{http::StatusCode, test};
 
     use crate::{
-        api::{resources::article::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::article::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::article::{model::ArticleCreateModel, repository::ArticleRepository},
     };
 
@@ -55,7 +63,7 @@ mod tests {
         let article_model = ArticleCreateModel::mock_default();
         let article = repositories
             .article_repository
-            .insert(&article_model.clone())
+            .insert(&article_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
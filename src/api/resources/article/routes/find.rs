@@ -43,10 +43,18 @@ async fn handler(
         .unwrap_or(config::get_config().page_size_default);
 
     let name = query.name.to_owned();
+    let fuzzy = query.fuzzy.unwrap_or(false);
+    let q = query.q.to_owned();
+    let category = query.category.to_owned();
+    let sort = query.sort.to_owned();
 
     let result = article::resources::find::execute(
         state.article_repository.clone(),
         name,
+        fuzzy,
+        q,
+        category,
+        sort,
         page,
         page_size,
     )
@@ -68,14 +76,18 @@ async fn handler(
 #[cfg(test)]
 mod tests {
     use actix_web::{http::StatusCode, test};
+    use chrono::{Duration, Utc};
 
     use crate::{
         api::{
             resources::article::{dto, routes::init_routes},
-            tests::utils::get_app,
-            utils::response::ApiResponse,
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::{
+            article::{model::ArticleCreateModel, repository::ArticleRepository},
+            categories::{model::CategoryCreateModel, repository::CategoryRepository},
         },
-        domain::article::{model::ArticleCreateModel, repository::ArticleRepository},
     };
 
     #[actix_web::test]
@@ -86,7 +98,7 @@ mod tests {
         let article_model = ArticleCreateModel::mock_default();
         repositories
             .article_repository
-            .insert(&article_model.clone())
+            .insert(&article_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -109,7 +121,7 @@ mod tests {
         let state_model = ArticleCreateModel::mock_default();
         repositories
             .article_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -130,6 +142,117 @@ mod tests {
         assert!(!response_article_finded.records.is_empty());
     }
 
+    #[actix_web::test]
+    async fn it_should_return_article_finded_by_category() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut article_model = ArticleCreateModel::mock_default();
+        article_model.categoryids = vec![category.id];
+        repositories
+            .article_repository
+            .insert(&article_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/article?category={}", category.id))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response_article_finded: ApiResponse<dto::ResponseArticle> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_article_finded.records.len(), 1);
+        assert_eq!(
+            response_article_finded.records[0].categories[0].id,
+            category.id
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_articles_sorted() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let name = random_string(12);
+        let mut older_model = ArticleCreateModel::mock_default();
+        older_model.name = name.clone();
+        older_model.publish_at = Utc::now() - Duration::days(2);
+        let older = repositories
+            .article_repository
+            .insert(&older_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut newer_model = ArticleCreateModel::mock_default();
+        newer_model.name = name.clone();
+        newer_model.publish_at = Utc::now() - Duration::days(1);
+        let newer = repositories
+            .article_repository
+            .insert(&newer_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/article?name={name}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseArticle> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].articleid, newer.articleid);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/article?name={name}&sort=publish_at"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseArticle> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].articleid, older.articleid);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_article_finded_by_french_text() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let suffix = random_string(12).to_lowercase();
+        let mut article_model = ArticleCreateModel::mock_default();
+        article_model.name = format!("Soirée {suffix}");
+        article_model.description = Some(format!("Retour sur les évaluations de modèles {suffix}"));
+        repositories
+            .article_repository
+            .insert(&article_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/article?q=%C3%A9valuation%20{suffix}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseArticle> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert!(response.records[0]
+            .snippet
+            .as_ref()
+            .unwrap()
+            .contains("<mark>évaluations</mark>"));
+    }
+
     #[actix_web::test]
     async fn it_should_return_article_no_content() {
         let (_, app) = get_app(init_routes).await;
@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::article::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::article::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::article::{model::ArticleCreateModel, repository::ArticleRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let article_model = ArticleCreateModel::mock_default();
         let article = repositories
             .article_repository
-            .insert(&article_model.clone())
+            .insert(&article_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -1,11 +1,17 @@
 use actix_web::web;
 
+use crate::api::middleware::role::{role_policy, Role};
+
 pub mod create;
 pub mod delete_by_articleid;
 pub mod find;
 pub mod find_by_articleid;
 pub mod update_by_articleid;
 
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(DeletePolicy, [Role::Admin, Role::Editor]);
+
 pub fn init_routes(config: &mut web::ServiceConfig) {
     config.service(create::handler);
     config.service(update_by_articleid::handler);
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::article::dto::{self, ResponseArticle},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_article",
     path = "/article/{article_id}",
     tag = "article",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("article_id" = i32, Path, description = "Article uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "Article updated",  body = ApiResponseArticle),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "Article not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/article/{article_id}")]
+#[put("/article/{article_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateArticle>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,8 @@ async fn handler(
 
     let article: article::model::ArticleModel = article::resources::update_by_articleid::execute(
         state.article_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +68,7 @@ mod tests {
     use crate::{
         api::{
             resources::article::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::article::{model::ArticleCreateModel, repository::ArticleRepository},
@@ -72,7 +82,7 @@ mod tests {
         let article_model = ArticleCreateModel::mock_default();
         let aritcle = repositories
             .article_repository
-            .insert(&article_model.clone())
+            .insert(&article_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use validator::Validate;
+
+use crate::{
+    api::utils::validator::validate_page_size_max, domain::audit_log::model::AuditLogModel,
+};
+
+#[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
+pub struct RequestFindAuditLog {
+    /// Resource type, e.g. `event` or `city`
+    #[validate(length(max = 64))]
+    pub resource: Option<String>,
+    /// Id of the resource
+    pub resourceid: Option<i32>,
+    /// Actor, e.g. `api_key:<name>` or `jwt:<sub>`
+    #[validate(length(max = 128))]
+    pub actor: Option<String>,
+    pub page: Option<u32>,
+    #[validate(custom = "validate_page_size_max")]
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ResponseAuditLog {
+    pub auditlogid: i64,
+    pub actor: String,
+    pub resource: String,
+    pub resourceid: i32,
+    pub action: String,
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+impl From<AuditLogModel> for ResponseAuditLog {
+    fn from(value: AuditLogModel) -> Self {
+        Self {
+            auditlogid: value.auditlogid,
+            actor: value.actor,
+            resource: value.resource,
+            resourceid: value.resourceid,
+            action: value.action,
+            before: value.before,
+            after: value.after,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use actix_web::{
+    get,
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        config,
+        lib::AppState,
+        resources::audit_log::dto::{self, ResponseAuditLog},
+        utils::response::ApiResponse,
+    },
+    domain::{audit_log, error::DomainError},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_audit_log",
+    path = "/audit",
+    tag = "audit",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        dto::RequestFindAuditLog
+    ),
+    responses(
+         (status = 200, description = "audit log",  body = ApiResponseAuditLog),
+         (status = 204, description = "no content audit log"),
+         (status = 400, description = "Invalid query parameters",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[get("/audit", wrap = "super::ReadPolicy")]
+async fn handler(
+    state: Data<AppState>,
+    query: Query<dto::RequestFindAuditLog>,
+) -> Result<HttpResponse, DomainError> {
+    query.validate()?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query
+        .page_size
+        .unwrap_or(config::get_config().page_size_default);
+
+    let result = audit_log::resources::find::execute(
+        state.audit_log_repository.clone(),
+        query.resource.to_owned(),
+        query.resourceid,
+        query.actor.to_owned(),
+        page,
+        page_size,
+    )
+    .await?;
+
+    if let Some((audit_logs, count)) = result {
+        let response = ApiResponse::<ResponseAuditLog>::new(
+            audit_logs.into_iter().map(|i| i.into()).collect(),
+            Some(page),
+            Some(count),
+            Some(page_size),
+        );
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::{
+        api::{
+            lib::AppState,
+            resources::{
+                audit_log::{dto, routes::init_routes},
+                organizer::{self, dto::RequestCreateOrganizer},
+            },
+            tests::utils::{get_app, get_repositories, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::audit_log::model::AuditLogCreateModel,
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_audit_log_finded_by_query() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let mut audit_log_model = AuditLogCreateModel::mock_default();
+        audit_log_model.actor = random_string(10);
+        repositories
+            .audit_log_repository
+            .insert(&audit_log_model)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/audit?resource={}&actor={}&page=1&page_size=24",
+                audit_log_model.resource, audit_log_model.actor
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseAuditLog> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].actor, audit_log_model.actor);
+    }
+
+    #[actix_web::test]
+    async fn it_should_record_changes_made_through_the_api() {
+        let (_, app) = get_app(|config| {
+            init_routes(config);
+            organizer::routes::init_routes(config);
+        })
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .set_json(RequestCreateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+
+        let body = test::read_body(res).await;
+        let created: ApiResponse<organizer::dto::ResponseOrganizer> =
+            serde_json::from_slice(&body).unwrap();
+        let organizerid = created.records[0].organizerid;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/organizer/{organizerid}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/audit?resource=organizer&resourceid={organizerid}&actor={TEST_ACTOR}"
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseAuditLog> = serde_json::from_slice(&body).unwrap();
+        let entries = response.records;
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, "delete");
+        assert!(entries[0].before.is_some() && entries[0].after.is_none());
+        assert_eq!(entries[1].action, "create");
+        assert!(entries[1].before.is_none() && entries[1].after.is_some());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_audit_log_no_content() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/audit?actor={}", random_string(20)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_without_role() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/audit").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+}
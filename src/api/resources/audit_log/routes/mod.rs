@@ -0,0 +1,11 @@
+use actix_web::web;
+
+use crate::api::middleware::role::{role_policy, Role};
+
+pub mod find;
+
+role_policy!(ReadPolicy, [Role::Admin]);
+
+pub fn init_routes(config: &mut web::ServiceConfig) {
+    config.service(find::handler);
+}
@@ -5,21 +5,29 @@ use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 use crate::{
-    api::utils::validator::validate_page_size_max,
-    domain::categories::model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel},
+    api::utils::validator::{validate_page_size_max, validate_slug},
+    domain::categories::model::{
+        CategoryCreateModel, CategoryModel, CategoryTreeModel, CategoryUpdateModel,
+    },
 };
 
+#[cfg(test)]
+use crate::api::utils::random_string;
+
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
 pub struct RequestCreateCategory {
     #[validate(length(max = 64))]
     pub name: String,
+    #[validate(length(max = 100), custom = "validate_slug")]
+    pub slug: String,
     #[validate(length(max = 512))]
     pub description: Option<String>,
+    pub parent_id: Option<i32>,
 }
 impl From<RequestCreateCategory> for CategoryCreateModel {
     fn from(value: RequestCreateCategory) -> Self {
-        CategoryCreateModel::new(value.name, value.description)
+        CategoryCreateModel::new(value.name, value.slug, value.description, value.parent_id)
     }
 }
 #[cfg(test)]
@@ -27,7 +35,9 @@ impl RequestCreateCategory {
     pub fn mock_default() -> Self {
         Self {
             name: "Burgers".to_string(),
+            slug: random_string(10).to_lowercase(),
             description: Some("The Big Burgers".to_string()),
+            parent_id: None,
         }
     }
 }
@@ -37,12 +47,23 @@ impl RequestCreateCategory {
 pub struct RequestUpdateCategory {
     #[validate(length(max = 64))]
     pub name: String,
+    #[validate(length(max = 100), custom = "validate_slug")]
+    pub slug: String,
     #[validate(length(max = 512))]
     pub description: Option<String>,
+    pub parent_id: Option<i32>,
+    /// Defaults to `true` when omitted.
+    pub is_active: Option<bool>,
 }
 impl From<RequestUpdateCategory> for CategoryUpdateModel {
     fn from(value: RequestUpdateCategory) -> Self {
-        CategoryUpdateModel::new(value.name, value.description)
+        CategoryUpdateModel::new(
+            value.name,
+            value.slug,
+            value.description,
+            value.parent_id,
+            value.is_active.unwrap_or(true),
+        )
     }
 }
 #[cfg(test)]
@@ -50,7 +71,10 @@ impl RequestUpdateCategory {
     pub fn mock_default() -> Self {
         Self {
             name: "French fries".to_string(),
+            slug: random_string(10).to_lowercase(),
             description: Some("The French fries".to_string()),
+            parent_id: None,
+            is_active: Some(true),
         }
     }
 
@@ -58,24 +82,43 @@ impl RequestUpdateCategory {
         self.name = name.to_string();
         self
     }
+
+    pub fn with_parent_id(mut self, parent_id: i32) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
 pub struct RequestFindCategories {
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
+    /// Also return inactive categories. Admin only.
+    pub include_inactive: Option<bool>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
 }
 
+#[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
+pub struct RequestFindCategoryTree {
+    /// Also return inactive categories. Admin only.
+    pub include_inactive: Option<bool>,
+}
+
 #[cfg_attr(test, derive(Deserialize))]
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ResponseCategory {
     pub id: i32,
     pub name: String,
+    pub slug: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i32>,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -85,10 +128,36 @@ impl From<CategoryModel> for ResponseCategory {
         Self {
             id: value.id,
             name: value.name,
+            slug: value.slug,
             description: value.description,
+            parent_id: value.parent_id,
             is_active: value.is_active,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
 }
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseCategoryTree {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub children: Vec<ResponseCategoryTree>,
+}
+impl From<CategoryTreeModel> for ResponseCategoryTree {
+    fn from(value: CategoryTreeModel) -> Self {
+        Self {
+            id: value.category.id,
+            name: value.category.name,
+            slug: value.category.slug,
+            description: value.category.description,
+            is_active: value.category.is_active,
+            children: value.children.into_iter().map(|i| i.into()).collect(),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::categories::dto::{self, ResponseCategory},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,32 @@ use crate::{
     operation_id = "create_categories",
     path = "/categories",
     tag = "categories",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateCategory,
     responses(
          (status = 201, description = "category created",  body = ApiResponseCategory),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/categories")]
+#[post("/categories", wrap = "super::CreatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateCategory>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let category =
-        categories::resources::create::execute(state.category_repository.clone(), body.0.into())
-            .await?;
+    let category = categories::resources::create::execute(
+        state.category_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseCategory>::new(vec![category.into()], None, None, None);
 
@@ -6,7 +6,7 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{categories, error::DomainError},
 };
 
@@ -15,6 +15,10 @@ use crate::{
     operation_id = "delete_categories",
     path = "/categories/{category_id}",
     tag = "categories",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("category_id" = i32, Path, description = "category uuid"),
     ),
@@ -23,15 +27,19 @@ use crate::{
          (status = 400, description = "Invalid category id",  body = ErrorResponse),
          (status = 404, description = "category not found",  body = ErrorResponse),
          (status = 409, description = "category is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/categories/{category_id}")]
+#[delete("/categories/{category_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     categories::resources::delete_by_id::execute(
         state.category_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -44,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::categories::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::categories::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::categories::{model::CategoryCreateModel, repository::CategoryRepository},
     };
 
@@ -56,7 +64,7 @@ mod tests {
         let category_model = CategoryCreateModel::mock_default();
         let category = repositories
             .category_repository
-            .insert(&category_model.clone())
+            .insert(&category_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -1,6 +1,6 @@
 use actix_web::{
     get,
-    web::{Data, Query},
+    web::{Data, Query, ReqData},
     HttpResponse,
 };
 
@@ -10,6 +10,7 @@ use crate::{
     api::{
         config,
         lib::AppState,
+        middleware::role::Role,
         resources::categories::dto::{self, ResponseCategory},
         utils::response::ApiResponse,
     },
@@ -28,25 +29,33 @@ use crate::{
          (status = 200, description = "categories",  body = ApiResponseCategory),
          (status = 204, description = "no content categories"),
          (status = 400, description = "Invalid query parameters",  body = ErrorResponse),
+         (status = 401, description = "include_inactive requested without credentials",  body = ErrorResponse),
+         (status = 403, description = "include_inactive requested by a non-admin role",  body = ErrorResponse),
     ),
  )]
 #[get("/categories")]
 async fn handler(
     state: Data<AppState>,
     query: Query<dto::RequestFindCategories>,
+    role: Option<ReqData<Role>>,
 ) -> Result<HttpResponse, DomainError> {
     query.validate()?;
 
+    let include_inactive = super::include_inactive(query.include_inactive, role)?;
+
     let page = query.page.unwrap_or(1);
     let page_size = query
         .page_size
         .unwrap_or(config::get_config().page_size_default);
 
     let name = query.name.to_owned();
+    let fuzzy = query.fuzzy.unwrap_or(false);
 
     let result = categories::resources::find::execute(
         state.category_repository.clone(),
         name,
+        fuzzy,
+        include_inactive,
         page,
         page_size,
     )
@@ -72,10 +81,13 @@ mod tests {
     use crate::{
         api::{
             resources::categories::{dto, routes::init_routes},
-            tests::utils::get_app,
-            utils::response::ApiResponse,
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::categories::{
+            model::{CategoryCreateModel, CategoryUpdateModel},
+            repository::CategoryRepository,
         },
-        domain::categories::{model::CategoryCreateModel, repository::CategoryRepository},
     };
 
     #[actix_web::test]
@@ -86,7 +98,7 @@ mod tests {
         let category_model = CategoryCreateModel::mock_default();
         repositories
             .category_repository
-            .insert(&category_model.clone())
+            .insert(&category_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -109,7 +121,7 @@ mod tests {
         let category_model = CategoryCreateModel::mock_default();
         repositories
             .category_repository
-            .insert(&category_model.clone())
+            .insert(&category_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -155,4 +167,46 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_inactive_categories_only_when_requested() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut category_update_model = CategoryUpdateModel::mock_default();
+        category_update_model.name = random_string(16);
+        category_update_model.is_active = false;
+        repositories
+            .category_repository
+            .update_by_id(&category.id, &category_update_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/categories?name={}", category_update_model.name))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/categories?name={}&include_inactive=true",
+                category_update_model.name
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCategory> = serde_json::from_slice(&body).unwrap();
+
+        assert!(response.records.iter().any(|i| i.id == category.id));
+    }
 }
@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::categories::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::categories::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::categories::{model::CategoryCreateModel, repository::CategoryRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let category_model = CategoryCreateModel::mock_default();
         let category = repositories
             .category_repository
-            .insert(&category_model.clone())
+            .insert(&category_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
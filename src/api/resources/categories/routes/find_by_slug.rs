@@ -0,0 +1,136 @@
+use actix_web::{
+    get,
+    web::{self, Data, Query, ReqData},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::role::Role,
+        resources::categories::dto::{self, ResponseCategory},
+        utils::response::ApiResponse,
+    },
+    domain::{categories, error::DomainError},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_category_by_slug",
+    path = "/categories/slug/{slug}",
+    tag = "categories",
+    params(
+        ("slug" = String, Path, description = "Category slug"),
+        dto::RequestFindCategoryTree
+    ),
+    responses(
+         (status = 200, description = "Category finded",  body = ApiResponseCategory),
+         (status = 204, description = "Category no content"),
+         (status = 401, description = "include_inactive requested without credentials",  body = ErrorResponse),
+         (status = 403, description = "include_inactive requested by a non-admin role",  body = ErrorResponse),
+    ),
+ )]
+#[get("/categories/slug/{slug}")]
+async fn handler(
+    state: Data<AppState>,
+    param: web::Path<String>,
+    query: Query<dto::RequestFindCategoryTree>,
+    role: Option<ReqData<Role>>,
+) -> Result<HttpResponse, DomainError> {
+    let include_inactive = super::include_inactive(query.include_inactive, role)?;
+
+    let result = categories::resources::find_by_slug::execute(
+        state.category_repository.clone(),
+        param.to_owned(),
+        include_inactive,
+    )
+    .await?;
+
+    if let Some(category) = result {
+        let response =
+            ApiResponse::<ResponseCategory>::new(vec![category.into()], None, None, None);
+
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::categories::{dto, routes::init_routes},
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::response::ApiResponse,
+        },
+        domain::categories::{
+            model::{CategoryCreateModel, CategoryUpdateModel},
+            repository::CategoryRepository,
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_category_finded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category_model = CategoryCreateModel::mock_default();
+        let category = repositories
+            .category_repository
+            .insert(&category_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/categories/slug/{}", category.slug))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCategory> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].id, category.id);
+    }
+
+    #[actix_web::test]
+    async fn it_should_hide_inactive_category_by_default() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut category_update_model = CategoryUpdateModel::mock_default();
+        category_update_model.slug = category.slug.clone();
+        category_update_model.is_active = false;
+        repositories
+            .category_repository
+            .update_by_id(&category.id, &category_update_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/categories/slug/{}", category.slug))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/categories/slug/{}?include_inactive=true",
+                category.slug
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,136 @@
+use actix_web::{
+    get,
+    web::{Data, Query, ReqData},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::role::Role,
+        resources::categories::dto::{self, ResponseCategoryTree},
+        utils::response::ApiResponse,
+    },
+    domain::{categories, error::DomainError},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_category_tree",
+    path = "/categories/tree",
+    tag = "categories",
+    params(
+        dto::RequestFindCategoryTree
+    ),
+    responses(
+         (status = 200, description = "Root categories with their nested children",  body = ApiResponseCategoryTree),
+         (status = 204, description = "no content categories"),
+         (status = 401, description = "include_inactive requested without credentials",  body = ErrorResponse),
+         (status = 403, description = "include_inactive requested by a non-admin role",  body = ErrorResponse),
+    ),
+ )]
+#[get("/categories/tree")]
+async fn handler(
+    state: Data<AppState>,
+    query: Query<dto::RequestFindCategoryTree>,
+    role: Option<ReqData<Role>>,
+) -> Result<HttpResponse, DomainError> {
+    let include_inactive = super::include_inactive(query.include_inactive, role)?;
+
+    let tree = categories::resources::find_tree::execute(
+        state.category_repository.clone(),
+        include_inactive,
+    )
+    .await?;
+
+    if tree.is_empty() {
+        return Ok(HttpResponse::NoContent().finish());
+    }
+
+    let response = ApiResponse::<ResponseCategoryTree>::new(
+        tree.into_iter().map(|i| i.into()).collect(),
+        None,
+        None,
+        None,
+    );
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test, App};
+
+    use crate::{
+        api::{
+            lib::AppState,
+            resources::categories::{dto, routes::init_routes},
+            tests::utils::{get_app, get_repositories, TEST_ACTOR},
+            utils::response::ApiResponse,
+        },
+        domain::categories::{model::CategoryCreateModel, repository::CategoryRepository},
+    };
+
+    fn find(tree: &[dto::ResponseCategoryTree], id: i32) -> Option<&dto::ResponseCategoryTree> {
+        tree.iter().find_map(|node| {
+            (node.id == id)
+                .then_some(node)
+                .or_else(|| find(&node.children, id))
+        })
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_nested_categories() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let parent = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut child_model = CategoryCreateModel::mock_default();
+        child_model.parent_id = Some(parent.id);
+        let child = repositories
+            .category_repository
+            .insert(&child_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri("/categories/tree")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCategoryTree> =
+            serde_json::from_slice(&body).unwrap();
+
+        let parent = response
+            .records
+            .iter()
+            .find(|node| node.id == parent.id)
+            .unwrap();
+        assert!(find(&parent.children, child.id).is_some());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_unauthorized_when_include_inactive_without_role() {
+        let repositories = get_repositories().await;
+        let app = test::init_service(
+            App::new()
+                .app_data(AppState::mock_default(&repositories))
+                .configure(init_routes),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/categories/tree?include_inactive=true")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -1,15 +1,47 @@
-use actix_web::web;
+use actix_web::web::{self, ReqData};
+
+use crate::{
+    api::middleware::role::{role_policy, Role},
+    domain::error::DomainError,
+};
 
 pub mod create;
 pub mod delete_by_id;
 pub mod find;
 pub mod find_by_id;
+pub mod find_by_slug;
+pub mod find_tree;
 pub mod update_by_id;
 
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(DeletePolicy, [Role::Admin]);
+
 pub fn init_routes(config: &mut web::ServiceConfig) {
     config.service(create::handler);
     config.service(update_by_id::handler);
+    config.service(find_tree::handler);
+    config.service(find_by_slug::handler);
     config.service(find_by_id::handler);
     config.service(find::handler);
     config.service(delete_by_id::handler);
 }
+
+/// Inactive categories are hidden unless an admin asks for them with
+/// `include_inactive=true`.
+fn include_inactive(
+    include_inactive: Option<bool>,
+    role: Option<ReqData<Role>>,
+) -> Result<bool, DomainError> {
+    if !include_inactive.unwrap_or(false) {
+        return Ok(false);
+    }
+
+    match role.map(|role| role.into_inner()) {
+        Some(Role::Admin) => Ok(true),
+        Some(_) => Err(DomainError::Forbidden(String::from(
+            "Only admins can include inactive categories",
+        ))),
+        None => Err(DomainError::Unauthorized(String::from("Missing credentials"))),
+    }
+}
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::categories::dto::{self, ResponseCategory},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_categories",
     path = "/categories/{category_id}",
     tag = "categories",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("category_id" = i32, Path, description = "Category uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "Category updated",  body = ApiResponseCategory),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "Category not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/categories/{category_id}")]
+#[put("/categories/{category_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateCategory>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,7 @@ async fn handler(
 
     let category = categories::resources::update_by_id::execute(
         state.category_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +67,7 @@ mod tests {
     use crate::{
         api::{
             resources::categories::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::categories::{model::CategoryCreateModel, repository::CategoryRepository},
@@ -72,7 +81,7 @@ mod tests {
         let category_model = CategoryCreateModel::mock_default();
         let category = repositories
             .category_repository
-            .insert(&category_model.clone())
+            .insert(&category_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -108,4 +117,31 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_parent_creates_cycle() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let parent = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut child_model = CategoryCreateModel::mock_default();
+        child_model.parent_id = Some(parent.id);
+        let child = repositories
+            .category_repository
+            .insert(&child_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/categories/{}", parent.id))
+            .set_json(dto::RequestUpdateCategory::mock_default().with_parent_id(child.id))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
 }
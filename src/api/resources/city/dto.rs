@@ -11,12 +11,17 @@ use crate::api::utils::{
 };
 
 use crate::{
-    api::utils::{validator::validate_page_size_max},
+    api::utils::validator::{
+        validate_city_request, validate_create_city_request, validate_page_size_max,
+        validate_time_zone, validate_update_city_request,
+    },
     domain::city::model::{CityCreateModel, CityModel, CityUpdateModel, CityDetailModel},
+    repository::city::CitySortOption,
 };
 
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+#[validate(schema(function = "validate_create_city_request"))]
 pub struct RequestCreateCity {
     #[validate(length(max = 64))]
     pub name: String,
@@ -25,6 +30,13 @@ pub struct RequestCreateCity {
     pub stateid: i32,
     #[validate(length(max = 64))]
     pub slug: String,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
+    /// IANA time zone name, e.g. `America/Toronto`.
+    #[validate(custom = "validate_time_zone")]
+    pub time_zone: Option<String>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -39,6 +51,9 @@ impl From<RequestCreateCity> for CityCreateModel {
             value.slug,
             value.stateid,
             value.extid,
+            value.latitude,
+            value.longitude,
+            value.time_zone,
             value.highres_link,
             value.photo_link,
             value.thumb_link
@@ -53,6 +68,9 @@ impl RequestCreateCity {
             slug: random_string(10),
             extid: random_string(10),
             stateid: random_number(),
+            latitude: None,
+            longitude: None,
+            time_zone: Some("America/Toronto".to_string()),
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -62,12 +80,20 @@ impl RequestCreateCity {
 
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_update_city_request"))]
 pub struct RequestUpdateCity {
     #[validate(length(max = 64))]
     pub name: String,
     pub stateid: i32,
     #[validate(length(max = 64))]
     pub slug: String,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
+    /// IANA time zone name, e.g. `America/Toronto`.
+    #[validate(custom = "validate_time_zone")]
+    pub time_zone: Option<String>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -81,6 +107,9 @@ impl From<RequestUpdateCity> for CityUpdateModel {
             value.name, 
             value.slug,
             value.stateid,
+            value.latitude,
+            value.longitude,
+            value.time_zone,
             value.highres_link,
             value.photo_link,
             value.thumb_link,
@@ -94,6 +123,9 @@ impl RequestUpdateCity {
             name: random_string(20),
             slug: random_string(2),
             stateid: random_number(),
+            latitude: None,
+            longitude: None,
+            time_zone: Some("America/Toronto".to_string()),
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -107,9 +139,25 @@ impl RequestUpdateCity {
 }
 
 #[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
+#[validate(schema(function = "validate_city_request"))]
 pub struct RequestFindCategories {
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
+    pub stateid: Option<i32>,
+    #[validate(length(max = 2))]
+    pub state_symbol: Option<String>,
+    /// Latitude of the point to search around.
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: Option<f64>,
+    /// Longitude of the point to search around.
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lng: Option<f64>,
+    /// Only return cities within this many kilometres of `lat`/`lng`.
+    pub radius_km: Option<f64>,
+    pub sort: Option<CitySortOption>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
@@ -129,6 +177,18 @@ pub struct ResponseCity {
     pub photo_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_km: Option<f64>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateTime<Utc>>,
@@ -144,6 +204,12 @@ impl From<CityModel> for ResponseCity {
             highres_link: value.highres_link,
             thumb_link: value.thumb_link,
             photo_link: value.photo_link,
+            state_name: value.state_name,
+            state_symbol: value.state_symbol,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            time_zone: value.time_zone,
+            distance_km: value.distance_km,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
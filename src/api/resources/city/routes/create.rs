@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::city::dto::{self, ResponseCity},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,32 @@ use crate::{
     operation_id = "create_city",
     path = "/city",
     tag = "city",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateState,
     responses(
          (status = 201, description = "city created",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/city")]
+#[post("/city", wrap = "super::CreatePolicy")]
 async fn handler(
     city: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateCity>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let city =
-        city::resources::create::execute(city.city_repository.clone(), body.0.into())
-            .await?;
+    let city = city::resources::create::execute(
+        city.city_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseCity>::new(vec![city.into()], None, None, None);
 
@@ -64,4 +75,22 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::CREATED);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_latitude_out_of_range() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut request_create_city = dto::RequestCreateCity::mock_default();
+        request_create_city.latitude = Some(91.0);
+        request_create_city.longitude = Some(0.0);
+
+        let req = test::TestRequest::post()
+            .uri("/city")
+            .set_json(request_create_city)
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
 }
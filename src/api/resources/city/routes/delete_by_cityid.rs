@@ -6,7 +6,7 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{city, error::DomainError},
 };
 
@@ -15,6 +15,10 @@ use crate::{
     operation_id = "delete_city",
     path = "/city/{city_id}",
     tag = "city",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("city_id" = i32, Path, description = "city uuid"),
     ),
@@ -23,15 +27,19 @@ use crate::{
          (status = 400, description = "Invalid city id",  body = ErrorResponse),
          (status = 404, description = "city not found",  body = ErrorResponse),
          (status = 409, description = "city is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/city/{city_id}")]
+#[delete("/city/{city_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     city: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     city::resources::delete_by_cityid::execute(
         city.city_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -44,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::city::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::city::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::city::{model::CityCreateModel, repository::CityRepository},
     };
 
@@ -56,7 +64,7 @@ mod tests {
         let city_model = CityCreateModel::mock_default();
         let city = repositories
             .city_repository
-            .insert(&city_model.clone())
+            .insert(&city_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
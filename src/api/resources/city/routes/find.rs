@@ -43,10 +43,24 @@ async fn handler(
         .unwrap_or(config::get_config().page_size_default);
 
     let name = query.name.to_owned();
+    let fuzzy = query.fuzzy.unwrap_or(false);
+    let stateid = query.stateid.to_owned();
+    let state_symbol = query.state_symbol.to_owned();
+    let lat = query.lat.to_owned();
+    let lng = query.lng.to_owned();
+    let radius_km = query.radius_km.to_owned();
+    let sort = query.sort.to_owned();
 
     let result = city::resources::find::execute(
         state.city_repository.clone(),
         name,
+        fuzzy,
+        stateid,
+        state_symbol,
+        lat,
+        lng,
+        radius_km,
+        sort,
         page,
         page_size,
     )
@@ -72,10 +86,13 @@ mod tests {
     use crate::{
         api::{
             resources::city::{dto, routes::init_routes},
-            tests::utils::get_app,
-            utils::response::ApiResponse,
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::{
+            city::{model::CityCreateModel, repository::CityRepository},
+            group::{model::GroupCreateModel, repository::GroupRepository},
         },
-        domain::city::{model::CityCreateModel, repository::CityRepository},
     };
 
     #[actix_web::test]
@@ -86,7 +103,7 @@ mod tests {
         let city_model = CityCreateModel::mock_default();
         repositories
             .city_repository
-            .insert(&city_model.clone())
+            .insert(&city_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -109,7 +126,7 @@ mod tests {
         let city_model = CityCreateModel::mock_default();
         repositories
             .city_repository
-            .insert(&city_model.clone())
+            .insert(&city_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -130,6 +147,34 @@ mod tests {
         assert!(!response_city_finded.records.is_empty());
     }
 
+    #[actix_web::test]
+    async fn it_should_return_city_finded_ignoring_case_and_accents() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let suffix = random_string(10).to_lowercase();
+        let mut city_model = CityCreateModel::mock_default();
+        city_model.name = format!("Montréal {suffix}");
+        repositories
+            .city_repository
+            .insert(&city_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/city?name=MONTREAL%20{}", suffix.to_uppercase()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCity> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].name, city_model.name);
+    }
+
     #[actix_web::test]
     async fn it_should_return_city_no_content() {
         let (_, app) = get_app(init_routes).await;
@@ -155,4 +200,145 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_city_finded_by_state_symbol_with_state_embedded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let mut city_model = CityCreateModel::mock_default();
+        city_model.stateid = 9;
+        let city = repositories
+            .city_repository
+            .insert(&city_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/city?state_symbol=QC&name={}", city.name.to_lowercase()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCity> = serde_json::from_slice(&body).unwrap();
+        let response_city = response.records.first().unwrap();
+
+        assert_eq!(response_city.cityid, city.cityid);
+        assert_eq!(response_city.state_symbol.as_deref(), Some("qc"));
+        assert_eq!(response_city.state_name.as_deref(), Some("Quebec"));
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_cities_sorted_by_groups() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let prefix = random_string(12);
+        let mut first_city_model = CityCreateModel::mock_default();
+        first_city_model.name = format!("{prefix} a");
+        let first_city = repositories
+            .city_repository
+            .insert(&first_city_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut second_city_model = CityCreateModel::mock_default();
+        second_city_model.name = format!("{prefix} b");
+        let second_city = repositories
+            .city_repository
+            .insert(&second_city_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut group_model = GroupCreateModel::mock_default();
+        group_model.cityid = second_city.cityid;
+        repositories
+            .group_repository
+            .insert(&group_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/city?name={prefix}&sort=Name"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCity> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.first().unwrap().cityid, first_city.cityid);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/city?name={prefix}&sort=Groups"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCity> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.first().unwrap().cityid, second_city.cityid);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_cities_within_radius_sorted_by_distance() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let prefix = random_string(12);
+        let mut montreal_model = CityCreateModel::mock_default();
+        montreal_model.name = format!("{prefix} montreal");
+        montreal_model.latitude = Some(45.5019);
+        montreal_model.longitude = Some(-73.5674);
+        let montreal = repositories
+            .city_repository
+            .insert(&montreal_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut laval_model = CityCreateModel::mock_default();
+        laval_model.name = format!("{prefix} laval");
+        laval_model.latitude = Some(45.6066);
+        laval_model.longitude = Some(-73.7124);
+        let laval = repositories
+            .city_repository
+            .insert(&laval_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut toronto_model = CityCreateModel::mock_default();
+        toronto_model.name = format!("{prefix} toronto");
+        toronto_model.latitude = Some(43.6532);
+        toronto_model.longitude = Some(-79.3832);
+        repositories
+            .city_repository
+            .insert(&toronto_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/city?name={prefix}&lat=45.5019&lng=-73.5674&radius_km=25&sort=Distance"
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseCity> = serde_json::from_slice(&body).unwrap();
+        let cityids: Vec<i32> = response.records.iter().map(|i| i.cityid).collect();
+
+        assert_eq!(cityids, vec![montreal.cityid, laval.cityid]);
+        assert!(response.records[0].distance_km.unwrap() < 0.001);
+        assert!(response.records[1].distance_km.unwrap() < 25.0);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_radius_without_coordinates() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri("/city?radius_km=25")
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
 }
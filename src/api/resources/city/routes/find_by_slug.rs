@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::city::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::city::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::city::{model::CityCreateModel, repository::CityRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let city_model = CityCreateModel::mock_default();
         let city = repositories
             .city_repository
-            .insert(&city_model.clone())
+            .insert(&city_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
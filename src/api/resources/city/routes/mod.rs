@@ -1,5 +1,7 @@
 use actix_web::web;
 
+use crate::api::middleware::role::{role_policy, Role};
+
 pub mod create;
 pub mod delete_by_cityid;
 pub mod find;
@@ -7,6 +9,10 @@ pub mod find_by_slug;
 pub mod find_by_cityid;
 pub mod update_by_cityid;
 
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(DeletePolicy, [Role::Admin]);
+
 pub fn init_routes(config: &mut web::ServiceConfig) {
     config.service(create::handler);
     config.service(update_by_cityid::handler);
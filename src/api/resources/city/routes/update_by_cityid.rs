@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::city::dto::{self, ResponseCity},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_city",
     path = "/city/{city_id}",
     tag = "city",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("city_id" = i32, Path, description = "City uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "City updated",  body = ApiResponseCity),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "City not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/city/{city_id}")]
+#[put("/city/{city_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     city: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateCity>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,7 @@ async fn handler(
 
     let city: city::model::CityModel = city::resources::update_by_cityid::execute(
         city.city_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +67,7 @@ mod tests {
     use crate::{
         api::{
             resources::city::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::city::{model::CityCreateModel, repository::CityRepository},
@@ -72,7 +81,7 @@ mod tests {
         let city_model = CityCreateModel::mock_default();
         let city = repositories
             .city_repository
-            .insert(&city_model.clone())
+            .insert(&city_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -1,4 +1,5 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, FixedOffset, Offset, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
@@ -11,12 +12,14 @@ use crate::api::utils::{
 };
 
 use crate::{
-    api::utils::{validator::{validate_page_size_max,validate_event_status_option,validate_event_request}},
-    domain::event::model::{EventCreateModel, EventModel, EventUpdateModel, EventDetailModel}, repository::event::{EventStatusOption, DateRangeOption},
+    api::resources::categories::dto::ResponseCategory,
+    api::utils::{validator::{validate_page_size_max,validate_event_status_option,validate_event_request,validate_create_event_request,validate_update_event_request,validate_time_zone,validate_currency}},
+    domain::{event::model::{EventCreateModel, EventModel, EventUpdateModel, EventDetailModel, EventLifecycleStatus, EventLifecycleUpdateModel}, upsert::UpsertOutcome}, repository::event::{EventStatusOption, DateRangeOption, EventSortOption},
 };
 
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+#[validate(schema(function = "validate_create_event_request"))]
 pub struct RequestCreateEvent {
     #[validate(length(max = 64))]
     pub name: String,
@@ -24,6 +27,10 @@ pub struct RequestCreateEvent {
     pub description: String,
     #[validate(length(max = 64))]
     pub location: String,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
     #[validate(length(max = 64))]
     pub extid: String,
     pub groupid: i32,
@@ -34,9 +41,24 @@ pub struct RequestCreateEvent {
     #[validate(length(max = 64))]
     pub link: String,
     pub waitlist_count: i32,
+    /// Derived from `price_max` when prices are given.
+    #[serde(default)]
     pub fee: bool,
+    /// Lowest ticket price in cents, 0 for free events.
+    #[validate(range(min = 0))]
+    pub price_min: Option<i32>,
+    /// Highest ticket price in cents. Defaults to `price_min`.
+    pub price_max: Option<i32>,
+    /// ISO 4217 code of the prices. Defaults to `CAD`.
+    #[validate(custom = "validate_currency")]
+    pub currency: Option<String>,
+    #[validate(length(max = 512), url)]
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -50,6 +72,8 @@ impl From<RequestCreateEvent> for EventCreateModel {
             value.name, 
             value.description,
             value.location,
+            value.latitude,
+            value.longitude,
             value.groupid,
             value.extid,
             value.link,
@@ -61,9 +85,14 @@ impl From<RequestCreateEvent> for EventCreateModel {
             value.fee,
             value.rsvp_limit,
             value.time,
+            value.categoryids,
             value.highres_link,
             value.photo_link,
             value.thumb_link,
+            value.price_min,
+            value.price_max,
+            value.currency,
+            value.ticket_url,
         )
     }
 }
@@ -74,6 +103,8 @@ impl RequestCreateEvent {
             name: "Event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             groupid: random_number(),
             extid: random_string(10),
             in_person: true,
@@ -83,8 +114,13 @@ impl RequestCreateEvent {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: None,
+            currency: None,
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
+            categoryids: vec![],
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -94,6 +130,7 @@ impl RequestCreateEvent {
 
 #[cfg_attr(test, derive(Serialize))]
 #[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_update_event_request"))]
 pub struct RequestUpdateEvent {
     #[validate(length(max = 64))]
     pub name: String,
@@ -101,6 +138,10 @@ pub struct RequestUpdateEvent {
     pub description: String,
     #[validate(length(max = 64))]
     pub location: String,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: Option<f64>,
     pub groupid: i32,
     pub in_person: bool,
     pub is_online: bool,
@@ -109,9 +150,24 @@ pub struct RequestUpdateEvent {
     #[validate(length(max = 64))]
     pub link: String,
     pub waitlist_count: i32,
+    /// Derived from `price_max` when prices are given.
+    #[serde(default)]
     pub fee: bool,
+    /// Lowest ticket price in cents, 0 for free events.
+    #[validate(range(min = 0))]
+    pub price_min: Option<i32>,
+    /// Highest ticket price in cents. Defaults to `price_min`.
+    pub price_max: Option<i32>,
+    /// ISO 4217 code of the prices. Defaults to `CAD`.
+    #[validate(custom = "validate_currency")]
+    pub currency: Option<String>,
+    #[validate(length(max = 512), url)]
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -125,6 +181,8 @@ impl From<RequestUpdateEvent> for EventUpdateModel {
             value.name, 
             value.description,
             value.location,
+            value.latitude,
+            value.longitude,
             value.groupid,
             value.link,
             value.in_person,
@@ -135,9 +193,14 @@ impl From<RequestUpdateEvent> for EventUpdateModel {
             value.fee,
             value.rsvp_limit,
             value.time,
+            value.categoryids,
             value.highres_link,
             value.photo_link,
             value.thumb_link,
+            value.price_min,
+            value.price_max,
+            value.currency,
+            value.ticket_url,
         )
     }
 }
@@ -148,6 +211,8 @@ impl RequestUpdateEvent {
             name: "Event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             groupid: random_number(),
             in_person: true,
             is_online: true,
@@ -156,8 +221,13 @@ impl RequestUpdateEvent {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: None,
+            currency: None,
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
+            categoryids: vec![],
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -170,24 +240,92 @@ impl RequestUpdateEvent {
     }
 }
 
+/// Cancels, postpones, reschedules or restores an event.
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RequestUpdateEventLifecycle {
+    #[schema(value_type = String, example = "cancelled")]
+    pub lifecycle_status: EventLifecycleStatus,
+    #[validate(length(max = 512))]
+    pub lifecycle_reason: Option<String>,
+    /// Event replacing this one; required when rescheduling.
+    pub rescheduled_to: Option<i32>,
+}
+impl From<RequestUpdateEventLifecycle> for EventLifecycleUpdateModel {
+    fn from(value: RequestUpdateEventLifecycle) -> Self {
+        EventLifecycleUpdateModel::new(
+            value.lifecycle_status,
+            value.lifecycle_reason,
+            value.rescheduled_to,
+        )
+    }
+}
+#[cfg(test)]
+impl RequestUpdateEventLifecycle {
+    pub fn mock_default() -> Self {
+        Self {
+            lifecycle_status: EventLifecycleStatus::Cancelled,
+            lifecycle_reason: Some("Venue closed".to_string()),
+            rescheduled_to: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
 #[validate(schema(function = "validate_event_request"))]
 pub struct RequestFindEvent {
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
+    /// Full-text search over name and description, in English or French,
+    /// best matches first. Supports quoted phrases, `or` and `-` exclusions.
+    #[validate(length(min = 1, max = 256))]
+    pub q: Option<String>,
     pub in_person: Option<bool>,
     pub is_online: Option<bool>,
     #[validate(length(max = 64))]
     pub group_slug: Option<String>,
+    /// Only return events of groups in this city.
+    #[validate(length(max = 64))]
+    pub city_slug: Option<String>,
+    /// Only return events of groups in this state or province, e.g. `qc`.
+    #[validate(length(max = 2))]
+    pub state_symbol: Option<String>,
     #[validate(length(max = 64))]
     pub location: Option<String>,
     pub has_fee: Option<bool>,
+    /// Only return events whose lowest price, in cents, is at most this.
+    #[validate(range(min = 0))]
+    pub max_price: Option<i32>,
     pub rsvp_limit: Option<u32>,
+    /// Period of the events, e.g. `Tomorrow` or `Next7Days`. Only `Custom`
+    /// takes `start_date` and `end_date`, which it requires.
     pub time_frame: Option<DateRangeOption>,
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
+    /// IANA time zone used for `time_frame` boundaries and local times.
+    /// Defaults to the time zone of each event's city, then UTC.
+    #[validate(custom = "validate_time_zone")]
+    pub tz: Option<String>,
+    pub category: Option<i32>,
+    /// Latitude of the point to search around.
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: Option<f64>,
+    /// Longitude of the point to search around.
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lng: Option<f64>,
+    /// Only return events within this many kilometres of `lat`/`lng`.
+    pub radius_km: Option<f64>,
+    /// One of `time`, `yes_rsvp_count`, `name` or `distance`, prefixed with
+    /// `-` for descending order. Defaults to `time`, or `-time` for past events.
+    pub sort: Option<EventSortOption>,
     #[validate(custom = "validate_event_status_option")]
     pub status: Option<EventStatusOption>,
+    /// One of `scheduled`, `cancelled`, `postponed` or `rescheduled`.
+    /// Cancelled events are left out unless requested.
+    pub lifecycle_status: Option<EventLifecycleStatus>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
@@ -200,6 +338,10 @@ pub struct ResponseEvent {
     pub name: String,
     pub description: String,
     pub location: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
     pub extid: String,
     pub groupid: i32,
     pub in_person: bool,
@@ -209,14 +351,31 @@ pub struct ResponseEvent {
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    /// Lowest ticket price in cents of `currency`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_min: Option<i32>,
+    /// Highest ticket price in cents of `currency`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_max: Option<i32>,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    pub categories: Vec<ResponseCategory>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub highres_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub photo_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_link: Option<String>,
+    #[schema(value_type = String, example = "scheduled")]
+    pub lifecycle_status: EventLifecycleStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_reason: Option<String>,
+    /// Event replacing this one when it was rescheduled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescheduled_to: Option<i32>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateTime<Utc>>,
@@ -228,6 +387,8 @@ impl From<EventModel> for ResponseEvent {
             name: value.name,
             description: value.description,
             location: value.location,
+            latitude: value.latitude,
+            longitude: value.longitude,
             extid: value.extid,
             groupid: value.groupid,
             in_person: value.in_person,
@@ -237,11 +398,19 @@ impl From<EventModel> for ResponseEvent {
             link: value.link,
             waitlist_count: value.waitlist_count,
             fee: value.fee,
+            price_min: value.price_min,
+            price_max: value.price_max,
+            currency: value.currency,
+            ticket_url: value.ticket_url,
             yes_rsvp_count: value.yes_rsvp_count,
             rsvp_limit: value.rsvp_limit,
+            categories: value.categories.into_iter().map(|i| i.into()).collect(),
             highres_link: value.highres_link,
             photo_link: value.photo_link,
             thumb_link: value.thumb_link,
+            lifecycle_status: value.lifecycle_status,
+            lifecycle_reason: value.lifecycle_reason,
+            rescheduled_to: value.rescheduled_to,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -256,16 +425,40 @@ pub struct ResponseDetailEvent {
     pub description: String,
     pub location: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_symbol: Option<String>,
     pub in_person: bool,
     pub is_online: bool,
     pub time: DateTime<Utc>,
     pub duration: i32,
+    pub time_zone: String,
+    pub local_start: DateTime<FixedOffset>,
+    pub local_end: DateTime<FixedOffset>,
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    /// Lowest ticket price in cents of `currency`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_min: Option<i32>,
+    /// Highest ticket price in cents of `currency`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_max: Option<i32>,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -274,33 +467,134 @@ pub struct ResponseDetailEvent {
     pub photo_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_link: Option<String>,
+    #[schema(value_type = String, example = "scheduled")]
+    pub lifecycle_status: EventLifecycleStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescheduled_to: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_km: Option<f64>,
+    /// Description excerpt with the matches of `q` wrapped in `<mark>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Series this occurrence belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seriesid: Option<i32>,
+    /// Start the series' rule gives this occurrence; identifies it when
+    /// overriding or cancelling it, even once rescheduled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurrence: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateTime<Utc>>,
 }
 impl From<EventDetailModel> for ResponseDetailEvent {
     fn from(value: EventDetailModel) -> Self {
+        let tz: Tz = value.time_zone.parse().unwrap_or(Tz::UTC);
+        let to_local = |time: DateTime<Utc>| {
+            let local = time.with_timezone(&tz);
+            local.with_timezone(&local.offset().fix())
+        };
+        let local_start = to_local(value.time);
+        let local_end = to_local(value.time + Duration::seconds(value.duration.into()));
+
         Self {
             eventid: value.eventid,
             name: value.name,
             description: value.description,
             location: value.location,
+            latitude: value.latitude,
+            longitude: value.longitude,
             group_slug: value.group_slug,
             group_name: value.group_name,
+            city_name: value.city_name,
+            city_slug: value.city_slug,
+            state_name: value.state_name,
+            state_symbol: value.state_symbol,
             in_person: value.in_person,
             is_online: value.is_online,
             time: value.time,
             duration: value.duration,
+            time_zone: value.time_zone,
+            local_start,
+            local_end,
             link: value.link,
             waitlist_count: value.waitlist_count,
             fee: value.fee,
+            price_min: value.price_min,
+            price_max: value.price_max,
+            currency: value.currency,
+            ticket_url: value.ticket_url,
             yes_rsvp_count: value.yes_rsvp_count,
             rsvp_limit: value.rsvp_limit,
             highres_link: value.highres_link,
             photo_link: value.photo_link,
             thumb_link: value.thumb_link,
+            lifecycle_status: value.lifecycle_status,
+            lifecycle_reason: value.lifecycle_reason,
+            rescheduled_to: value.rescheduled_to,
+            distance_km: value.distance_km,
+            snippet: value.snippet,
+            seriesid: value.seriesid,
+            occurrence: value.occurrence,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
-}
\ No newline at end of file
+}
+#[cfg_attr(test, derive(Deserialize, PartialEq))]
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkEventStatus {
+    Created,
+    Updated,
+    Unchanged,
+    Error,
+}
+impl From<UpsertOutcome> for BulkEventStatus {
+    fn from(value: UpsertOutcome) -> Self {
+        match value {
+            UpsertOutcome::Created => BulkEventStatus::Created,
+            UpsertOutcome::Updated => BulkEventStatus::Updated,
+            UpsertOutcome::Unchanged => BulkEventStatus::Unchanged,
+        }
+    }
+}
+
+/// Result of one line of a bulk import.
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseBulkEvent {
+    /// Line number in the request, starting at 1.
+    pub line: usize,
+    pub status: BulkEventStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eventid: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extid: Option<String>,
+    /// Why the line was rejected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+impl ResponseBulkEvent {
+    pub fn upserted(line: usize, outcome: UpsertOutcome, event: &EventModel) -> Self {
+        Self {
+            line,
+            status: outcome.into(),
+            eventid: Some(event.eventid),
+            extid: Some(event.extid.clone()),
+            message: None,
+        }
+    }
+
+    pub fn error(line: usize, extid: Option<String>, message: String) -> Self {
+        Self {
+            line,
+            status: BulkEventStatus::Error,
+            eventid: None,
+            extid,
+            message: Some(message),
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use actix_web::{
+    post,
+    web::{self, Bytes, BytesMut, Data},
+    HttpResponse,
+};
+use futures::{channel::mpsc, StreamExt};
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event::dto::{RequestCreateEvent, ResponseBulkEvent},
+    },
+    domain::{
+        error::DomainError,
+        event::{self, model::EventCreateModel},
+    },
+};
+
+/// Events upserted per transaction.
+const BULK_BATCH_SIZE: usize = 100;
+/// Longest accepted line; the import stops at a longer one.
+const BULK_LINE_MAX_BYTES: usize = 64 * 1024;
+
+type ResultSender = mpsc::UnboundedSender<Result<Bytes, DomainError>>;
+/// A parsed line, or the extid it carried (if any) and why it was rejected.
+type ParsedLine = Result<EventCreateModel, (Option<String>, String)>;
+
+#[utoipa::path(
+    post,
+    operation_id = "bulk_upsert_events",
+    path = "/event/bulk",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    request_body(
+        content = String,
+        content_type = "application/x-ndjson",
+        description = "One RequestCreateEvent per line, upserted by extid"
+    ),
+    responses(
+         (status = 200, description = "One ResponseBulkEvent per non-blank line, streamed as the lines are imported", content_type = "application/x-ndjson", body = ResponseBulkEvent),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[post("/event/bulk", wrap = "super::UpsertPolicy")]
+async fn handler(state: Data<AppState>, actor: Actor, payload: web::Payload) -> HttpResponse {
+    let (sender, receiver) = mpsc::unbounded();
+    actix_web::rt::spawn(import(state, actor.0, payload, sender));
+
+    HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .streaming(receiver)
+}
+
+/// Reads the request line by line and upserts its events in batches, sending
+/// back a result per line as each batch completes.
+async fn import(
+    state: Data<AppState>,
+    actor: String,
+    mut payload: web::Payload,
+    sender: ResultSender,
+) {
+    let mut buffer = BytesMut::new();
+    let mut batch = vec![];
+    let mut line = 0;
+
+    while let Some(chunk) = payload.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                send(
+                    &sender,
+                    ResponseBulkEvent::error(line + 1, None, err.to_string()),
+                );
+                return;
+            }
+        };
+        buffer.extend_from_slice(&chunk);
+
+        while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+            let content = buffer.split_to(end + 1);
+            line += 1;
+            if let Some(parsed) = parse_line(&content[..end]) {
+                batch.push((line, parsed));
+            }
+            if batch.len() >= BULK_BATCH_SIZE && !flush(&state, &actor, &mut batch, &sender).await {
+                return;
+            }
+        }
+
+        if buffer.len() > BULK_LINE_MAX_BYTES {
+            flush(&state, &actor, &mut batch, &sender).await;
+            send(
+                &sender,
+                ResponseBulkEvent::error(
+                    line + 1,
+                    None,
+                    format!("line is longer than {BULK_LINE_MAX_BYTES} bytes"),
+                ),
+            );
+            return;
+        }
+    }
+
+    if let Some(parsed) = parse_line(&buffer) {
+        batch.push((line + 1, parsed));
+    }
+    flush(&state, &actor, &mut batch, &sender).await;
+}
+
+/// Parses and validates one line, skipping blank ones. Rejected lines keep
+/// the extid when it could be read, so clients can match them.
+fn parse_line(content: &[u8]) -> Option<ParsedLine> {
+    if content.iter().all(u8::is_ascii_whitespace) {
+        return None;
+    }
+
+    let request: RequestCreateEvent = match serde_json::from_slice(content) {
+        Ok(request) => request,
+        Err(err) => return Some(Err((None, err.to_string()))),
+    };
+    if let Err(err) = request.validate() {
+        return Some(Err((Some(request.extid), err.to_string())));
+    }
+
+    Some(Ok(request.into()))
+}
+
+/// Upserts the valid lines of `batch` and sends the result of every line in
+/// order. Returns false once the client is gone.
+async fn flush(
+    state: &Data<AppState>,
+    actor: &str,
+    batch: &mut Vec<(usize, ParsedLine)>,
+    sender: &ResultSender,
+) -> bool {
+    let lines = std::mem::take(batch);
+    let event_create_models: Vec<EventCreateModel> = lines
+        .iter()
+        .filter_map(|(_, parsed)| parsed.as_ref().ok().cloned())
+        .collect();
+    let extids: Vec<String> = event_create_models
+        .iter()
+        .map(|event_create_model| event_create_model.extid.clone())
+        .collect();
+
+    let mut upserted = match event::resources::bulk_upsert_by_extid::execute(
+        state.event_repository.clone(),
+        state.category_repository.clone(),
+        actor.to_owned(),
+        event_create_models,
+    )
+    .await
+    {
+        Ok(results) => results
+            .into_iter()
+            .map(|result| result.map_err(|err| err.to_string()))
+            .collect(),
+        Err(err) => vec![Err(err.to_string()); extids.len()],
+    }
+    .into_iter()
+    .zip(extids);
+
+    for (line, parsed) in lines {
+        let response = match parsed {
+            Ok(_) => match upserted.next() {
+                Some((Ok(upserted), _)) => {
+                    ResponseBulkEvent::upserted(line, upserted.outcome, &upserted.record)
+                }
+                Some((Err(message), extid)) => ResponseBulkEvent::error(line, Some(extid), message),
+                None => continue,
+            },
+            Err((extid, message)) => ResponseBulkEvent::error(line, extid, message),
+        };
+        if !send(sender, response) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn send(sender: &ResultSender, response: ResponseBulkEvent) -> bool {
+    let mut content = serde_json::to_vec(&response).unwrap_or_default();
+    content.push(b'\n');
+    sender.unbounded_send(Ok(Bytes::from(content))).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test;
+
+    use crate::api::{
+        resources::event::{
+            dto::{self, BulkEventStatus},
+            routes::init_routes,
+        },
+        tests::utils::get_app,
+        utils::random_string,
+    };
+
+    fn ndjson(requests: &[dto::RequestCreateEvent]) -> String {
+        requests
+            .iter()
+            .map(|request| serde_json::to_string(request).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_a_result_per_line() {
+        let (_, app) = get_app(init_routes).await;
+
+        let created = dto::RequestCreateEvent::mock_default();
+        let mut invalid = dto::RequestCreateEvent::mock_default();
+        invalid.name = random_string(65);
+        let body = format!(
+            "{}\n\nnot json\n{}\n",
+            ndjson(std::slice::from_ref(&created)),
+            ndjson(&[invalid.clone(), created.clone()]),
+        );
+
+        let req = test::TestRequest::post()
+            .uri("/event/bulk")
+            .insert_header(("content-type", "application/x-ndjson"))
+            .set_payload(body)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let results: Vec<dto::ResponseBulkEvent> = body
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+
+        let lines: Vec<_> = results.iter().map(|result| result.line).collect();
+        assert_eq!(lines, vec![1, 3, 4, 5]);
+        assert_eq!(results[0].status, BulkEventStatus::Created);
+        assert_eq!(results[0].extid.as_ref(), Some(&created.extid));
+        assert_eq!(results[1].status, BulkEventStatus::Error);
+        assert!(results[1].message.is_some());
+        assert_eq!(results[2].status, BulkEventStatus::Error);
+        assert_eq!(results[2].extid.as_ref(), Some(&invalid.extid));
+        assert_eq!(results[3].status, BulkEventStatus::Unchanged);
+        assert_eq!(results[3].eventid, results[0].eventid);
+    }
+
+    #[actix_web::test]
+    async fn it_should_keep_importing_after_a_failing_event() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut missing_category = dto::RequestCreateEvent::mock_default();
+        missing_category.categoryids = vec![i32::MAX];
+        let valid = dto::RequestCreateEvent::mock_default();
+
+        let req = test::TestRequest::post()
+            .uri("/event/bulk")
+            .set_payload(ndjson(&[missing_category, valid.clone()]))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let results: Vec<dto::ResponseBulkEvent> = body
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].status, BulkEventStatus::Error);
+        assert_eq!(results[1].status, BulkEventStatus::Created);
+        assert_eq!(results[1].extid.as_ref(), Some(&valid.extid));
+    }
+}
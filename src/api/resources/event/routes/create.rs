@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::event::dto::{self, ResponseEvent},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,33 @@ use crate::{
     operation_id = "create_event",
     path = "/event",
     tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateState,
     responses(
          (status = 201, description = "event created",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/event")]
+#[post("/event", wrap = "super::CreatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateEvent>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let event =
-        event::resources::create::execute(state.event_repository.clone(), body.0.into())
-            .await?;
+    let event = event::resources::create::execute(
+        state.event_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseEvent>::new(vec![event.into()], None, None, None);
 
@@ -49,6 +61,7 @@ mod tests {
     use crate::api::{
         resources::event::{dto, routes::init_routes},
         tests::utils::get_app,
+        utils::response::ApiResponse,
     };
 
     #[actix_web::test]
@@ -64,4 +77,76 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::CREATED);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_only_latitude_provided() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut request_create_event = dto::RequestCreateEvent::mock_default();
+        request_create_event.latitude = Some(45.5019);
+
+        let req = test::TestRequest::post()
+            .uri("/event")
+            .set_json(request_create_event)
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_derive_fee_from_price() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut request_create_event = dto::RequestCreateEvent::mock_default();
+        request_create_event.price_min = Some(1000);
+        request_create_event.price_max = Some(2500);
+        request_create_event.ticket_url = Some("https://tickets.example.com/event".to_string());
+
+        let req = test::TestRequest::post()
+            .uri("/event")
+            .set_json(request_create_event)
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let event = response.records.first().unwrap();
+
+        assert!(event.fee);
+        assert_eq!(event.price_min, Some(1000));
+        assert_eq!(event.price_max, Some(2500));
+        assert_eq!(event.currency, "CAD");
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_price_is_invalid() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut below_min = dto::RequestCreateEvent::mock_default();
+        below_min.price_min = Some(2500);
+        below_min.price_max = Some(1000);
+        let mut without_min = dto::RequestCreateEvent::mock_default();
+        without_min.price_min = None;
+        without_min.price_max = Some(1000);
+        let mut negative = dto::RequestCreateEvent::mock_default();
+        negative.price_min = Some(-1);
+        let mut currency = dto::RequestCreateEvent::mock_default();
+        currency.currency = Some("cad".to_string());
+
+        for request_create_event in [below_min, without_min, negative, currency] {
+            let req = test::TestRequest::post()
+                .uri("/event")
+                .set_json(request_create_event)
+                .to_request();
+
+            let res = test::call_service(&app, req).await;
+
+            assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+        }
+    }
 }
@@ -6,32 +6,40 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{event, error::DomainError},
 };
 
+/// Cancels the event rather than removing it, so it stays reachable by id.
 #[utoipa::path(
     delete,
     operation_id = "delete_event",
     path = "/event/{event_id}",
     tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("event_id" = i32, Path, description = "event uuid"),
     ),
     responses(
-         (status = 204, description = "event deleted"),
+         (status = 204, description = "event cancelled"),
          (status = 400, description = "Invalid event id",  body = ErrorResponse),
          (status = 404, description = "event not found",  body = ErrorResponse),
-         (status = 409, description = "event is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/event/{event_id}")]
+#[delete("/event/{event_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     event::resources::delete_by_eventid::execute(
         state.event_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -44,19 +52,22 @@ mod tests {
     
 
     use crate::{
-        api::{resources::event::routes::init_routes, tests::utils::get_app, utils::random_number},
-        domain::event::{model::EventCreateModel, repository::EventRepository},
+        api::{resources::event::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
+        domain::event::{
+            model::{EventCreateModel, EventLifecycleStatus},
+            repository::EventRepository,
+        },
     };
 
     #[actix_web::test]
-    async fn it_should_return_void_event_deleted() {
+    async fn it_should_cancel_event_deleted() {
         let (repositories, app) = get_app(init_routes).await;
 
         //Seed
         let event_model = EventCreateModel::mock_default();
         let event = repositories
             .event_repository
-            .insert(&event_model.clone())
+            .insert(&event_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -65,7 +76,16 @@ mod tests {
             .to_request();
         let res = test::call_service(&app, req).await;
 
-        assert!(res.status().is_success());
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let cancelled = repositories
+            .event_repository
+            .find_by_eventid(&event.eventid)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(cancelled.lifecycle_status, EventLifecycleStatus::Cancelled);
     }
 
     #[actix_web::test]
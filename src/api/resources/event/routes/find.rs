@@ -13,7 +13,10 @@ use crate::{
         resources::event::dto::{self, ResponseDetailEvent},
         utils::response::ApiResponse,
     },
-    domain::{event, error::DomainError}
+    domain::{
+        event::{self, model::EventFindFilter},
+        error::DomainError,
+    },
 };
 
 #[utoipa::path(
@@ -42,36 +45,35 @@ async fn handler(
         .page_size
         .unwrap_or(config::get_config().page_size_default);
 
-    let name = query.name.to_owned();
-    let in_person = query.in_person.to_owned();
-    let is_online = query.is_online.to_owned();
-    let location = query.location.to_owned();
-    let group_slug = query.group_slug.to_owned();
-    let has_fee = query.has_fee.to_owned();
-    let rsvp_limit = query.rsvp_limit.to_owned();
-    let status = query.status.to_owned();
-    
-    let time_frame = query.time_frame.to_owned();
-    let start_date = query.start_date.to_owned();
-    let end_date = query.end_date.to_owned();
-
-    let result = event::resources::find::execute(
-        state.event_repository.clone(),
-        name,
-        in_person,
-        is_online,
-        group_slug,
-        location,
-        has_fee,
-        rsvp_limit,
-        status,
-        time_frame,
-        start_date,
-        end_date,
-        page,
-        page_size,
-    )
-    .await?;
+    let filter = EventFindFilter {
+        name: query.name.to_owned(),
+        fuzzy: query.fuzzy.unwrap_or(false),
+        q: query.q.to_owned(),
+        in_person: query.in_person.to_owned(),
+        is_online: query.is_online.to_owned(),
+        group_slug: query.group_slug.to_owned(),
+        city_slug: query.city_slug.to_owned(),
+        state_symbol: query.state_symbol.to_owned(),
+        location: query.location.to_owned(),
+        has_fee: query.has_fee.to_owned(),
+        max_price: query.max_price.to_owned(),
+        rsvp_limit: query.rsvp_limit.to_owned(),
+        status: query.status.to_owned(),
+        lifecycle_status: query.lifecycle_status.to_owned(),
+        time_frame: query.time_frame.to_owned(),
+        start_date: query.start_date.to_owned(),
+        end_date: query.end_date.to_owned(),
+        time_zone: query.tz.to_owned(),
+        category: query.category.to_owned(),
+        latitude: query.lat.to_owned(),
+        longitude: query.lng.to_owned(),
+        radius_km: query.radius_km.to_owned(),
+        sort: query.sort.to_owned(),
+    };
+
+    let result =
+        event::resources::find::execute(state.event_repository.clone(), filter, page, page_size)
+            .await?;
 
     if let Some((state, count)) = result {
         let response = ApiResponse::<ResponseDetailEvent>::new(
@@ -89,14 +91,25 @@ async fn handler(
 #[cfg(test)]
 mod tests {
     use actix_web::{http::StatusCode, test};
+    use chrono::{Duration, TimeZone, Utc};
+    use chrono_tz::Tz;
 
     use crate::{
         api::{
             resources::event::{dto, routes::init_routes},
-            tests::utils::get_app,
-            utils::response::ApiResponse,
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::{
+            categories::{model::CategoryCreateModel, repository::CategoryRepository},
+            city::{model::CityCreateModel, repository::CityRepository},
+            event::{model::EventCreateModel, repository::EventRepository},
+            event_series::{
+                model::{EventOccurrenceUpsertModel, EventSeriesCreateModel},
+                repository::EventSeriesRepository,
+            },
+            group::{model::GroupCreateModel, repository::GroupRepository},
         },
-        domain::{event::{model::EventCreateModel, repository::EventRepository}},
     };
 
     #[actix_web::test]
@@ -107,7 +120,7 @@ mod tests {
         let event_model = EventCreateModel::mock_default();
         repositories
             .event_repository
-            .insert(&event_model.clone())
+            .insert(&event_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -130,7 +143,7 @@ mod tests {
         let event_model = EventCreateModel::mock_default();
         repositories
             .event_repository
-            .insert(&event_model.clone())
+            .insert(&event_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -151,6 +164,121 @@ mod tests {
         assert!(!response_event_finded.records.is_empty());
     }
 
+    #[actix_web::test]
+    async fn it_should_return_events_finded_by_text_ranked_with_snippets() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let suffix = random_string(12).to_lowercase();
+        let mut in_description_model = EventCreateModel::mock_default();
+        in_description_model.name = format!("Monthly Meetup {suffix}");
+        in_description_model.description = format!("A talk about LLM evaluation {suffix}");
+        let mut in_name_model = EventCreateModel::mock_default();
+        in_name_model.name = format!("Evaluation Night {suffix}");
+        let mut unrelated_model = EventCreateModel::mock_default();
+        unrelated_model.name = format!("Salsa Night {suffix}");
+        for event_model in [&in_description_model, &in_name_model, &unrelated_model] {
+            repositories
+                .event_repository
+                .insert(event_model, TEST_ACTOR)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?q=evaluations%20{suffix}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 2);
+        assert_eq!(response.records[0].name, in_name_model.name);
+        assert_eq!(response.records[1].name, in_description_model.name);
+        assert!(response.records[1]
+            .snippet
+            .as_ref()
+            .unwrap()
+            .contains("<mark>evaluation</mark>"));
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_events_finded_by_similar_name_when_fuzzy() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let suffix = random_string(12);
+        let mut exact_model = EventCreateModel::mock_default();
+        exact_model.name = format!("Salsa Night {suffix}");
+        let mut similar_model = EventCreateModel::mock_default();
+        similar_model.name = format!("Salsa Nite {suffix}");
+        for event_model in [&similar_model, &exact_model] {
+            repositories
+                .event_repository
+                .insert(event_model, TEST_ACTOR)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name=salsa%20nigth%20{suffix}"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name=salsa%20night%20{suffix}&fuzzy=true"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 2);
+        assert_eq!(response.records[0].name, exact_model.name);
+        assert_eq!(response.records[1].name, similar_model.name);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_event_finded_by_category() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.categoryids = vec![category.id];
+        repositories
+            .event_repository
+            .insert(&event_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?category={}", category.id))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response_event_finded: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_event_finded.records.len(), 1);
+    }
+
     #[actix_web::test]
     async fn it_should_return_event_no_content() {
         let (_, app) = get_app(init_routes).await;
@@ -176,4 +304,478 @@ mod tests {
 
         assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
     }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_relative_time_frame_has_dates() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri("/event?time_frame=ThisWeekend&start_date=2024-03-09T00:00:00Z")
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_events_within_radius() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let name = random_string(12);
+        let mut nearby_model = EventCreateModel::mock_default();
+        nearby_model.name = name.clone();
+        nearby_model.latitude = Some(49.2827);
+        nearby_model.longitude = Some(-123.1207);
+        let nearby = repositories
+            .event_repository
+            .insert(&nearby_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut faraway_model = EventCreateModel::mock_default();
+        faraway_model.name = name.clone();
+        faraway_model.latitude = Some(48.4284);
+        faraway_model.longitude = Some(-123.3656);
+        repositories
+            .event_repository
+            .insert(&faraway_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/event?name={name}&lat=49.2827&lng=-123.1207&radius_km=25&sort=distance"
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].eventid, nearby.eventid);
+        assert!(response.records[0].distance_km.unwrap() < 0.001);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_only_lat_provided() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri("/event?lat=49.2827&radius_km=25")
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_evaluate_today_in_requested_time_zone() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed: the first and last minute of today in Kiritimati (UTC+14)
+        let tz: Tz = "Pacific/Kiritimati".parse().unwrap();
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        let name = random_string(12);
+        for (hour, minute) in [(0, 1), (23, 59)] {
+            let mut event_model = EventCreateModel::mock_default();
+            event_model.name = name.clone();
+            event_model.time = tz
+                .from_local_datetime(&today.and_hms_opt(hour, minute, 0).unwrap())
+                .unwrap()
+                .with_timezone(&Utc);
+            repositories
+                .event_repository
+                .insert(&event_model, TEST_ACTOR)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/event?name={name}&time_frame=Today&tz=Pacific/Kiritimati"
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 2);
+        assert!(response
+            .records
+            .iter()
+            .all(|i| i.local_start.offset().local_minus_utc() == 14 * 3600));
+
+        // The two instants are almost 24 hours apart, so no UTC day holds both
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&time_frame=Today&tz=UTC"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        if res.status().is_success() {
+            let body = test::read_body(res).await;
+            let response: ApiResponse<dto::ResponseDetailEvent> =
+                serde_json::from_slice(&body).unwrap();
+
+            assert!(response.records.len() < 2);
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_should_default_to_city_time_zone() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let mut city_model = CityCreateModel::mock_default();
+        city_model.time_zone = Some("America/Vancouver".to_string());
+        let city = repositories
+            .city_repository
+            .insert(&city_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut group_model = GroupCreateModel::mock_default();
+        group_model.cityid = city.cityid;
+        let group = repositories
+            .group_repository
+            .insert(&group_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.name = random_string(12);
+        event_model.groupid = group.groupid;
+        repositories
+            .event_repository
+            .insert(&event_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={}", event_model.name))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+        let event = response.records.first().unwrap();
+
+        assert_eq!(event.time_zone, "America/Vancouver");
+        assert!([-7 * 3600, -8 * 3600].contains(&event.local_start.offset().local_minus_utc()));
+        assert_eq!(
+            (event.local_end - event.local_start).num_seconds(),
+            i64::from(event.duration)
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_time_zone_is_invalid() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri("/event?tz=Mars/Olympus_Mons")
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_expand_event_series_into_occurrences() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed: five weekly occurrences, the second excluded, the third
+        //cancelled and the fourth rescheduled by an hour
+        let start = Utc.timestamp_opt(Utc::now().timestamp() + 86400, 0).unwrap();
+        let week = Duration::weeks(1);
+        let name = random_string(12);
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.name = name.clone();
+        event_model.time = start;
+        let event = repositories
+            .event_repository
+            .insert(&event_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut event_series_model = EventSeriesCreateModel::mock_default();
+        event_series_model.eventid = event.eventid;
+        event_series_model.rrule = "FREQ=WEEKLY;COUNT=5".to_string();
+        event_series_model.exdates = vec![start + week];
+        let event_series = repositories
+            .event_series_repository
+            .insert(&event_series_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut cancelled = EventOccurrenceUpsertModel::mock_default();
+        cancelled.occurrence = start + week * 2;
+        repositories
+            .event_series_repository
+            .upsert_occurrence(&event_series.seriesid, &cancelled, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut rescheduled = EventOccurrenceUpsertModel::mock_default();
+        rescheduled.occurrence = start + week * 3;
+        rescheduled.cancelled = false;
+        rescheduled.time = Some(start + week * 3 + Duration::hours(1));
+        rescheduled.location = Some("Library".to_string());
+        repositories
+            .event_series_repository
+            .upsert_occurrence(&event_series.seriesid, &rescheduled, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&status=Upcomming"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        let mut times: Vec<_> = response.records.iter().map(|i| i.time).collect();
+        times.sort();
+        assert_eq!(
+            times,
+            vec![start, start + week * 3 + Duration::hours(1), start + week * 4]
+        );
+        assert!(response
+            .records
+            .iter()
+            .all(|i| i.eventid == event.eventid && i.seriesid == Some(event_series.seriesid)));
+
+        let moved = response
+            .records
+            .iter()
+            .find(|i| i.occurrence == Some(start + week * 3))
+            .unwrap();
+        assert_eq!(moved.location, "Library");
+
+        // Only the last occurrence falls within a custom time frame around it
+        let format = "%Y-%m-%dT%H:%M:%SZ";
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/event?name={name}&time_frame=Custom&start_date={}&end_date={}",
+                (start + week * 4 - Duration::days(1)).format(format),
+                (start + week * 4 + Duration::days(1)).format(format),
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].time, start + week * 4);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_events_sorted() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let name = random_string(12);
+        let mut earlier_model = EventCreateModel::mock_default();
+        earlier_model.name = name.clone();
+        earlier_model.time = Utc::now() + Duration::days(1);
+        earlier_model.yes_rsvp_count = 1;
+        let earlier = repositories
+            .event_repository
+            .insert(&earlier_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut later_model = EventCreateModel::mock_default();
+        later_model.name = name.clone();
+        later_model.time = Utc::now() + Duration::days(2);
+        later_model.yes_rsvp_count = 10;
+        let later = repositories
+            .event_repository
+            .insert(&later_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&sort=time"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].eventid, earlier.eventid);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&sort=-yes_rsvp_count"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].eventid, later.eventid);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_sort_is_unknown() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri("/event?sort=-location")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+
+        let req = test::TestRequest::get()
+            .uri("/event?sort=distance")
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_events_finded_by_city_and_state() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed: events with the same name in a Quebec city and in another state
+        let name = random_string(12);
+        let mut cities = vec![];
+        for stateid in [9, 2] {
+            let mut city_model = CityCreateModel::mock_default();
+            city_model.stateid = stateid;
+            let city = repositories
+                .city_repository
+                .insert(&city_model, TEST_ACTOR)
+                .await
+                .unwrap();
+            let mut group_model = GroupCreateModel::mock_default();
+            group_model.cityid = city.cityid;
+            let group = repositories
+                .group_repository
+                .insert(&group_model, TEST_ACTOR)
+                .await
+                .unwrap();
+            let mut event_model = EventCreateModel::mock_default();
+            event_model.name = name.clone();
+            event_model.groupid = group.groupid;
+            repositories
+                .event_repository
+                .insert(&event_model, TEST_ACTOR)
+                .await
+                .unwrap();
+            cities.push(city);
+        }
+        let city = &cities[0];
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&city_slug={}", city.slug))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].city_slug.as_ref(), Some(&city.slug));
+        assert_eq!(response.records[0].city_name.as_ref(), Some(&city.name));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&state_symbol=QC"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 1);
+        assert_eq!(response.records[0].state_symbol.as_deref(), Some("qc"));
+        assert_eq!(response.records[0].state_name.as_deref(), Some("Quebec"));
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_events_finded_by_max_price() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed: a free event, a $10-$25 event, a $40 event and one only flagged with a fee
+        let name = random_string(12);
+        for (fee, price_min, price_max) in [
+            (false, Some(0), Some(0)),
+            (true, Some(1000), Some(2500)),
+            (true, Some(4000), Some(4000)),
+            (true, None, None),
+        ] {
+            let mut event_model = EventCreateModel::mock_default();
+            event_model.name = name.clone();
+            event_model.fee = fee;
+            event_model.price_min = price_min;
+            event_model.price_max = price_max;
+            repositories
+                .event_repository
+                .insert(&event_model, TEST_ACTOR)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&max_price=2000&sort=name"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+        let mut prices: Vec<_> = response
+            .records
+            .iter()
+            .map(|event| (event.price_min, event.price_max, event.fee))
+            .collect();
+        prices.sort();
+
+        assert_eq!(
+            prices,
+            vec![(Some(0), Some(0), false), (Some(1000), Some(2500), true)]
+        );
+        assert!(response.records.iter().all(|event| event.currency == "CAD"));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={name}&has_fee=true"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailEvent> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records.len(), 3);
+    }
 }
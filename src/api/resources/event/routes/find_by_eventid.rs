@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::event::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::event::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::event::{model::EventCreateModel, repository::EventRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let event_model = EventCreateModel::mock_default();
         let event = repositories
             .event_repository
-            .insert(&event_model.clone())
+            .insert(&event_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
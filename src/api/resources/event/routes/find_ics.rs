@@ -0,0 +1,107 @@
+use actix_web::{
+    get,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{config::get_config, lib::AppState, utils::ics},
+    domain::{error::DomainError, event},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_event_ics",
+    path = "/event/{event_id}.ics",
+    tag = "event",
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+    ),
+    responses(
+         (status = 200, description = "Event as an iCalendar VEVENT", content_type = "text/calendar", body = String),
+         (status = 404, description = "Event not found", body = ErrorResponse),
+    ),
+ )]
+#[get("/event/{event_id:\\d+}.ics")]
+async fn handler(
+    app_state: Data<AppState>,
+    param: web::Path<i32>,
+) -> Result<HttpResponse, DomainError> {
+    let result = event::resources::find_by_eventid::execute(
+        app_state.event_repository.clone(),
+        param.to_owned(),
+    )
+    .await?;
+
+    if let Some(event) = result {
+        let calendar = ics::render_calendar(None, &[(&event).into()], &get_config().ics_uid_domain);
+
+        return Ok(HttpResponse::Ok()
+            .content_type(ics::CONTENT_TYPE)
+            .body(calendar));
+    }
+
+    Err(DomainError::NotFound(String::from("Event id not found")))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            config::get_config, resources::event::routes::init_routes, tests::utils::{get_app, TEST_ACTOR},
+            utils::random_number,
+        },
+        domain::event::{model::EventCreateModel, repository::EventRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_event_as_icalendar() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.name = "Rust, Tea; and \\ Cake".to_string();
+        event_model.description = "Line one\nLine two ".repeat(10);
+        let event = repositories
+            .event_repository
+            .insert(&event_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event/{}.ics", event.eventid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            "text/calendar; charset=utf-8"
+        );
+
+        let body = String::from_utf8(test::read_body(res).await.to_vec()).unwrap();
+        assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(body.contains(&format!(
+            "UID:{}@{}\r\n",
+            event.extid,
+            get_config().ics_uid_domain
+        )));
+        assert!(body.contains("SUMMARY:Rust\\, Tea\\; and \\\\ Cake\r\n"));
+        assert!(body.contains("DESCRIPTION:Line one\\nLine two Line one"));
+        assert!(body.split("\r\n").all(|line| line.len() <= 75));
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event/{}.ics", random_number()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
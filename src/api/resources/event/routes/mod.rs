@@ -1,14 +1,29 @@
 use actix_web::web;
 
+use crate::api::middleware::role::{role_policy, Role};
+
+pub mod bulk;
 pub mod create;
 pub mod delete_by_eventid;
 pub mod find;
 pub mod find_by_eventid;
+pub mod find_ics;
 pub mod update_by_eventid;
+pub mod update_lifecycle_by_eventid;
+pub mod upsert_by_extid;
+
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(UpsertPolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(DeletePolicy, [Role::Admin, Role::Editor]);
 
 pub fn init_routes(config: &mut web::ServiceConfig) {
+    config.service(bulk::handler);
     config.service(create::handler);
+    config.service(upsert_by_extid::handler);
     config.service(update_by_eventid::handler);
+    config.service(update_lifecycle_by_eventid::handler);
+    config.service(find_ics::handler);
     config.service(find_by_eventid::handler);
     config.service(find::handler);
     config.service(delete_by_eventid::handler);
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::event::dto::{self, ResponseEvent},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_event",
     path = "/event/{event_id}",
     tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("event_id" = i32, Path, description = "Event uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "Event updated",  body = ApiResponseEvent),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "Event not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/event/{event_id}")]
+#[put("/event/{event_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateEvent>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,8 @@ async fn handler(
 
     let event: event::model::EventModel = event::resources::update_by_eventid::execute(
         state.event_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +68,7 @@ mod tests {
     use crate::{
         api::{
             resources::event::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::event::{model::EventCreateModel, repository::EventRepository},
@@ -72,7 +82,7 @@ mod tests {
         let event_model = EventCreateModel::mock_default();
         let event = repositories
             .event_repository
-            .insert(&event_model.clone())
+            .insert(&event_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
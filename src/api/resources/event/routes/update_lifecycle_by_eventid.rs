@@ -0,0 +1,203 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event::dto::{self, ResponseEvent},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, event},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "update_event_lifecycle",
+    path = "/event/{event_id}/lifecycle",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("event_id" = i32, Path, description = "Event id"),
+    ),
+    request_body = RequestUpdateEventLifecycle,
+    responses(
+         (status = 200, description = "Event lifecycle status updated",  body = ApiResponseEvent),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 404, description = "Event not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/event/{event_id}/lifecycle", wrap = "super::UpdatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+    body: web::Json<dto::RequestUpdateEventLifecycle>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let event: event::model::EventModel = event::resources::update_lifecycle_by_eventid::execute(
+        state.event_repository.clone(),
+        actor.0,
+        param.to_owned(),
+        body.0.into(),
+    )
+    .await?;
+
+    let response = ApiResponse::<ResponseEvent>::new(vec![event.into()], None, None, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::event::{dto, routes::init_routes},
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_number, random_string, response::ApiResponse},
+        },
+        domain::event::{
+            model::{EventCreateModel, EventLifecycleStatus},
+            repository::EventRepository,
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_cancel_event_and_leave_it_out_of_listings() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.name = random_string(12);
+        let event = repositories
+            .event_repository
+            .insert(&event_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/{}/lifecycle", event.eventid))
+            .set_json(dto::RequestUpdateEventLifecycle::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let cancelled = response.records.first().unwrap();
+
+        assert_eq!(cancelled.lifecycle_status, EventLifecycleStatus::Cancelled);
+        assert_eq!(cancelled.lifecycle_reason.as_deref(), Some("Venue closed"));
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event?name={}", event_model.name))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/event?name={}&lifecycle_status=cancelled",
+                event_model.name
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event/{}", event.eventid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn it_should_reschedule_event_to_another_event() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let replacement = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut request = dto::RequestUpdateEventLifecycle::mock_default();
+        request.lifecycle_status = EventLifecycleStatus::Rescheduled;
+        request.rescheduled_to = Some(replacement.eventid);
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/{}/lifecycle", event.eventid))
+            .set_json(request)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let rescheduled = response.records.first().unwrap();
+
+        assert_eq!(
+            rescheduled.lifecycle_status,
+            EventLifecycleStatus::Rescheduled
+        );
+        assert_eq!(rescheduled.rescheduled_to, Some(replacement.eventid));
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_rescheduled_without_target() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut request = dto::RequestUpdateEventLifecycle::mock_default();
+        request.lifecycle_status = EventLifecycleStatus::Rescheduled;
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/{}/lifecycle", event.eventid))
+            .set_json(request)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_error_when_event_is_missing() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/{}/lifecycle", random_number()))
+            .set_json(dto::RequestUpdateEventLifecycle::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
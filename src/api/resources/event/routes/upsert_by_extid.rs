@@ -0,0 +1,148 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event::dto::{self, ResponseEvent},
+        utils::response::upsert_response,
+    },
+    domain::{error::DomainError, event},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "upsert_event_by_extid",
+    path = "/event/extid/{extid}",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("extid" = String, Path, description = "External identifier"),
+    ),
+    request_body = RequestUpdateEvent,
+    responses(
+         (status = 201, description = "Event created",  body = ApiResponseEvent,
+            headers(("X-Upsert-Result" = String, description = "created"))),
+         (status = 200, description = "Event updated, or left unchanged when it already matched",  body = ApiResponseEvent,
+            headers(("X-Upsert-Result" = String, description = "updated or unchanged"))),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/event/extid/{extid}", wrap = "super::UpsertPolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<String>,
+    body: web::Json<dto::RequestUpdateEvent>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let upserted = event::resources::upsert_by_extid::execute(
+        state.event_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
+        param.into_inner(),
+        body.0.into(),
+    )
+    .await?;
+
+    Ok(upsert_response::<ResponseEvent>(
+        upserted.outcome,
+        upserted.record.into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::api::{
+        resources::event::{dto, routes::init_routes},
+        tests::utils::get_app,
+        utils::{
+            random_string,
+            response::{ApiResponse, UPSERT_RESULT_HEADER},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_create_update_and_skip_unchanged_event() {
+        let (_, app) = get_app(init_routes).await;
+
+        let uri = format!("/event/extid/{}", random_string(12));
+        let request_update_event = dto::RequestUpdateEvent::mock_default();
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_event.clone())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(UPSERT_RESULT_HEADER).unwrap(), "created");
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let created = response.records.first().unwrap();
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_event.clone())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(UPSERT_RESULT_HEADER).unwrap(),
+            "unchanged"
+        );
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let unchanged = response.records.first().unwrap();
+
+        assert_eq!(unchanged.eventid, created.eventid);
+        assert_eq!(unchanged.updated_at, created.updated_at);
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_event.with_name("Renamed event"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(res.headers().get(UPSERT_RESULT_HEADER).unwrap(), "updated");
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseEvent> = serde_json::from_slice(&body).unwrap();
+        let updated = response.records.first().unwrap();
+
+        assert_eq!(updated.eventid, created.eventid);
+        assert_eq!(updated.name, "Renamed event");
+        assert_ne!(updated.updated_at, created.updated_at);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_payload_is_invalid() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/extid/{}", random_string(12)))
+            .set_json(dto::RequestUpdateEvent::mock_default().with_name(&random_string(65)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use validator::Validate;
+
+#[cfg(test)]
+use crate::api::utils::random_number;
+
+use crate::{
+    api::utils::validator::{validate_rrule, validate_time_zone},
+    domain::event_series::model::{
+        EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesCreateModel, EventSeriesModel,
+        EventSeriesUpdateModel,
+    },
+};
+
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RequestCreateEventSeries {
+    /// Event holding the details and first occurrence of the series.
+    pub eventid: i32,
+    /// RFC 5545 recurrence rule, e.g. `FREQ=WEEKLY;BYDAY=TU`.
+    #[validate(length(max = 256), custom = "validate_rrule")]
+    pub rrule: String,
+    /// Occurrence starts left out of the series.
+    #[serde(default)]
+    #[validate(length(max = 366))]
+    pub exdates: Vec<DateTime<Utc>>,
+    /// IANA time zone the rule is expanded in. Defaults to the time zone of
+    /// the event's city, then UTC.
+    #[validate(custom = "validate_time_zone")]
+    pub time_zone: Option<String>,
+}
+impl From<RequestCreateEventSeries> for EventSeriesCreateModel {
+    fn from(value: RequestCreateEventSeries) -> Self {
+        EventSeriesCreateModel::new(value.eventid, value.rrule, value.exdates, value.time_zone)
+    }
+}
+#[cfg(test)]
+impl RequestCreateEventSeries {
+    pub fn mock_default() -> Self {
+        Self {
+            eventid: random_number(),
+            rrule: "FREQ=WEEKLY".to_string(),
+            exdates: vec![],
+            time_zone: Some("America/Toronto".to_string()),
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RequestUpdateEventSeries {
+    #[validate(length(max = 256), custom = "validate_rrule")]
+    pub rrule: String,
+    #[serde(default)]
+    #[validate(length(max = 366))]
+    pub exdates: Vec<DateTime<Utc>>,
+    #[validate(custom = "validate_time_zone")]
+    pub time_zone: String,
+}
+impl From<RequestUpdateEventSeries> for EventSeriesUpdateModel {
+    fn from(value: RequestUpdateEventSeries) -> Self {
+        EventSeriesUpdateModel::new(value.rrule, value.exdates, value.time_zone)
+    }
+}
+#[cfg(test)]
+impl RequestUpdateEventSeries {
+    pub fn mock_default() -> Self {
+        Self {
+            rrule: "FREQ=MONTHLY;BYDAY=1TU".to_string(),
+            exdates: vec![],
+            time_zone: "America/Toronto".to_string(),
+        }
+    }
+}
+
+/// Overrides the fields that are set, or cancels, the occurrence starting at
+/// `occurrence`. Sending an occurrence again replaces its previous override.
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RequestUpsertEventOccurrence {
+    /// Start the series' rule gives the occurrence.
+    pub occurrence: DateTime<Utc>,
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Rescheduled start.
+    pub time: Option<DateTime<Utc>>,
+    #[validate(range(min = 0))]
+    pub duration: Option<i32>,
+    #[validate(length(max = 64))]
+    pub name: Option<String>,
+    #[validate(length(max = 512))]
+    pub description: Option<String>,
+    #[validate(length(max = 64))]
+    pub location: Option<String>,
+}
+impl From<RequestUpsertEventOccurrence> for EventOccurrenceUpsertModel {
+    fn from(value: RequestUpsertEventOccurrence) -> Self {
+        EventOccurrenceUpsertModel::new(
+            value.occurrence,
+            value.cancelled,
+            value.time,
+            value.duration,
+            value.name,
+            value.description,
+            value.location,
+        )
+    }
+}
+#[cfg(test)]
+impl RequestUpsertEventOccurrence {
+    pub fn mock_default() -> Self {
+        Self {
+            occurrence: DateTime::default(),
+            cancelled: false,
+            time: None,
+            duration: None,
+            name: Some("Special edition".to_string()),
+            description: None,
+            location: None,
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseEventOccurrence {
+    pub occurrence: DateTime<Utc>,
+    pub cancelled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+impl From<EventOccurrenceModel> for ResponseEventOccurrence {
+    fn from(value: EventOccurrenceModel) -> Self {
+        Self {
+            occurrence: value.occurrence,
+            cancelled: value.cancelled,
+            time: value.time,
+            duration: value.duration,
+            name: value.name,
+            description: value.description,
+            location: value.location,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseEventSeries {
+    pub seriesid: i32,
+    pub eventid: i32,
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    pub time_zone: String,
+    /// Start of the first occurrence.
+    pub time: DateTime<Utc>,
+    pub occurrences: Vec<ResponseEventOccurrence>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+impl From<EventSeriesModel> for ResponseEventSeries {
+    fn from(value: EventSeriesModel) -> Self {
+        Self {
+            seriesid: value.seriesid,
+            eventid: value.eventid,
+            rrule: value.rrule,
+            exdates: value.exdates,
+            time_zone: value.time_zone,
+            time: value.time,
+            occurrences: value.occurrences.into_iter().map(|o| o.into()).collect(),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use actix_web::{
+    post,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event_series::dto::{self, ResponseEventSeries},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, event_series},
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "create_event_series",
+    path = "/event/series",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    request_body = RequestCreateEventSeries,
+    responses(
+         (status = 201, description = "Event series created",  body = ApiResponseEventSeries),
+         (status = 400, description = "Invalid payload or event already has a series",  body = ErrorResponse),
+         (status = 404, description = "Event not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[post("/event/series", wrap = "super::CreatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    body: web::Json<dto::RequestCreateEventSeries>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let event_series = event_series::resources::create::execute(
+        state.event_series_repository.clone(),
+        state.event_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
+
+    let response =
+        ApiResponse::<ResponseEventSeries>::new(vec![event_series.into()], None, None, None);
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::event_series::{
+                dto::{self, ResponseEventSeries},
+                routes::init_routes,
+            },
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::response::ApiResponse,
+        },
+        domain::event::{model::EventCreateModel, repository::EventRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_event_series_created() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut request_create_event_series = dto::RequestCreateEventSeries::mock_default();
+        request_create_event_series.eventid = event.eventid;
+        request_create_event_series.time_zone = None;
+
+        let req = test::TestRequest::post()
+            .uri("/event/series")
+            .set_json(request_create_event_series)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<ResponseEventSeries> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.records[0].eventid, event.eventid);
+        assert_eq!(response.records[0].time_zone, "UTC");
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_rrule_is_invalid() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut request_create_event_series = dto::RequestCreateEventSeries::mock_default();
+        request_create_event_series.eventid = event.eventid;
+        request_create_event_series.rrule = "FREQ=WEEKLY;BYDAY=XX".to_string();
+
+        let req = test::TestRequest::post()
+            .uri("/event/series")
+            .set_json(request_create_event_series)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_when_event_does_not_exist() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut request_create_event_series = dto::RequestCreateEventSeries::mock_default();
+        request_create_event_series.eventid = i32::MAX;
+
+        let req = test::TestRequest::post()
+            .uri("/event/series")
+            .set_json(request_create_event_series)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,104 @@
+use actix_web::{
+    delete,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{lib::AppState, middleware::actor::Actor},
+    domain::{error::DomainError, event_series},
+};
+
+#[utoipa::path(
+    delete,
+    operation_id = "delete_event_series",
+    path = "/event/series/{series_id}",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("series_id" = i32, Path, description = "Series id"),
+    ),
+    responses(
+         (status = 204, description = "Event series deleted, its event is kept as a single event"),
+         (status = 404, description = "Event series not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[delete("/event/series/{series_id}", wrap = "super::DeletePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+) -> Result<HttpResponse, DomainError> {
+    event_series::resources::delete_by_seriesid::execute(
+        state.event_series_repository.clone(),
+        actor.0,
+        param.to_owned(),
+    )
+    .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::event_series::routes::init_routes, tests::utils::{get_app, TEST_ACTOR},
+            utils::random_number,
+        },
+        domain::{
+            event::{model::EventCreateModel, repository::EventRepository},
+            event_series::{model::EventSeriesCreateModel, repository::EventSeriesRepository},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_void_event_series_deleted() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut event_series_model = EventSeriesCreateModel::mock_default();
+        event_series_model.eventid = event.eventid;
+        let event_series = repositories
+            .event_series_repository
+            .insert(&event_series_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/event/series/{}", event_series.seriesid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+        assert!(repositories
+            .event_series_repository
+            .find_by_seriesid(&event_series.seriesid)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_error_when_deleted_because_invalid_id() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/event/series/{}", random_number()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,101 @@
+use actix_web::{
+    get,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        lib::AppState, resources::event_series::dto::ResponseEventSeries,
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, event_series},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_event_series_by_seriesid",
+    path = "/event/series/{series_id}",
+    tag = "event",
+    params(
+        ("series_id" = i32, Path, description = "Series id"),
+    ),
+    responses(
+         (status = 200, description = "Event series finded",  body = ApiResponseEventSeries),
+         (status = 204, description = "Event series no content"),
+    ),
+ )]
+#[get("/event/series/{series_id}")]
+async fn handler(
+    state: Data<AppState>,
+    param: web::Path<i32>,
+) -> Result<HttpResponse, DomainError> {
+    let result = event_series::resources::find_by_seriesid::execute(
+        state.event_series_repository.clone(),
+        param.to_owned(),
+    )
+    .await?;
+
+    if let Some(event_series) = result {
+        let response =
+            ApiResponse::<ResponseEventSeries>::new(vec![event_series.into()], None, None, None);
+
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::event_series::routes::init_routes, tests::utils::{get_app, TEST_ACTOR},
+            utils::random_number,
+        },
+        domain::{
+            event::{model::EventCreateModel, repository::EventRepository},
+            event_series::{model::EventSeriesCreateModel, repository::EventSeriesRepository},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_event_series_finded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut event_series_model = EventSeriesCreateModel::mock_default();
+        event_series_model.eventid = event.eventid;
+        let event_series = repositories
+            .event_series_repository
+            .insert(&event_series_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event/series/{}", event_series.seriesid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_no_content() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/event/series/{}", random_number()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+}
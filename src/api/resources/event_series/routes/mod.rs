@@ -0,0 +1,21 @@
+use actix_web::web;
+
+use crate::api::middleware::role::{role_policy, Role};
+
+pub mod create;
+pub mod delete_by_seriesid;
+pub mod find_by_seriesid;
+pub mod update_by_seriesid;
+pub mod upsert_occurrence;
+
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(DeletePolicy, [Role::Admin, Role::Editor]);
+
+pub fn init_routes(config: &mut web::ServiceConfig) {
+    config.service(create::handler);
+    config.service(update_by_seriesid::handler);
+    config.service(upsert_occurrence::handler);
+    config.service(find_by_seriesid::handler);
+    config.service(delete_by_seriesid::handler);
+}
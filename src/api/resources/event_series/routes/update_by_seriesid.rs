@@ -0,0 +1,126 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event_series::dto::{self, ResponseEventSeries},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, event_series},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "update_event_series",
+    path = "/event/series/{series_id}",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("series_id" = i32, Path, description = "Series id"),
+    ),
+    request_body = RequestUpdateEventSeries,
+    responses(
+         (status = 200, description = "Event series updated",  body = ApiResponseEventSeries),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 404, description = "Event series not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/event/series/{series_id}", wrap = "super::UpdatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+    body: web::Json<dto::RequestUpdateEventSeries>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let event_series = event_series::resources::update_by_seriesid::execute(
+        state.event_series_repository.clone(),
+        actor.0,
+        param.to_owned(),
+        body.0.into(),
+    )
+    .await?;
+
+    let response =
+        ApiResponse::<ResponseEventSeries>::new(vec![event_series.into()], None, None, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::event_series::{
+                dto::{self, ResponseEventSeries},
+                routes::init_routes,
+            },
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_number, response::ApiResponse},
+        },
+        domain::{
+            event::{model::EventCreateModel, repository::EventRepository},
+            event_series::{model::EventSeriesCreateModel, repository::EventSeriesRepository},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_event_series_updated() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let event = repositories
+            .event_repository
+            .insert(&EventCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut event_series_model = EventSeriesCreateModel::mock_default();
+        event_series_model.eventid = event.eventid;
+        let event_series = repositories
+            .event_series_repository
+            .insert(&event_series_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/series/{}", event_series.seriesid))
+            .set_json(dto::RequestUpdateEventSeries::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<ResponseEventSeries> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.records[0].rrule, "FREQ=MONTHLY;BYDAY=1TU");
+        assert_eq!(response.records[0].time_zone, "America/Toronto");
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_error_when_updated_because_invalid_id() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/event/series/{}", random_number()))
+            .set_json(dto::RequestUpdateEventSeries::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,155 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::event_series::dto::{self, ResponseEventOccurrence},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, event_series},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "upsert_event_occurrence",
+    path = "/event/series/{series_id}/occurrence",
+    tag = "event",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("series_id" = i32, Path, description = "Series id"),
+    ),
+    request_body = RequestUpsertEventOccurrence,
+    responses(
+         (status = 200, description = "Occurrence overridden or cancelled",  body = ApiResponseEventOccurrence),
+         (status = 400, description = "Invalid payload or not an occurrence of the series",  body = ErrorResponse),
+         (status = 404, description = "Event series not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/event/series/{series_id}/occurrence", wrap = "super::UpdatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+    body: web::Json<dto::RequestUpsertEventOccurrence>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let event_occurrence = event_series::resources::upsert_occurrence::execute(
+        state.event_series_repository.clone(),
+        actor.0,
+        param.to_owned(),
+        body.0.into(),
+    )
+    .await?;
+
+    let response = ApiResponse::<ResponseEventOccurrence>::new(
+        vec![event_occurrence.into()],
+        None,
+        None,
+        None,
+    );
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        api::{
+            resources::event_series::{dto, routes::init_routes},
+            tests::utils::{get_app, Repositories, TEST_ACTOR},
+        },
+        domain::{
+            event::{model::EventCreateModel, repository::EventRepository},
+            event_series::{
+                model::{EventSeriesCreateModel, EventSeriesModel},
+                repository::EventSeriesRepository,
+            },
+        },
+    };
+
+    async fn seed_series(repositories: &Repositories) -> EventSeriesModel {
+        let mut event_model = EventCreateModel::mock_default();
+        event_model.time = Utc::now();
+        let event = repositories
+            .event_repository
+            .insert(&event_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut event_series_model = EventSeriesCreateModel::mock_default();
+        event_series_model.eventid = event.eventid;
+        repositories
+            .event_series_repository
+            .insert(&event_series_model, TEST_ACTOR)
+            .await
+            .unwrap()
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_occurrence_overridden() {
+        let (repositories, app) = get_app(init_routes).await;
+        let event_series = seed_series(&repositories).await;
+
+        let mut request_upsert_event_occurrence = dto::RequestUpsertEventOccurrence::mock_default();
+        request_upsert_event_occurrence.occurrence = event_series.time + Duration::weeks(2);
+
+        let req = test::TestRequest::put()
+            .uri(&format!(
+                "/event/series/{}/occurrence",
+                event_series.seriesid
+            ))
+            .set_json(request_upsert_event_occurrence)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+
+        let event_series = repositories
+            .event_series_repository
+            .find_by_seriesid(&event_series.seriesid)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event_series.occurrences.len(), 1);
+        assert_eq!(
+            event_series.occurrences[0].name.as_deref(),
+            Some("Special edition")
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_not_an_occurrence() {
+        let (repositories, app) = get_app(init_routes).await;
+        let event_series = seed_series(&repositories).await;
+
+        let mut request_upsert_event_occurrence = dto::RequestUpsertEventOccurrence::mock_default();
+        request_upsert_event_occurrence.occurrence = event_series.time + Duration::days(3);
+
+        let req = test::TestRequest::put()
+            .uri(&format!(
+                "/event/series/{}/occurrence",
+                event_series.seriesid
+            ))
+            .set_json(request_upsert_event_occurrence)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -12,7 +12,9 @@ use crate::api::utils::{
 
 use crate::{
     api::utils::{validator::validate_page_size_max},
+    api::resources::{categories::dto::ResponseCategory, organizer::dto::ResponseOrganizer},
     domain::group::model::{GroupCreateModel, GroupModel, GroupUpdateModel, ImageLinks, GroupPageModel, DetailedGroup},
+    repository::group::GroupSortOption,
 };
 
 #[cfg_attr(test, derive(Serialize))]
@@ -30,8 +32,11 @@ pub struct RequestCreateGroup {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    #[validate(length(max = 100))]
-    pub organizer: String,
+    #[validate(length(max = 16))]
+    pub organizerids: Vec<i32>,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -50,7 +55,8 @@ impl From<RequestCreateGroup> for GroupCreateModel {
             value.private,
             value.members,
             value.cityid,
-            value.organizer,
+            value.organizerids,
+            value.categoryids,
             ImageLinks{
                 highres_link:   value.highres_link,
                 photo_link: value.photo_link,
@@ -67,7 +73,8 @@ impl RequestCreateGroup {
             description: "The Big Group".to_string(),
             extid: random_string(10),
             slug: random_string(10),
-            organizer: "organizer".to_string(),
+            organizerids: vec![],
+            categoryids: vec![],
             active: true,
             private: true,
             members: 100,
@@ -92,8 +99,11 @@ pub struct RequestUpdateGroup {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    #[validate(length(max = 100))]
-    pub organizer: String,
+    #[validate(length(max = 16))]
+    pub organizerids: Vec<i32>,
+    #[serde(default)]
+    #[validate(length(max = 16))]
+    pub categoryids: Vec<i32>,
     #[validate(length(max = 512))]
     pub highres_link: Option<String>,
     #[validate(length(max = 512))]
@@ -111,7 +121,8 @@ impl From<RequestUpdateGroup> for GroupUpdateModel {
             value.private,
             value.members,
             value.cityid,
-            value.organizer,
+            value.organizerids,
+            value.categoryids,
             ImageLinks{
                 highres_link:   value.highres_link,
                 photo_link: value.photo_link,
@@ -127,7 +138,8 @@ impl RequestUpdateGroup {
             name: random_string(10),
             description: "The Big Group".to_string(),
             slug: random_string(10),
-            organizer: "organizer".to_string(),
+            organizerids: vec![],
+            categoryids: vec![],
             active: true,
             private: true,
             members: 100,
@@ -150,6 +162,13 @@ pub struct RequestFindGroup {
     pub city: Option<String>,
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
+    pub category: Option<i32>,
+    /// One of `name`, `members` or `event_count`, prefixed with `-` for
+    /// descending order. Defaults to `name`.
+    pub sort: Option<GroupSortOption>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
@@ -167,7 +186,8 @@ pub struct ResponseGroup {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    pub organizer: String,
+    pub organizers: Vec<ResponseOrganizer>,
+    pub categories: Vec<ResponseCategory>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub highres_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,7 +210,8 @@ impl From<GroupModel> for ResponseGroup {
             private: value.private,
             members: value.members,
             cityid: value.cityid,
-            organizer: value.organizer,
+            organizers: value.organizers.into_iter().map(|i| i.into()).collect(),
+            categories: value.categories.into_iter().map(|i| i.into()).collect(),
             highres_link: value.highres_link,
             photo_link: value.photo_link,
             thumb_link: value.thumb_link,
@@ -252,7 +273,8 @@ pub struct ApiResponseDetailGroup {
     pub members: i32,
     pub city_name: String,
     pub state_symbol: String,
-    pub organizer: String,
+    pub organizers: Vec<ResponseOrganizer>,
+    pub categories: Vec<ResponseCategory>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub highres_link: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,7 +298,8 @@ impl From<DetailedGroup> for ApiResponseDetailGroup {
             members: value.group.members,
             city_name: value.city.name,
             state_symbol: value.state.symbol,
-            organizer: value.group.organizer,
+            organizers: value.group.organizers.into_iter().map(|i| i.into()).collect(),
+            categories: value.group.categories.into_iter().map(|i| i.into()).collect(),
             highres_link: value.group.highres_link,
             photo_link: value.group.photo_link,
             thumb_link: value.group.thumb_link,
@@ -0,0 +1,142 @@
+use actix_web::{
+    get,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        config::get_config,
+        lib::AppState,
+        utils::ics::{self, IcsEvent},
+    },
+    domain::{
+        error::DomainError,
+        event::{self, model::EventFindFilter},
+        group,
+    },
+    repository::event::EventStatusOption,
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_group_calendar",
+    path = "/group/{slug}/calendar.ics",
+    tag = "group",
+    params(
+        ("slug" = str, Path, description = "Group slug"),
+    ),
+    responses(
+         (status = 200, description = "Upcoming events of the group as an iCalendar feed", content_type = "text/calendar", body = String),
+         (status = 404, description = "Group not found", body = ErrorResponse),
+    ),
+ )]
+#[get("/group/{slug}/calendar.ics")]
+async fn handler(
+    app_state: Data<AppState>,
+    param: web::Path<String>,
+) -> Result<HttpResponse, DomainError> {
+    let config = get_config();
+    let slug = param.into_inner();
+
+    let result =
+        group::resources::find_by_slug::execute(app_state.group_repository.clone(), slug.clone())
+            .await?;
+
+    if let Some(group) = result {
+        let events = event::resources::find::execute(
+            app_state.event_repository.clone(),
+            EventFindFilter {
+                group_slug: Some(slug),
+                status: Some(EventStatusOption::Upcomming),
+                ..Default::default()
+            },
+            1,
+            config.page_size_max,
+        )
+        .await?
+        .map(|(events, _)| events.iter().map(IcsEvent::from).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+        let calendar = ics::render_calendar(Some(&group.name), &events, &config.ics_uid_domain);
+
+        return Ok(HttpResponse::Ok()
+            .content_type(ics::CONTENT_TYPE)
+            .body(calendar));
+    }
+
+    Err(DomainError::NotFound(String::from("Group slug not found")))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        api::{resources::group::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_string},
+        domain::{
+            event::{model::EventCreateModel, repository::EventRepository},
+            group::{model::GroupCreateModel, repository::GroupRepository},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_upcoming_events_of_group() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let group = repositories
+            .group_repository
+            .insert(&GroupCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut upcoming_model = EventCreateModel::mock_default();
+        upcoming_model.groupid = group.groupid;
+        upcoming_model.time = Utc::now() + Duration::days(7);
+        let upcoming = repositories
+            .event_repository
+            .insert(&upcoming_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut past_model = EventCreateModel::mock_default();
+        past_model.groupid = group.groupid;
+        past_model.time = Utc::now() - Duration::days(7);
+        let past = repositories
+            .event_repository
+            .insert(&past_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/group/{}/calendar.ics", group.slug))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get("content-type").unwrap(),
+            "text/calendar; charset=utf-8"
+        );
+
+        let body = String::from_utf8(test::read_body(res).await.to_vec()).unwrap();
+        assert!(body.contains("X-WR-CALNAME:"));
+        assert!(body.contains(&format!("UID:{}@", upcoming.extid)));
+        assert!(!body.contains(&format!("UID:{}@", past.extid)));
+        assert_eq!(body.matches("BEGIN:VEVENT\r\n").count(), 1);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/group/{}/calendar.ics", random_string(12)))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::group::dto::{self, ResponseGroup},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,34 @@ use crate::{
     operation_id = "create_group",
     path = "/group",
     tag = "group",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateState,
     responses(
          (status = 201, description = "group created",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/group")]
+#[post("/group", wrap = "super::CreatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateGroup>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let group =
-        group::resources::create::execute(state.group_repository.clone(), body.0.into())
-            .await?;
+    let group = group::resources::create::execute(
+        state.group_repository.clone(),
+        state.organizer_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseGroup>::new(vec![group.into()], None, None, None);
 
@@ -6,7 +6,7 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{group, error::DomainError},
 };
 
@@ -15,6 +15,10 @@ use crate::{
     operation_id = "delete_group",
     path = "/group/{group_id}",
     tag = "group",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("group_id" = i32, Path, description = "group uuid"),
     ),
@@ -23,15 +27,19 @@ use crate::{
          (status = 400, description = "Invalid group id",  body = ErrorResponse),
          (status = 404, description = "group not found",  body = ErrorResponse),
          (status = 409, description = "group is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/group/{group_id}")]
+#[delete("/group/{group_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     group::resources::delete_by_groupid::execute(
         state.group_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -44,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::group::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::group::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::group::{model::GroupCreateModel, repository::GroupRepository},
     };
 
@@ -56,7 +64,7 @@ mod tests {
         let group_model = GroupCreateModel::mock_default();
         let group = repositories
             .group_repository
-            .insert(&group_model.clone())
+            .insert(&group_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
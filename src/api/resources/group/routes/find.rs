@@ -43,12 +43,18 @@ async fn handler(
         .unwrap_or(config::get_config().page_size_default);
 
     let name = query.name.to_owned();
+    let fuzzy = query.fuzzy.unwrap_or(false);
     let city = query.city.to_owned();
+    let category = query.category.to_owned();
+    let sort = query.sort.to_owned();
 
     let result = group::resources::find::execute(
         state.group_repository.clone(),
         name,
+        fuzzy,
         city,
+        category,
+        sort,
         page,
         page_size,
     )
@@ -74,10 +80,13 @@ mod tests {
     use crate::{
         api::{
             resources::group::{dto, routes::init_routes},
-            tests::utils::get_app,
-            utils::response::ApiResponse,
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::{
+            categories::{model::CategoryCreateModel, repository::CategoryRepository},
+            group::{model::GroupCreateModel, repository::GroupRepository},
         },
-        domain::group::{model::GroupCreateModel, repository::GroupRepository},
     };
 
     #[actix_web::test]
@@ -88,7 +97,7 @@ mod tests {
         let group_model = GroupCreateModel::mock_default();
         repositories
             .group_repository
-            .insert(&group_model.clone())
+            .insert(&group_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -111,7 +120,7 @@ mod tests {
         let group_model = GroupCreateModel::mock_default();
         repositories
             .group_repository
-            .insert(&group_model.clone())
+            .insert(&group_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -132,6 +141,80 @@ mod tests {
         assert!(!response_group_finded.records.is_empty());
     }
 
+    #[actix_web::test]
+    async fn it_should_return_group_finded_by_category() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let category = repositories
+            .category_repository
+            .insert(&CategoryCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut group_model = GroupCreateModel::mock_default();
+        group_model.categoryids = vec![category.id];
+        repositories
+            .group_repository
+            .insert(&group_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/group?category={}", category.id))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response_group_finded: ApiResponse<dto::ResponsePageGroup> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_group_finded.records.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_groups_sorted() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let prefix = random_string(12);
+        let mut smaller_model = GroupCreateModel::mock_default();
+        smaller_model.name = format!("{prefix} a");
+        smaller_model.members = 10;
+        let smaller = repositories
+            .group_repository
+            .insert(&smaller_model, TEST_ACTOR)
+            .await
+            .unwrap();
+        let mut larger_model = GroupCreateModel::mock_default();
+        larger_model.name = format!("{prefix} b");
+        larger_model.members = 1000;
+        let larger = repositories
+            .group_repository
+            .insert(&larger_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/group?name={prefix}&sort=name"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponsePageGroup> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].group_slug, smaller.slug);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/group?name={prefix}&sort=-members"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponsePageGroup> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response.records[0].group_slug, larger.slug);
+    }
+
     #[actix_web::test]
     async fn it_should_return_group_no_content() {
         let (_, app) = get_app(init_routes).await;
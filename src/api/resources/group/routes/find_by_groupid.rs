@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::group::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::group::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::group::{model::GroupCreateModel, repository::GroupRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let group_model = GroupCreateModel::mock_default();
         let group = repositories
             .group_repository
-            .insert(&group_model.clone())
+            .insert(&group_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
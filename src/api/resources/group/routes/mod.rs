@@ -1,16 +1,27 @@
 use actix_web::web;
 
+use crate::api::middleware::role::{role_policy, Role};
+
+pub mod calendar;
 pub mod create;
 pub mod delete_by_groupid;
 pub mod find;
 pub mod find_by_groupid;
 pub mod find_by_slug;
 pub mod update_by_groupid;
+pub mod upsert_by_extid;
+
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(UpsertPolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(DeletePolicy, [Role::Admin]);
 
 pub fn init_routes(config: &mut web::ServiceConfig) {
     config.service(create::handler);
+    config.service(upsert_by_extid::handler);
     config.service(update_by_groupid::handler);
     config.service(find_by_groupid::handler);
+    config.service(calendar::handler);
     config.service(find_by_slug::handler);
     config.service(find::handler);
     config.service(delete_by_groupid::handler);
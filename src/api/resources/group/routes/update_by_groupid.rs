@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::group::dto::{self, ResponseGroup},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_group",
     path = "/group/{group_id}",
     tag = "group",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("group_id" = i32, Path, description = "Group uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "Group updated",  body = ApiResponseGroup),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "Group not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/group/{group_id}")]
+#[put("/group/{group_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateGroup>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,9 @@ async fn handler(
 
     let group: group::model::GroupModel = group::resources::update_by_groupid::execute(
         state.group_repository.clone(),
+        state.organizer_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +69,7 @@ mod tests {
     use crate::{
         api::{
             resources::group::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::group::{model::GroupCreateModel, repository::GroupRepository},
@@ -72,7 +83,7 @@ mod tests {
         let group_model = GroupCreateModel::mock_default();
         let group = repositories
             .group_repository
-            .insert(&group_model.clone())
+            .insert(&group_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
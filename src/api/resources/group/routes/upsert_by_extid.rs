@@ -0,0 +1,151 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::group::dto::{self, ResponseGroup},
+        utils::response::upsert_response,
+    },
+    domain::{error::DomainError, group},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "upsert_group_by_extid",
+    path = "/group/extid/{extid}",
+    tag = "group",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("extid" = String, Path, description = "External identifier"),
+    ),
+    request_body = RequestUpdateGroup,
+    responses(
+         (status = 201, description = "Group created",  body = ApiResponseGroup,
+            headers(("X-Upsert-Result" = String, description = "created"))),
+         (status = 200, description = "Group updated, or left unchanged when it already matched",  body = ApiResponseGroup,
+            headers(("X-Upsert-Result" = String, description = "updated or unchanged"))),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/group/extid/{extid}", wrap = "super::UpsertPolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<String>,
+    body: web::Json<dto::RequestUpdateGroup>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let upserted = group::resources::upsert_by_extid::execute(
+        state.group_repository.clone(),
+        state.organizer_repository.clone(),
+        state.category_repository.clone(),
+        actor.0,
+        param.into_inner(),
+        body.0.into(),
+    )
+    .await?;
+
+    Ok(upsert_response::<ResponseGroup>(
+        upserted.outcome,
+        upserted.record.into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::api::{
+        resources::group::{dto, routes::init_routes},
+        tests::utils::get_app,
+        utils::{
+            random_number, random_string,
+            response::{ApiResponse, UPSERT_RESULT_HEADER},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_create_update_and_skip_unchanged_group() {
+        let (_, app) = get_app(init_routes).await;
+
+        let uri = format!("/group/extid/{}", random_string(12));
+        let request_update_group = dto::RequestUpdateGroup::mock_default();
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_group.clone())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+        assert_eq!(res.headers().get(UPSERT_RESULT_HEADER).unwrap(), "created");
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseGroup> = serde_json::from_slice(&body).unwrap();
+        let created = response.records.first().unwrap();
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_group.clone())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(
+            res.headers().get(UPSERT_RESULT_HEADER).unwrap(),
+            "unchanged"
+        );
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseGroup> = serde_json::from_slice(&body).unwrap();
+        let unchanged = response.records.first().unwrap();
+
+        assert_eq!(unchanged.groupid, created.groupid);
+        assert_eq!(unchanged.updated_at, created.updated_at);
+
+        let req = test::TestRequest::put()
+            .uri(&uri)
+            .set_json(request_update_group.with_name("Burgers Supreme"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+        assert_eq!(res.headers().get(UPSERT_RESULT_HEADER).unwrap(), "updated");
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseGroup> = serde_json::from_slice(&body).unwrap();
+        let updated = response.records.first().unwrap();
+
+        assert_eq!(updated.groupid, created.groupid);
+        assert_eq!(updated.name, "Burgers Supreme");
+        assert_ne!(updated.updated_at, created.updated_at);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_when_organizer_is_missing() {
+        let (_, app) = get_app(init_routes).await;
+
+        let mut request_update_group = dto::RequestUpdateGroup::mock_default();
+        request_update_group.organizerids = vec![random_number() + 1000];
+        let req = test::TestRequest::put()
+            .uri(&format!("/group/extid/{}", random_string(12)))
+            .set_json(request_update_group)
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+}
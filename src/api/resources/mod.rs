@@ -1,8 +1,11 @@
 pub mod article;
+pub mod audit_log;
 pub mod categories;
 pub mod state;
 pub mod city;
 pub mod group;
+pub mod organizer;
 pub mod event;
+pub mod event_series;
 pub mod health;
 pub mod swagger;
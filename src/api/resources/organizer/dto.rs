@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use validator::Validate;
+
+#[cfg(test)]
+use crate::api::utils::random_string;
+
+use crate::{
+    api::utils::validator::validate_page_size_max,
+    domain::organizer::model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel},
+};
+
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RequestCreateOrganizer {
+    #[validate(length(max = 64))]
+    pub firstname: String,
+    #[validate(length(max = 64))]
+    pub lastname: String,
+    #[validate(length(max = 512))]
+    pub bio: String,
+    #[validate(length(max = 64))]
+    pub extid: String,
+    #[validate(length(max = 512))]
+    pub highres_link: Option<String>,
+    #[validate(length(max = 512))]
+    pub photo_link: Option<String>,
+    #[validate(length(max = 512))]
+    pub thumb_link: Option<String>,
+}
+impl From<RequestCreateOrganizer> for OrganizerCreateModel {
+    fn from(value: RequestCreateOrganizer) -> Self {
+        OrganizerCreateModel::new(
+            value.firstname,
+            value.lastname,
+            value.bio,
+            value.extid,
+            value.highres_link,
+            value.photo_link,
+            value.thumb_link,
+        )
+    }
+}
+#[cfg(test)]
+impl RequestCreateOrganizer {
+    pub fn mock_default() -> Self {
+        Self {
+            firstname: random_string(10),
+            lastname: random_string(10),
+            bio: "The Big Organizer".to_string(),
+            extid: random_string(10),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Serialize))]
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct RequestUpdateOrganizer {
+    #[validate(length(max = 64))]
+    pub firstname: String,
+    #[validate(length(max = 64))]
+    pub lastname: String,
+    #[validate(length(max = 512))]
+    pub bio: String,
+    #[validate(length(max = 512))]
+    pub highres_link: Option<String>,
+    #[validate(length(max = 512))]
+    pub photo_link: Option<String>,
+    #[validate(length(max = 512))]
+    pub thumb_link: Option<String>,
+}
+impl From<RequestUpdateOrganizer> for OrganizerUpdateModel {
+    fn from(value: RequestUpdateOrganizer) -> Self {
+        OrganizerUpdateModel::new(
+            value.firstname,
+            value.lastname,
+            value.bio,
+            value.highres_link,
+            value.photo_link,
+            value.thumb_link,
+        )
+    }
+}
+#[cfg(test)]
+impl RequestUpdateOrganizer {
+    pub fn mock_default() -> Self {
+        Self {
+            firstname: random_string(10),
+            lastname: random_string(10),
+            bio: "The Big Organizer".to_string(),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+        }
+    }
+
+    pub fn with_firstname(mut self, firstname: &str) -> Self {
+        self.firstname = firstname.to_string();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
+pub struct RequestFindOrganizer {
+    #[validate(length(max = 64))]
+    pub name: Option<String>,
+    pub page: Option<u32>,
+    #[validate(custom = "validate_page_size_max")]
+    pub page_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate, IntoParams)]
+pub struct RequestFindOrganizerGroups {
+    pub page: Option<u32>,
+    #[validate(custom = "validate_page_size_max")]
+    pub page_size: Option<u32>,
+}
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseOrganizer {
+    pub organizerid: i32,
+    pub firstname: String,
+    pub lastname: String,
+    pub bio: String,
+    pub extid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highres_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_link: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+impl From<OrganizerModel> for ResponseOrganizer {
+    fn from(value: OrganizerModel) -> Self {
+        Self {
+            organizerid: value.organizerid,
+            firstname: value.firstname,
+            lastname: value.lastname,
+            bio: value.bio,
+            extid: value.extid,
+            highres_link: value.highres_link,
+            photo_link: value.photo_link,
+            thumb_link: value.thumb_link,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use actix_web::{
+    post,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::organizer::dto::{self, ResponseOrganizer},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, organizer},
+};
+
+#[utoipa::path(
+    post,
+    operation_id = "create_organizer",
+    path = "/organizer",
+    tag = "organizer",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    request_body = RequestCreateOrganizer,
+    responses(
+         (status = 201, description = "organizer created",  body = ApiResponseOrganizer),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[post("/organizer", wrap = "super::CreatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    body: web::Json<dto::RequestCreateOrganizer>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let organizer = organizer::resources::create::execute(
+        state.organizer_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
+
+    let response = ApiResponse::<ResponseOrganizer>::new(vec![organizer.into()], None, None, None);
+
+    Ok(HttpResponse::Created().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::api::{
+        resources::organizer::{dto, routes::init_routes},
+        tests::utils::get_app,
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_created() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::post()
+            .uri("/organizer")
+            .set_json(dto::RequestCreateOrganizer::mock_default())
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::CREATED);
+    }
+}
@@ -0,0 +1,89 @@
+use actix_web::{
+    delete,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{lib::AppState, middleware::actor::Actor},
+    domain::{error::DomainError, organizer},
+};
+
+#[utoipa::path(
+    delete,
+    operation_id = "delete_organizer",
+    path = "/organizer/{organizer_id}",
+    tag = "organizer",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("organizer_id" = i32, Path, description = "organizer id"),
+    ),
+    responses(
+         (status = 204, description = "organizer deleted"),
+         (status = 400, description = "Invalid organizer id",  body = ErrorResponse),
+         (status = 404, description = "organizer not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[delete("/organizer/{organizer_id}", wrap = "super::DeletePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+) -> Result<HttpResponse, DomainError> {
+    organizer::resources::delete_by_organizerid::execute(
+        state.organizer_repository.clone(),
+        actor.0,
+        param.to_owned(),
+    )
+    .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::organizer::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number,
+        },
+        domain::organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_void_organizer_deleted() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer_model = OrganizerCreateModel::mock_default();
+        let organizer = repositories
+            .organizer_repository
+            .insert(&organizer_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/organizer/{}", organizer.organizerid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_error_when_deleting() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/organizer/{}", random_number()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
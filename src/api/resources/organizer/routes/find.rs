@@ -0,0 +1,159 @@
+use actix_web::{
+    get,
+    web::{Data, Query},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        config,
+        lib::AppState,
+        resources::organizer::dto::{self, ResponseOrganizer},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, organizer},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_organizer",
+    path = "/organizer",
+    tag = "organizer",
+    params(
+        dto::RequestFindOrganizer
+    ),
+    responses(
+         (status = 200, description = "organizer",  body = ApiResponseOrganizer),
+         (status = 204, description = "no content organizer"),
+         (status = 400, description = "Invalid query parameters",  body = ErrorResponse),
+    ),
+ )]
+#[get("/organizer")]
+async fn handler(
+    state: Data<AppState>,
+    query: Query<dto::RequestFindOrganizer>,
+) -> Result<HttpResponse, DomainError> {
+    query.validate()?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query
+        .page_size
+        .unwrap_or(config::get_config().page_size_default);
+
+    let name = query.name.to_owned();
+
+    let result = organizer::resources::find::execute(
+        state.organizer_repository.clone(),
+        name,
+        page,
+        page_size,
+    )
+    .await?;
+
+    if let Some((organizers, count)) = result {
+        let response = ApiResponse::<ResponseOrganizer>::new(
+            organizers.into_iter().map(|i| i.into()).collect(),
+            Some(page),
+            Some(count),
+            Some(page_size),
+        );
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::organizer::{dto, routes::init_routes},
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::response::ApiResponse,
+        },
+        domain::organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_finded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer_model = OrganizerCreateModel::mock_default();
+        repositories
+            .organizer_repository
+            .insert(&organizer_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get().uri("/organizer").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response_organizer_finded: ApiResponse<dto::ResponseOrganizer> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert!(!response_organizer_finded.records.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_finded_by_query() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer_model = OrganizerCreateModel::mock_default();
+        repositories
+            .organizer_repository
+            .insert(&organizer_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/organizer?name={}&page=1&page_size=24",
+                organizer_model.lastname,
+            ))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response_organizer_finded: ApiResponse<dto::ResponseOrganizer> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert!(!response_organizer_finded.records.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_no_content() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer?name={}", "no-content"))
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_bad_request_error_when_query_parameters_is_invalid() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer?page={}&page_size=24", "invalid"))
+            .to_request();
+
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,90 @@
+use actix_web::{
+    get,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        lib::AppState, resources::organizer::dto::ResponseOrganizer, utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, organizer},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_organizer_by_organizerid",
+    path = "/organizer/{organizer_id}",
+    tag = "organizer",
+    params(
+        ("organizer_id" = i32, Path, description = "Organizer id"),
+    ),
+    responses(
+         (status = 200, description = "Organizer finded",  body = ApiResponseOrganizer),
+         (status = 204, description = "Organizer no content"),
+    ),
+ )]
+#[get("/organizer/{organizer_id}")]
+async fn handler(
+    state: Data<AppState>,
+    param: web::Path<i32>,
+) -> Result<HttpResponse, DomainError> {
+    let result = organizer::resources::find_by_organizerid::execute(
+        state.organizer_repository.clone(),
+        param.to_owned(),
+    )
+    .await?;
+
+    if let Some(organizer) = result {
+        let response =
+            ApiResponse::<ResponseOrganizer>::new(vec![organizer.into()], None, None, None);
+
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::organizer::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number,
+        },
+        domain::organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_finded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer_model = OrganizerCreateModel::mock_default();
+        let organizer = repositories
+            .organizer_repository
+            .insert(&organizer_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer/{}", organizer.organizerid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_no_content() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer/{}", random_number()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+}
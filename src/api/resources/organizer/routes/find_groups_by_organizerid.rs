@@ -0,0 +1,141 @@
+use actix_web::{
+    get,
+    web::{self, Data, Query},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        config,
+        lib::AppState,
+        resources::{group::dto::ResponsePageGroup, organizer::dto},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, group},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_groups_by_organizerid",
+    path = "/organizer/{organizer_id}/groups",
+    tag = "organizer",
+    params(
+        ("organizer_id" = i32, Path, description = "Organizer id"),
+        dto::RequestFindOrganizerGroups
+    ),
+    responses(
+         (status = 200, description = "Groups run by the organizer",  body = ApiResponseGroup),
+         (status = 204, description = "no content group"),
+         (status = 400, description = "Invalid query parameters",  body = ErrorResponse),
+    ),
+ )]
+#[get("/organizer/{organizer_id}/groups")]
+async fn handler(
+    state: Data<AppState>,
+    param: web::Path<i32>,
+    query: Query<dto::RequestFindOrganizerGroups>,
+) -> Result<HttpResponse, DomainError> {
+    query.validate()?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query
+        .page_size
+        .unwrap_or(config::get_config().page_size_default);
+
+    let result = group::resources::find_by_organizerid::execute(
+        state.group_repository.clone(),
+        param.to_owned(),
+        page,
+        page_size,
+    )
+    .await?;
+
+    if let Some((groups, count)) = result {
+        let response = ApiResponse::<ResponsePageGroup>::new(
+            groups.into_iter().map(|i| i.into()).collect(),
+            Some(page),
+            Some(count),
+            Some(page_size),
+        );
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::{group::dto::ResponsePageGroup, organizer::routes::init_routes},
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::response::ApiResponse,
+        },
+        domain::{
+            group::{model::GroupCreateModel, repository::GroupRepository},
+            organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+        },
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_groups_finded() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer = repositories
+            .organizer_repository
+            .insert(&OrganizerCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mut group_model = GroupCreateModel::mock_default();
+        group_model.organizerids = vec![organizer.organizerid];
+        let group = repositories
+            .group_repository
+            .insert(&group_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        assert_eq!(group.organizers.len(), 1);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer/{}/groups", organizer.organizerid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::OK);
+
+        let body = test::read_body(res).await;
+        let response_groups_finded: ApiResponse<ResponsePageGroup> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_groups_finded.records.len(), 1);
+        assert_eq!(
+            response_groups_finded.records.first().unwrap().group_slug,
+            group.slug
+        );
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_no_content() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer = repositories
+            .organizer_repository
+            .insert(&OrganizerCreateModel::mock_default(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/organizer/{}/groups", organizer.organizerid))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+}
@@ -0,0 +1,25 @@
+use actix_web::web;
+
+use crate::api::middleware::role::{role_policy, Role};
+
+pub mod create;
+pub mod delete_by_organizerid;
+pub mod find;
+pub mod find_by_extid;
+pub mod find_by_organizerid;
+pub mod find_groups_by_organizerid;
+pub mod update_by_organizerid;
+
+role_policy!(CreatePolicy, [Role::Admin, Role::Editor, Role::Ingest]);
+role_policy!(UpdatePolicy, [Role::Admin, Role::Editor]);
+role_policy!(DeletePolicy, [Role::Admin]);
+
+pub fn init_routes(config: &mut web::ServiceConfig) {
+    config.service(create::handler);
+    config.service(update_by_organizerid::handler);
+    config.service(find_by_extid::handler);
+    config.service(find_by_organizerid::handler);
+    config.service(find_groups_by_organizerid::handler);
+    config.service(find::handler);
+    config.service(delete_by_organizerid::handler);
+}
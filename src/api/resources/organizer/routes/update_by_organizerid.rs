@@ -0,0 +1,123 @@
+use actix_web::{
+    put,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use validator::Validate;
+
+use crate::{
+    api::{
+        lib::AppState,
+        middleware::actor::Actor,
+        resources::organizer::dto::{self, ResponseOrganizer},
+        utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, organizer},
+};
+
+#[utoipa::path(
+    put,
+    operation_id = "update_organizer",
+    path = "/organizer/{organizer_id}",
+    tag = "organizer",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("organizer_id" = i32, Path, description = "Organizer id"),
+    ),
+    request_body = RequestUpdateOrganizer,
+    responses(
+         (status = 200, description = "Organizer updated",  body = ApiResponseOrganizer),
+         (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 404, description = "Organizer not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
+    ),
+ )]
+#[put("/organizer/{organizer_id}", wrap = "super::UpdatePolicy")]
+async fn handler(
+    state: Data<AppState>,
+    actor: Actor,
+    param: web::Path<i32>,
+    body: web::Json<dto::RequestUpdateOrganizer>,
+) -> Result<HttpResponse, DomainError> {
+    body.validate()?;
+
+    let organizer = organizer::resources::update_by_organizerid::execute(
+        state.organizer_repository.clone(),
+        actor.0,
+        param.to_owned(),
+        body.0.into(),
+    )
+    .await?;
+
+    let response = ApiResponse::<ResponseOrganizer>::new(vec![organizer.into()], None, None, None);
+
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::organizer::{dto, routes::init_routes},
+            tests::utils::{get_app, TEST_ACTOR},
+            utils::{random_number, response::ApiResponse},
+        },
+        domain::organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+    };
+
+    #[actix_web::test]
+    async fn it_should_return_organizer_updated() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let organizer_model = OrganizerCreateModel::mock_default();
+        let organizer = repositories
+            .organizer_repository
+            .insert(&organizer_model.clone(), TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let mock_request_update_organizer =
+            dto::RequestUpdateOrganizer::mock_default().with_firstname("Jane");
+        let req = test::TestRequest::put()
+            .uri(&format!("/organizer/{}", organizer.organizerid))
+            .set_json(mock_request_update_organizer.clone())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let mock_response_organizer_updated: ApiResponse<dto::ResponseOrganizer> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            mock_response_organizer_updated
+                .records
+                .first()
+                .unwrap()
+                .firstname,
+            mock_request_update_organizer.firstname
+        )
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_not_found_error_when_updated_because_invalid_id() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/organizer/{}", random_number()))
+            .set_json(dto::RequestUpdateOrganizer::mock_default())
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NOT_FOUND);
+    }
+}
@@ -6,7 +6,9 @@ use validator::Validate;
 
 use crate::{
     api::utils::validator::validate_page_size_max,
-    domain::state::model::{StateCreateModel, StateModel, StateUpdateModel},
+    domain::state::model::{
+        StateCityModel, StateCreateModel, StateDetailModel, StateModel, StateUpdateModel,
+    },
 };
 
 #[cfg_attr(test, derive(Serialize))]
@@ -98,6 +100,9 @@ impl RequestUpdateState {
 pub struct RequestFindCategories {
     #[validate(length(max = 64))]
     pub name: Option<String>,
+    /// Also match names containing a word similar to `name`, most similar
+    /// first.
+    pub fuzzy: Option<bool>,
     pub page: Option<u32>,
     #[validate(custom = "validate_page_size_max")]
     pub page_size: Option<u32>,
@@ -135,3 +140,61 @@ impl From<StateModel> for ResponseState {
         }
     }
 }
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseStateCity {
+    pub cityid: i32,
+    pub name: String,
+    pub slug: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highres_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_link: Option<String>,
+    pub group_count: u32,
+    pub upcoming_event_count: u32,
+}
+impl From<StateCityModel> for ResponseStateCity {
+    fn from(value: StateCityModel) -> Self {
+        Self {
+            cityid: value.cityid,
+            name: value.name,
+            slug: value.slug,
+            highres_link: value.highres_link,
+            photo_link: value.photo_link,
+            thumb_link: value.thumb_link,
+            group_count: value.group_count,
+            upcoming_event_count: value.upcoming_event_count,
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Deserialize))]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseDetailState {
+    pub stateid: i32,
+    pub name: String,
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highres_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_link: Option<String>,
+    pub cities: Vec<ResponseStateCity>,
+}
+impl From<StateDetailModel> for ResponseDetailState {
+    fn from(value: StateDetailModel) -> Self {
+        Self {
+            stateid: value.stateid,
+            name: value.name,
+            symbol: value.symbol,
+            highres_link: value.highres_link,
+            photo_link: value.photo_link,
+            thumb_link: value.thumb_link,
+            cities: value.cities.into_iter().map(|i| i.into()).collect(),
+        }
+    }
+}
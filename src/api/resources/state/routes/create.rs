@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::state::dto::{self, ResponseState},
         utils::response::ApiResponse,
     },
@@ -20,22 +21,32 @@ use crate::{
     operation_id = "create_state",
     path = "/state",
     tag = "state",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     request_body = RequestCreateState,
     responses(
          (status = 201, description = "state created",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[post("/state")]
+#[post("/state", wrap = "super::CreatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     body: web::Json<dto::RequestCreateState>,
 ) -> Result<HttpResponse, DomainError> {
     body.validate()?;
 
-    let state =
-        state::resources::create::execute(state.state_repository.clone(), body.0.into())
-            .await?;
+    let state = state::resources::create::execute(
+        state.state_repository.clone(),
+        actor.0,
+        body.0.into(),
+    )
+    .await?;
 
     let response = ApiResponse::<ResponseState>::new(vec![state.into()], None, None, None);
 
@@ -6,7 +6,7 @@ use actix_web::{
 
 
 use crate::{
-    api::lib::AppState,
+    api::{lib::AppState, middleware::actor::Actor},
     domain::{state, error::DomainError},
 };
 
@@ -15,6 +15,10 @@ use crate::{
     operation_id = "delete_state",
     path = "/state/{state_id}",
     tag = "state",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("state_id" = i32, Path, description = "state uuid"),
     ),
@@ -23,15 +27,19 @@ use crate::{
          (status = 400, description = "Invalid state id",  body = ErrorResponse),
          (status = 404, description = "state not found",  body = ErrorResponse),
          (status = 409, description = "state is in use",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[delete("/state/{state_id}")]
+#[delete("/state/{state_id}", wrap = "super::DeletePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
 ) -> Result<HttpResponse, DomainError> {
     state::resources::delete_by_stateid::execute(
         state.state_repository.clone(),
+        actor.0,
         param.to_owned(),
     )
     .await?;
@@ -44,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::state::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::state::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::state::{model::StateCreateModel, repository::StateRepository},
     };
 
@@ -56,7 +64,7 @@ mod tests {
         let state_model = StateCreateModel::mock_default();
         let state = repositories
             .state_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -43,10 +43,12 @@ async fn handler(
         .unwrap_or(config::get_config().page_size_default);
 
     let name = query.name.to_owned();
+    let fuzzy = query.fuzzy.unwrap_or(false);
 
     let result = state::resources::find::execute(
         state.state_repository.clone(),
         name,
+        fuzzy,
         page,
         page_size,
     )
@@ -72,7 +74,7 @@ mod tests {
     use crate::{
         api::{
             resources::state::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::response::ApiResponse,
         },
         domain::state::{model::StateCreateModel, repository::StateRepository},
@@ -86,7 +88,7 @@ mod tests {
         let state_model = StateCreateModel::mock_default();
         repositories
             .state_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
@@ -109,7 +111,7 @@ mod tests {
         let state_model = StateCreateModel::mock_default();
         repositories
             .state_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
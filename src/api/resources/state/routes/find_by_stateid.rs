@@ -52,7 +52,7 @@ mod tests {
     
 
     use crate::{
-        api::{resources::state::routes::init_routes, tests::utils::get_app, utils::random_number},
+        api::{resources::state::routes::init_routes, tests::utils::{get_app, TEST_ACTOR}, utils::random_number},
         domain::state::{model::StateCreateModel, repository::StateRepository},
     };
 
@@ -64,7 +64,7 @@ mod tests {
         let state_model = StateCreateModel::mock_default();
         let state = repositories
             .state_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
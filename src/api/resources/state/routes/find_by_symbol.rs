@@ -0,0 +1,127 @@
+use actix_web::{
+    get,
+    web::{self, Data},
+    HttpResponse,
+};
+
+use crate::{
+    api::{
+        lib::AppState, resources::state::dto::ResponseDetailState, utils::response::ApiResponse,
+    },
+    domain::{error::DomainError, state},
+};
+
+#[utoipa::path(
+    get,
+    operation_id = "find_state_by_symbol",
+    path = "/state/{symbol}",
+    tag = "state",
+    params(
+        ("symbol" = String, Path, description = "State symbol, case-insensitive (e.g. on, qc)"),
+    ),
+    responses(
+         (status = 200, description = "State finded with its cities",  body = ApiResponseDetailState),
+         (status = 204, description = "State no content"),
+    ),
+ )]
+#[get("/state/{symbol:[A-Za-z][A-Za-z0-9]*}")]
+async fn handler(
+    state: Data<AppState>,
+    param: web::Path<String>,
+) -> Result<HttpResponse, DomainError> {
+    let result =
+        state::resources::find_by_symbol::execute(state.state_repository.clone(), param.to_owned())
+            .await?;
+
+    if let Some(state) = result {
+        let response =
+            ApiResponse::<ResponseDetailState>::new(vec![state.into()], None, None, None);
+
+        return Ok(HttpResponse::Ok().json(response));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test};
+
+    use crate::{
+        api::{
+            resources::state::{dto, routes::init_routes},
+            tests::utils::{get_app, Repositories, TEST_ACTOR},
+            utils::{random_string, response::ApiResponse},
+        },
+        domain::{
+            city::{model::CityCreateModel, repository::CityRepository},
+            state::{
+                model::{StateCreateModel, StateModel},
+                repository::StateRepository,
+            },
+        },
+    };
+
+    async fn seed_state(repositories: &Repositories) -> StateModel {
+        loop {
+            let mut state_model = StateCreateModel::mock_default();
+            state_model.symbol = random_string(64)
+                .chars()
+                .filter(|c| c.is_ascii_alphabetic())
+                .take(2)
+                .collect::<String>()
+                .to_lowercase();
+
+            if let Ok(state) = repositories.state_repository.insert(&state_model, TEST_ACTOR).await {
+                return state;
+            }
+        }
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_state_finded_with_cities() {
+        let (repositories, app) = get_app(init_routes).await;
+
+        //Seed
+        let state = seed_state(&repositories).await;
+        let mut city_model = CityCreateModel::mock_default();
+        city_model.stateid = state.stateid;
+        let city = repositories
+            .city_repository
+            .insert(&city_model, TEST_ACTOR)
+            .await
+            .unwrap();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/state/{}", state.symbol.to_uppercase()))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert!(res.status().is_success());
+
+        let body = test::read_body(res).await;
+        let response: ApiResponse<dto::ResponseDetailState> =
+            serde_json::from_slice(&body).unwrap();
+        let response_state = response.records.first().unwrap();
+
+        assert_eq!(response_state.stateid, state.stateid);
+
+        let response_city = response_state
+            .cities
+            .iter()
+            .find(|i| i.cityid == city.cityid)
+            .unwrap();
+        assert_eq!(response_city.group_count, 0);
+        assert_eq!(response_city.upcoming_event_count, 0);
+    }
+
+    #[actix_web::test]
+    async fn it_should_return_no_content() {
+        let (_, app) = get_app(init_routes).await;
+
+        let req = test::TestRequest::get().uri("/state/zz9").to_request();
+        let res = test::call_service(&app, req).await;
+
+        assert_eq!(res.status().as_u16(), StatusCode::NO_CONTENT);
+    }
+}
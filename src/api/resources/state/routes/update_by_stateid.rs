@@ -9,6 +9,7 @@ use validator::Validate;
 use crate::{
     api::{
         lib::AppState,
+        middleware::actor::Actor,
         resources::state::dto::{self, ResponseState},
         utils::response::ApiResponse,
     },
@@ -20,6 +21,10 @@ use crate::{
     operation_id = "update_state",
     path = "/state/{state_id}",
     tag = "state",
+    security(
+        ("api_key" = []),
+        ("bearer_auth" = [])
+    ),
     params(
         ("state_id" = i32, Path, description = "State uuid"),
     ),
@@ -28,11 +33,14 @@ use crate::{
          (status = 200, description = "State updated",  body = ApiResponseState),
          (status = 400, description = "Invalid payload",  body = ErrorResponse),
          (status = 404, description = "State not found",  body = ErrorResponse),
+         (status = 401, description = "Missing or invalid credentials",  body = ErrorResponse),
+         (status = 403, description = "API key is disabled or role is not allowed",  body = ErrorResponse),
     ),
  )]
-#[put("/state/{state_id}")]
+#[put("/state/{state_id}", wrap = "super::UpdatePolicy")]
 async fn handler(
     state: Data<AppState>,
+    actor: Actor,
     param: web::Path<i32>,
     body: web::Json<dto::RequestUpdateState>,
 ) -> Result<HttpResponse, DomainError> {
@@ -40,6 +48,7 @@ async fn handler(
 
     let state = state::resources::update_by_stateid::execute(
         state.state_repository.clone(),
+        actor.0,
         param.to_owned(),
         body.0.into(),
     )
@@ -58,7 +67,7 @@ mod tests {
     use crate::{
         api::{
             resources::state::{dto, routes::init_routes},
-            tests::utils::get_app,
+            tests::utils::{get_app, TEST_ACTOR},
             utils::{response::ApiResponse, random_number},
         },
         domain::state::{model::StateCreateModel, repository::StateRepository},
@@ -72,7 +81,7 @@ mod tests {
         let state_model = StateCreateModel::mock_default();
         let state = repositories
             .state_repository
-            .insert(&state_model.clone())
+            .insert(&state_model.clone(), TEST_ACTOR)
             .await
             .unwrap();
 
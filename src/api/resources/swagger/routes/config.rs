@@ -1,5 +1,8 @@
 use actix_web::{get, http::header, HttpResponse, Responder};
-use utoipa::OpenApi;
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
 use utoipa_swagger_ui::SwaggerUi;
 
 #[derive(OpenApi)]
@@ -11,11 +14,14 @@ use utoipa_swagger_ui::SwaggerUi;
         crate::api::resources::categories::routes::update_by_id::handler,
         crate::api::resources::categories::routes::find_by_id::handler,
         crate::api::resources::categories::routes::find::handler,
+        crate::api::resources::categories::routes::find_tree::handler,
+        crate::api::resources::categories::routes::find_by_slug::handler,
         crate::api::resources::categories::routes::delete_by_id::handler,
         //State
         crate::api::resources::state::routes::create::handler,
         crate::api::resources::state::routes::update_by_stateid::handler,
         crate::api::resources::state::routes::find_by_stateid::handler,
+        crate::api::resources::state::routes::find_by_symbol::handler,
         crate::api::resources::state::routes::find::handler,
         crate::api::resources::state::routes::delete_by_stateid::handler,
         //City
@@ -32,27 +38,53 @@ use utoipa_swagger_ui::SwaggerUi;
         crate::api::resources::article::routes::delete_by_articleid::handler,
         //Event
         crate::api::resources::event::routes::create::handler,
+        crate::api::resources::event::routes::bulk::handler,
         crate::api::resources::event::routes::update_by_eventid::handler,
+        crate::api::resources::event::routes::update_lifecycle_by_eventid::handler,
+        crate::api::resources::event::routes::upsert_by_extid::handler,
         crate::api::resources::event::routes::find_by_eventid::handler,
+        crate::api::resources::event::routes::find_ics::handler,
         crate::api::resources::event::routes::find::handler,
         crate::api::resources::event::routes::delete_by_eventid::handler,
+        crate::api::resources::event_series::routes::create::handler,
+        crate::api::resources::event_series::routes::update_by_seriesid::handler,
+        crate::api::resources::event_series::routes::upsert_occurrence::handler,
+        crate::api::resources::event_series::routes::find_by_seriesid::handler,
+        crate::api::resources::event_series::routes::delete_by_seriesid::handler,
          //Group
          crate::api::resources::group::routes::create::handler,
          crate::api::resources::group::routes::update_by_groupid::handler,
+         crate::api::resources::group::routes::upsert_by_extid::handler,
          crate::api::resources::group::routes::find_by_groupid::handler,
+         crate::api::resources::group::routes::calendar::handler,
          crate::api::resources::group::routes::find::handler,
          crate::api::resources::group::routes::delete_by_groupid::handler,
+        //Organizer
+        crate::api::resources::organizer::routes::create::handler,
+        crate::api::resources::organizer::routes::update_by_organizerid::handler,
+        crate::api::resources::organizer::routes::find_by_organizerid::handler,
+        crate::api::resources::organizer::routes::find_by_extid::handler,
+        crate::api::resources::organizer::routes::find_groups_by_organizerid::handler,
+        crate::api::resources::organizer::routes::find::handler,
+        crate::api::resources::organizer::routes::delete_by_organizerid::handler,
+        //Audit
+        crate::api::resources::audit_log::routes::find::handler,
     ),
     components(schemas(
         crate::api::error::ErrorResponse, crate::api::utils::response::Meta,
         //Category
         crate::api::utils::response::ApiResponseCategory,
         crate::api::resources::categories::dto::ResponseCategory,
+        crate::api::utils::response::ApiResponseCategoryTree,
+        crate::api::resources::categories::dto::ResponseCategoryTree,
         crate::api::resources::categories::dto::RequestCreateCategory,
         crate::api::resources::categories::dto::RequestUpdateCategory,
         //State
         crate::api::utils::response::ApiResponseState,
         crate::api::resources::state::dto::ResponseState,
+        crate::api::utils::response::ApiResponseDetailState,
+        crate::api::resources::state::dto::ResponseDetailState,
+        crate::api::resources::state::dto::ResponseStateCity,
         crate::api::resources::state::dto::RequestCreateState,
         crate::api::resources::state::dto::RequestUpdateState,
         //City
@@ -75,10 +107,53 @@ use utoipa_swagger_ui::SwaggerUi;
         crate::api::resources::event::dto::ResponseEvent,
         crate::api::resources::event::dto::RequestCreateEvent,
         crate::api::resources::event::dto::RequestUpdateEvent,
-    ))
+        crate::api::resources::event::dto::RequestUpdateEventLifecycle,
+        crate::api::resources::event::dto::ResponseBulkEvent,
+        crate::api::resources::event::dto::BulkEventStatus,
+        crate::api::utils::response::ApiResponseEventSeries,
+        crate::api::resources::event_series::dto::ResponseEventSeries,
+        crate::api::utils::response::ApiResponseEventOccurrence,
+        crate::api::resources::event_series::dto::ResponseEventOccurrence,
+        crate::api::resources::event_series::dto::RequestCreateEventSeries,
+        crate::api::resources::event_series::dto::RequestUpdateEventSeries,
+        crate::api::resources::event_series::dto::RequestUpsertEventOccurrence,
+        //Organizer
+        crate::api::utils::response::ApiResponseOrganizer,
+        crate::api::resources::organizer::dto::ResponseOrganizer,
+        crate::api::resources::organizer::dto::RequestCreateOrganizer,
+        crate::api::resources::organizer::dto::RequestUpdateOrganizer,
+        //Audit
+        crate::api::utils::response::ApiResponseAuditLog,
+        crate::api::resources::audit_log::dto::ResponseAuditLog,
+    )),
+    modifiers(&SecurityAddon)
 )]
 struct ApiDoc;
 
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "api_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(
+                    crate::api::middleware::api_key::API_KEY_HEADER,
+                ))),
+            );
+            components.add_security_scheme(
+                "bearer_auth",
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        }
+    }
+}
+
 #[get("/docs")]
 async fn redirect() -> impl Responder {
     HttpResponse::Found()
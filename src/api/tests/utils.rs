@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
 use crate::{
-    api::{error::ErrorResponse, lib::AppState, middleware},
+    api::{
+        error::ErrorResponse,
+        lib::AppState,
+        middleware::{self, actor::Actor, role::Role},
+    },
     repository::{
+        api_key::PgApiKeyRepository,
         categories::PgCategoryRepository,
         state::PgStateRepository,
         health::PgHealthRepository,
@@ -11,6 +16,10 @@ use crate::{
         article::PgArticleRepository, 
         group::PgGroupRepository, 
         event::PgEventRepository,
+        event_series::PgEventSeriesRepository,
+        organizer::PgOrganizerRepository,
+        rate_limit::RedisRateLimitRepository,
+        audit_log::PgAuditLogRepository,
     },
 };
 
@@ -21,6 +30,7 @@ use actix_http::Request;
 use actix_web::{
     body::MessageBody,
     dev::{Service, ServiceResponse},
+    HttpMessage,
     error::InternalError,
     test,
     web::{Data, ServiceConfig},
@@ -29,6 +39,9 @@ use actix_web::{
 
 static INIT_DB: OnceCell<()> = OnceCell::const_new();
 
+/// Actor recorded in the audit log for requests made through `get_app`.
+pub const TEST_ACTOR: &str = "test";
+
 async fn setup() {
     INIT_DB
         .get_or_init(|| async {
@@ -43,51 +56,111 @@ async fn setup() {
 
 pub struct Repositories {
     pub health_repository: Arc<PgHealthRepository>,
+    pub api_key_repository: Arc<PgApiKeyRepository>,
     pub category_repository: Arc<PgCategoryRepository>,
     pub state_repository: Arc<PgStateRepository>,
     pub city_repository: Arc<PgCityRepository>,
     pub article_repository: Arc<PgArticleRepository>,
     pub group_repository: Arc<PgGroupRepository>,
     pub event_repository: Arc<PgEventRepository>,
+    pub event_series_repository: Arc<PgEventSeriesRepository>,
+    pub organizer_repository: Arc<PgOrganizerRepository>,
+    pub rate_limit_repository: Arc<RedisRateLimitRepository>,
+    pub audit_log_repository: Arc<PgAuditLogRepository>,
 }
 
 impl Repositories {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         health_repository: Arc<PgHealthRepository>,
+        api_key_repository: Arc<PgApiKeyRepository>,
         category_repository: Arc<PgCategoryRepository>,
         state_repository: Arc<PgStateRepository>,
         city_repository: Arc<PgCityRepository>,
         article_repository: Arc<PgArticleRepository>,
         group_repository: Arc<PgGroupRepository>,
         event_repository: Arc<PgEventRepository>,
+        event_series_repository: Arc<PgEventSeriesRepository>,
+        organizer_repository: Arc<PgOrganizerRepository>,
+        rate_limit_repository: Arc<RedisRateLimitRepository>,
+        audit_log_repository: Arc<PgAuditLogRepository>,
     ) -> Self {
         Self {
             health_repository,
+            api_key_repository,
             category_repository,
             state_repository,
             city_repository,
             article_repository,
             group_repository,
             event_repository,
+            event_series_repository,
+            organizer_repository,
+            rate_limit_repository,
+            audit_log_repository,
         }
     }
 }
 
 impl AppState {
-    fn mock_default(repositories: &Repositories) -> Data<Self> {
+    pub fn mock_default(repositories: &Repositories) -> Data<Self> {
         Data::new(Self {
             health_repository: repositories.health_repository.clone(),
+            api_key_repository: repositories.api_key_repository.clone(),
             category_repository: repositories.category_repository.clone(),
             state_repository: repositories.state_repository.clone(),
             city_repository: repositories.city_repository.clone(),
             article_repository: repositories.article_repository.clone(),
             group_repository: repositories.group_repository.clone(),
             event_repository: repositories.event_repository.clone(),
+            event_series_repository: repositories.event_series_repository.clone(),
+            organizer_repository: repositories.organizer_repository.clone(),
+            rate_limit_repository: repositories.rate_limit_repository.clone(),
+            audit_log_repository: repositories.audit_log_repository.clone(),
         })
     }
 }
 
+/// Pool to the test database, for repository tests.
+pub async fn get_pool() -> Arc<deadpool_postgres::Pool> {
+    setup().await;
+
+    Arc::new(postgres::init().unwrap())
+}
+
+pub async fn get_repositories() -> Repositories {
+    let pool = get_pool().await;
+    let redis_client = Arc::new(redis::init());
+
+    let health_repository = Arc::new(PgHealthRepository::new(pool.clone(), redis_client.clone()));
+    let api_key_repository = Arc::new(PgApiKeyRepository::new(pool.clone(), redis_client.clone()));
+    let category_repository = Arc::new(PgCategoryRepository::new(pool.clone()));
+    let state_repository = Arc::new(PgStateRepository::new(pool.clone(), redis_client.clone()));
+    let city_repository = Arc::new(PgCityRepository::new(pool.clone(),redis_client.clone()));
+    let article_repository = Arc::new(PgArticleRepository::new(pool.clone()));
+    let group_repository = Arc::new(PgGroupRepository::new(pool.clone()));
+    let event_repository = Arc::new(PgEventRepository::new(pool.clone()));
+    let event_series_repository = Arc::new(PgEventSeriesRepository::new(pool.clone()));
+    let organizer_repository = Arc::new(PgOrganizerRepository::new(pool.clone()));
+    let rate_limit_repository = Arc::new(RedisRateLimitRepository::new(redis_client.clone()));
+    let audit_log_repository = Arc::new(PgAuditLogRepository::new(pool.clone()));
+
+    Repositories::new(
+        health_repository, 
+        api_key_repository,
+        category_repository,
+        state_repository,
+        city_repository,
+        article_repository,
+        group_repository,
+        event_repository,
+        event_series_repository,
+        organizer_repository,
+        rate_limit_repository,
+        audit_log_repository,
+    )
+}
+
 pub async fn get_app<F>(
     routes: F,
 ) -> (
@@ -97,7 +170,7 @@ pub async fn get_app<F>(
 where
     F: FnOnce(&mut ServiceConfig),
 {
-    setup().await;
+    let repositories = get_repositories().await;
 
     let json_config = actix_web::web::JsonConfig::default().error_handler(|err, _req| {
         let http_error =
@@ -117,33 +190,17 @@ where
         InternalError::from_response(err, http_error).into()
     });
 
-    let pool = Arc::new(postgres::init().unwrap());
-    let redis_client = Arc::new(redis::init());
-
-    let health_repository = Arc::new(PgHealthRepository::new(pool.clone(), redis_client.clone()));
-    let category_repository = Arc::new(PgCategoryRepository::new(pool.clone()));
-    let state_repository = Arc::new(PgStateRepository::new(pool.clone()));
-    let city_repository = Arc::new(PgCityRepository::new(pool.clone(),redis_client.clone()));
-    let article_repository = Arc::new(PgArticleRepository::new(pool.clone()));
-    let group_repository = Arc::new(PgGroupRepository::new(pool.clone()));
-    let event_repository = Arc::new(PgEventRepository::new(pool.clone()));
-
-    let repositories = Repositories::new(
-        health_repository, 
-        category_repository,
-        state_repository,
-        city_repository,
-        article_repository,
-        group_repository,
-        event_repository,
-    );
-
     let app_state = AppState::mock_default(&repositories);
 
     (
         repositories,
         test::init_service(
             App::new()
+                .wrap_fn(|req, srv| {
+                    req.extensions_mut().insert(Role::Admin);
+                    req.extensions_mut().insert(Actor(String::from(TEST_ACTOR)));
+                    srv.call(req)
+                })
                 .wrap(middleware::cors::default())
                 .app_data(json_config.to_owned())
                 .app_data(query_config.to_owned())
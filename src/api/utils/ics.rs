@@ -0,0 +1,228 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::event::model::{EventDetailModel, EventLifecycleStatus, EventModel};
+
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+const PRODID: &str = "-//dataroot.ca//api.dataroot.ca//EN";
+/// RFC 5545 limit for a content line, in octets, excluding the CRLF.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// The parts of an event written into a VEVENT.
+#[derive(Debug, Clone)]
+pub struct IcsEvent {
+    pub extid: String,
+    pub summary: String,
+    pub description: String,
+    pub location: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub url: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub stamp: DateTime<Utc>,
+    /// VEVENT STATUS, so subscribed calendars mark cancelled events.
+    pub status: &'static str,
+}
+
+impl From<&EventModel> for IcsEvent {
+    fn from(value: &EventModel) -> Self {
+        Self {
+            extid: value.extid.clone(),
+            summary: value.name.clone(),
+            description: value.description.clone(),
+            location: value.location.clone(),
+            latitude: value.latitude,
+            longitude: value.longitude,
+            url: value.link.clone(),
+            start: value.time,
+            end: value.time + Duration::seconds(value.duration.into()),
+            stamp: value.updated_at.unwrap_or(value.created_at),
+            status: ics_status(value.lifecycle_status),
+        }
+    }
+}
+
+impl From<&EventDetailModel> for IcsEvent {
+    fn from(value: &EventDetailModel) -> Self {
+        // Occurrences of a series share its event, so the rule's start keeps their UIDs apart.
+        let extid = match value.occurrence {
+            Some(occurrence) => format!("{}-{}", value.extid, format_time(&occurrence)),
+            None => value.extid.clone(),
+        };
+
+        Self {
+            extid,
+            summary: value.name.clone(),
+            description: value.description.clone(),
+            location: value.location.clone(),
+            latitude: value.latitude,
+            longitude: value.longitude,
+            url: value.link.clone(),
+            start: value.time,
+            end: value.time + Duration::seconds(value.duration.into()),
+            stamp: value.updated_at.unwrap_or(value.created_at),
+            status: ics_status(value.lifecycle_status),
+        }
+    }
+}
+
+fn ics_status(lifecycle_status: EventLifecycleStatus) -> &'static str {
+    match lifecycle_status {
+        EventLifecycleStatus::Scheduled => "CONFIRMED",
+        EventLifecycleStatus::Postponed => "TENTATIVE",
+        EventLifecycleStatus::Cancelled | EventLifecycleStatus::Rescheduled => "CANCELLED",
+    }
+}
+
+/// Renders a VCALENDAR holding one VEVENT per event. UIDs are `<extid>@<uid_domain>`
+/// so clients update events they already imported instead of duplicating them.
+pub fn render_calendar(name: Option<&str>, events: &[IcsEvent], uid_domain: &str) -> String {
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        format!("PRODID:{PRODID}"),
+        String::from("CALSCALE:GREGORIAN"),
+        String::from("METHOD:PUBLISH"),
+    ];
+    if let Some(name) = name {
+        lines.push(format!("X-WR-CALNAME:{}", escape_text(name)));
+        lines.push(String::from("REFRESH-INTERVAL;VALUE=DURATION:PT1H"));
+        lines.push(String::from("X-PUBLISHED-TTL:PT1H"));
+    }
+
+    for event in events {
+        lines.push(String::from("BEGIN:VEVENT"));
+        lines.push(format!("UID:{}@{}", escape_text(&event.extid), uid_domain));
+        lines.push(format!("DTSTAMP:{}", format_time(&event.stamp)));
+        lines.push(format!("DTSTART:{}", format_time(&event.start)));
+        lines.push(format!("DTEND:{}", format_time(&event.end)));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        lines.push(format!("STATUS:{}", event.status));
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        }
+        if !event.location.is_empty() {
+            lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+        }
+        if let (Some(latitude), Some(longitude)) = (event.latitude, event.longitude) {
+            lines.push(format!("GEO:{latitude};{longitude}"));
+        }
+        if !event.url.is_empty() {
+            lines.push(format!("URL:{}", strip_controls(&event.url)));
+        }
+        lines.push(String::from("END:VEVENT"));
+    }
+    lines.push(String::from("END:VCALENDAR"));
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes a TEXT value (RFC 5545 section 3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.replace("\r\n", "\n").chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' | '\r' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Drops control characters from a value that is not TEXT, such as a URI,
+/// so it cannot break out of its content line.
+fn strip_controls(value: &str) -> String {
+    value.chars().filter(|ch| !ch.is_control()).collect()
+}
+
+/// Folds a content line into chunks of at most 75 octets, never splitting a
+/// UTF-8 character, each terminated by CRLF; continuation lines start with a space.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 3);
+    let mut octets = 0;
+    for ch in line.chars() {
+        if octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(ch);
+        octets += ch.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_escape_text() {
+        assert_eq!(escape_text("a\\b;c,d\r\ne\nf"), "a\\\\b\\;c\\,d\\ne\\nf");
+    }
+
+    #[test]
+    fn it_should_fold_long_lines_on_char_boundaries() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold_line(&line);
+
+        assert!(folded.ends_with("\r\n"));
+        for part in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(part.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(folded.replace("\r\n ", "").trim_end_matches("\r\n"), line);
+    }
+
+    #[test]
+    fn it_should_render_event_with_stable_uid() {
+        let event = EventModel::mock_default();
+        let calendar = render_calendar(None, &[(&event).into()], "dataroot.ca");
+
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+        assert!(calendar.contains(&format!("UID:{}@dataroot.ca\r\n", event.extid)));
+        assert!(calendar.contains("DTSTART:19700101T000000Z\r\n"));
+        assert!(calendar.contains("DTEND:19700101T000005Z\r\n"));
+        assert!(!calendar.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn it_should_keep_url_on_its_content_lines() {
+        let mut event = EventModel::mock_default();
+        event.link = format!(
+            "https://dataroot.ca/{}\r\nATTENDEE:mailto:a@b.c",
+            "a".repeat(80)
+        );
+        let calendar = render_calendar(None, &[(&event).into()], "dataroot.ca");
+
+        assert!(!calendar.contains("\r\nATTENDEE"));
+        for line in calendar.trim_end_matches("\r\n").split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+        assert!(calendar.replace("\r\n ", "").contains(&format!(
+            "URL:https://dataroot.ca/{}ATTENDEE:mailto:a@b.c\r\n",
+            "a".repeat(80)
+        )));
+    }
+
+    #[test]
+    fn it_should_mark_cancelled_event() {
+        let mut event = EventModel::mock_default();
+        event.lifecycle_status = EventLifecycleStatus::Cancelled;
+        let calendar = render_calendar(None, &[(&event).into()], "dataroot.ca");
+
+        assert!(calendar.contains("STATUS:CANCELLED\r\n"));
+    }
+}
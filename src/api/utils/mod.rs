@@ -1,3 +1,4 @@
+pub mod ics;
 pub mod response;
 pub mod validator;
 
@@ -1,15 +1,20 @@
+use actix_web::HttpResponse;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::api::{
     config::get_config, 
-    resources::state::dto::ResponseState,
-    resources::categories::dto::ResponseCategory,
+    resources::state::dto::{ResponseDetailState, ResponseState},
+    resources::categories::dto::{ResponseCategory, ResponseCategoryTree},
     resources::city::dto::ResponseCity,
     resources::group::dto::ResponseGroup,
     resources::article::dto::ResponseArticle,
     resources::event::dto::ResponseEvent,
+    resources::event_series::dto::{ResponseEventOccurrence, ResponseEventSeries},
+    resources::organizer::dto::ResponseOrganizer,
+    resources::audit_log::dto::ResponseAuditLog,
 };
+use crate::domain::upsert::UpsertOutcome;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct Meta {
@@ -34,11 +39,17 @@ impl Meta {
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[aliases(
     ApiResponseCategory = ApiResponse<ResponseCategory>,
+    ApiResponseCategoryTree = ApiResponse<ResponseCategoryTree>,
     ApiResponseState = ApiResponse<ResponseState>,
+    ApiResponseDetailState = ApiResponse<ResponseDetailState>,
     ApiResponseArticle = ApiResponse<ResponseArticle>,
     ApiResponseEvent = ApiResponse<ResponseEvent>,
+    ApiResponseEventSeries = ApiResponse<ResponseEventSeries>,
+    ApiResponseEventOccurrence = ApiResponse<ResponseEventOccurrence>,
     ApiResponseCity = ApiResponse<ResponseCity>,
     ApiResponseGroup = ApiResponse<ResponseGroup>,
+    ApiResponseOrganizer = ApiResponse<ResponseOrganizer>,
+    ApiResponseAuditLog = ApiResponse<ResponseAuditLog>,
 )]
 pub struct ApiResponse<T> {
     pub meta: Meta,
@@ -57,3 +68,19 @@ impl<T> ApiResponse<T> {
         }
     }
 }
+
+/// Tells whether an upsert created, updated or left the record unchanged.
+pub const UPSERT_RESULT_HEADER: &str = "x-upsert-result";
+
+/// Answers an upsert with `201 Created` for new records and `200 OK`
+/// otherwise, naming the outcome in `X-Upsert-Result`.
+pub fn upsert_response<T: Serialize>(outcome: UpsertOutcome, record: T) -> HttpResponse {
+    let mut response = match outcome {
+        UpsertOutcome::Created => HttpResponse::Created(),
+        UpsertOutcome::Updated | UpsertOutcome::Unchanged => HttpResponse::Ok(),
+    };
+
+    response
+        .insert_header((UPSERT_RESULT_HEADER, outcome.as_str()))
+        .json(ApiResponse::<T>::new(vec![record], None, None, None))
+}
@@ -1,12 +1,24 @@
 use validator::ValidationError;
 
-use crate::{api::{config, resources::event::dto::RequestFindEvent}, repository::event::{EventStatusOption, DateRangeOption}};
+use crate::{
+    api::{
+        config,
+        resources::{
+            city::dto::{RequestCreateCity, RequestFindCategories as RequestFindCity, RequestUpdateCity},
+            event::dto::{RequestCreateEvent, RequestFindEvent, RequestUpdateEvent},
+        },
+    },
+    domain::event_series::rrule::RRule,
+    repository::{city::CitySortOption, event::{EventStatusOption, DateRangeOption, EventSortOption}},
+};
+
+const RADIUS_KM_MAX: f64 = 500.0;
 
 
 pub fn validate_event_status_option(value: &EventStatusOption) -> Result<(), ValidationError> {
     match value {
         EventStatusOption::Upcomming => Ok(()),
-        EventStatusOption::Recurrent => Ok(()),
+        EventStatusOption::InProgress => Ok(()),
         EventStatusOption::Past => Ok(()),
     }
 }
@@ -23,7 +35,15 @@ pub fn validate_event_request(request: &RequestFindEvent) -> Result<(), Validati
                 _ => return Err(ValidationError::new("start and end dates must be provided for custom time frame"))
             }
         },
-        Some(DateRangeOption::Today) | Some(DateRangeOption::ThisWeek) | Some(DateRangeOption::ThisMonth) => {
+        Some(DateRangeOption::Today)
+        | Some(DateRangeOption::Tomorrow)
+        | Some(DateRangeOption::ThisWeek)
+        | Some(DateRangeOption::ThisWeekend)
+        | Some(DateRangeOption::NextWeek)
+        | Some(DateRangeOption::Next7Days)
+        | Some(DateRangeOption::Next30Days)
+        | Some(DateRangeOption::ThisMonth)
+        | Some(DateRangeOption::NextMonth) => {
             if request.start_date.is_some() || request.end_date.is_some() {
                 return Err(ValidationError::new("start and end dates must be None for non-custom time frames"));
             }
@@ -32,6 +52,70 @@ pub fn validate_event_request(request: &RequestFindEvent) -> Result<(), Validati
             // time_frame is None, no validation necessary
         },
     }
+    validate_distance_query(request.lat, request.lng, request.radius_km)?;
+    if request.sort == Some(EventSortOption::Distance) && request.lat.is_none() {
+        return Err(ValidationError::new("lat and lng must be provided to sort by distance"));
+    }
+    Ok(())
+}
+
+pub fn validate_create_event_request(request: &RequestCreateEvent) -> Result<(), ValidationError> {
+    validate_coordinates(request.latitude, request.longitude)?;
+    validate_price(request.price_min, request.price_max)
+}
+
+pub fn validate_update_event_request(request: &RequestUpdateEvent) -> Result<(), ValidationError> {
+    validate_coordinates(request.latitude, request.longitude)?;
+    validate_price(request.price_min, request.price_max)
+}
+
+pub fn validate_city_request(request: &RequestFindCity) -> Result<(), ValidationError> {
+    validate_distance_query(request.lat, request.lng, request.radius_km)?;
+    if matches!(request.sort, Some(CitySortOption::Distance)) && request.lat.is_none() {
+        return Err(ValidationError::new("lat and lng must be provided to sort by distance"));
+    }
+    Ok(())
+}
+
+pub fn validate_create_city_request(request: &RequestCreateCity) -> Result<(), ValidationError> {
+    validate_coordinates(request.latitude, request.longitude)
+}
+
+pub fn validate_update_city_request(request: &RequestUpdateCity) -> Result<(), ValidationError> {
+    validate_coordinates(request.latitude, request.longitude)
+}
+
+fn validate_coordinates(latitude: Option<f64>, longitude: Option<f64>) -> Result<(), ValidationError> {
+    if latitude.is_some() != longitude.is_some() {
+        return Err(ValidationError::new("latitude and longitude must be provided together"));
+    }
+    Ok(())
+}
+
+fn validate_price(price_min: Option<i32>, price_max: Option<i32>) -> Result<(), ValidationError> {
+    match (price_min, price_max) {
+        (None, Some(_)) => Err(ValidationError::new("price_min must be provided with price_max")),
+        (Some(price_min), Some(price_max)) if price_max < price_min => {
+            Err(ValidationError::new("price_max should be greater than or equal to price_min"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_distance_query(
+    lat: Option<f64>,
+    lng: Option<f64>,
+    radius_km: Option<f64>,
+) -> Result<(), ValidationError> {
+    validate_coordinates(lat, lng)?;
+    if let Some(radius_km) = radius_km {
+        if lat.is_none() {
+            return Err(ValidationError::new("lat and lng must be provided with radius_km"));
+        }
+        if radius_km <= 0.0 || radius_km > RADIUS_KM_MAX {
+            return Err(ValidationError::new("radius_km must be greater than 0 and at most 500"));
+        }
+    }
     Ok(())
 }
 
@@ -42,3 +126,39 @@ pub fn validate_page_size_max(page_size: u32) -> Result<(), ValidationError> {
     }
     Ok(())
 }
+
+pub fn validate_time_zone(time_zone: &str) -> Result<(), ValidationError> {
+    if time_zone.parse::<chrono_tz::Tz>().is_err() {
+        return Err(ValidationError::new("time zone must be a valid IANA time zone name"));
+    }
+    Ok(())
+}
+
+pub fn validate_rrule(rrule: &str) -> Result<(), ValidationError> {
+    if let Err(message) = rrule.parse::<RRule>() {
+        let mut error = ValidationError::new("rrule must be a supported RFC 5545 recurrence rule");
+        error.add_param("reason".into(), &message);
+        return Err(error);
+    }
+    Ok(())
+}
+
+pub fn validate_currency(currency: &str) -> Result<(), ValidationError> {
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(ValidationError::new("currency must be an ISO 4217 code such as CAD"));
+    }
+    Ok(())
+}
+
+pub fn validate_slug(slug: &str) -> Result<(), ValidationError> {
+    if slug.is_empty()
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(ValidationError::new(
+            "slug must only contain lowercase letters, digits and hyphens",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[cfg(test)]
+use crate::api::utils::random_number;
+#[cfg(test)]
+use crate::api::utils::random_string;
+
+/// Hex encoded SHA-256 of a raw API key, as stored in `api_key.key_hash`.
+pub fn hash_key(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKeyCreateModel {
+    pub name: String,
+    pub key_hash: String,
+    pub active: bool,
+}
+
+impl ApiKeyCreateModel {
+    pub fn new(name: String, key: &str, active: bool) -> Self {
+        Self {
+            name,
+            key_hash: hash_key(key),
+            active,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyModel {
+    pub apikeyid: i32,
+    pub name: String,
+    pub key_hash: String,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+impl ApiKeyModel {
+    pub fn mock_default() -> Self {
+        Self {
+            apikeyid: random_number(),
+            name: random_string(10),
+            key_hash: hash_key(&random_string(32)),
+            active: true,
+            created_at: DateTime::default(),
+            updated_at: None,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+use super::model::{ApiKeyCreateModel, ApiKeyModel};
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn find_by_key_hash(&self, key_hash: String) -> Result<Option<ApiKeyModel>, DomainError>;
+    async fn insert(&self, api_key_create_model: &ApiKeyCreateModel) -> Result<ApiKeyModel, DomainError>;
+}
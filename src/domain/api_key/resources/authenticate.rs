@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    api_key::{
+        model::{hash_key, ApiKeyModel},
+        repository::ApiKeyRepository,
+    },
+    error::DomainError,
+};
+
+pub async fn execute(
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    key: Option<String>,
+) -> Result<ApiKeyModel, DomainError> {
+    let Some(key) = key else {
+        return Err(DomainError::Unauthorized(String::from("Missing API key")));
+    };
+
+    let Some(api_key) = api_key_repository.find_by_key_hash(hash_key(&key)).await? else {
+        return Err(DomainError::Unauthorized(String::from("Invalid API key")));
+    };
+
+    if !api_key.active {
+        return Err(DomainError::Forbidden(String::from("API key is disabled")));
+    }
+
+    Ok(api_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{api::utils::random_string, domain::api_key::model::ApiKeyCreateModel};
+
+    use super::*;
+
+    mock! {
+        pub FakeApiKeyRepository { }
+
+        #[async_trait]
+        impl ApiKeyRepository for FakeApiKeyRepository {
+            async fn find_by_key_hash(&self, key_hash: String) -> Result<Option<ApiKeyModel>, DomainError>;
+            async fn insert(&self, api_key_create_model: &ApiKeyCreateModel) -> Result<ApiKeyModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_api_key_authenticated() {
+        let mut api_key_repository = MockFakeApiKeyRepository::new();
+
+        api_key_repository
+            .expect_find_by_key_hash()
+            .return_once(|_| Ok(Some(ApiKeyModel::mock_default())));
+
+        let result = execute(Arc::new(api_key_repository), Some(random_string(32))).await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_unauthorized_when_key_is_missing() {
+        let api_key_repository = MockFakeApiKeyRepository::new();
+
+        let result = execute(Arc::new(api_key_repository), None).await;
+
+        match result {
+            Err(DomainError::Unauthorized(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_unauthorized_when_key_is_unknown() {
+        let mut api_key_repository = MockFakeApiKeyRepository::new();
+
+        api_key_repository
+            .expect_find_by_key_hash()
+            .return_once(|_| Ok(None));
+
+        let result = execute(Arc::new(api_key_repository), Some(random_string(32))).await;
+
+        match result {
+            Err(DomainError::Unauthorized(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_forbidden_when_key_is_disabled() {
+        let mut api_key_repository = MockFakeApiKeyRepository::new();
+
+        let mut api_key = ApiKeyModel::mock_default();
+        api_key.active = false;
+        api_key_repository
+            .expect_find_by_key_hash()
+            .return_once(|_| Ok(Some(api_key)));
+
+        let result = execute(Arc::new(api_key_repository), Some(random_string(32))).await;
+
+        match result {
+            Err(DomainError::Forbidden(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use rand::distributions::{Alphanumeric, DistString};
+
+use crate::domain::{
+    api_key::{
+        model::{ApiKeyCreateModel, ApiKeyModel},
+        repository::ApiKeyRepository,
+    },
+    error::DomainError,
+};
+
+const API_KEY_LENGTH: usize = 40;
+
+/// Generates a new key and stores only its hash. The raw key is returned once
+/// and cannot be recovered afterwards.
+pub async fn execute(
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    name: String,
+) -> Result<(ApiKeyModel, String), DomainError> {
+    let key = Alphanumeric.sample_string(&mut rand::thread_rng(), API_KEY_LENGTH);
+
+    let api_key = api_key_repository
+        .insert(&ApiKeyCreateModel::new(name, &key, true))
+        .await?;
+
+    Ok((api_key, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{api::utils::random_string, domain::api_key::model::hash_key};
+
+    use super::*;
+
+    mock! {
+        pub FakeApiKeyRepository { }
+
+        #[async_trait]
+        impl ApiKeyRepository for FakeApiKeyRepository {
+            async fn find_by_key_hash(&self, key_hash: String) -> Result<Option<ApiKeyModel>, DomainError>;
+            async fn insert(&self, api_key_create_model: &ApiKeyCreateModel) -> Result<ApiKeyModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_api_key_created() {
+        let mut api_key_repository = MockFakeApiKeyRepository::new();
+
+        api_key_repository.expect_insert().return_once(|model| {
+            let mut api_key = ApiKeyModel::mock_default();
+            api_key.key_hash = model.key_hash.clone();
+            Ok(api_key)
+        });
+
+        let (api_key, key) = execute(Arc::new(api_key_repository), random_string(10))
+            .await
+            .unwrap();
+
+        assert_eq!(key.len(), API_KEY_LENGTH);
+        assert_eq!(api_key.key_hash, hash_key(&key));
+    }
+}
@@ -1,4 +1,7 @@
 use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::categories::model::CategoryModel;
 
 
 #[cfg(test)]
@@ -17,6 +20,7 @@ pub struct ArticleCreateModel {
     pub link: String,
     pub author: String,
     pub publish_at: DateTime<Utc>,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -31,6 +35,7 @@ impl ArticleCreateModel {
         link: String,
         author: String,
         publish_at: DateTime<Utc>,
+        categoryids: Vec<i32>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link: Option<String>,
@@ -44,6 +49,7 @@ impl ArticleCreateModel {
             author,
             link,
             publish_at,
+            categoryids,
             highres_link,
             photo_link,
             thumb_link,
@@ -63,6 +69,7 @@ impl ArticleCreateModel {
             source: "source".to_string(),
             author: "author".to_string(),
             publish_at: DateTime::default(),
+            categoryids: vec![],
             highres_link: Some("The img".to_string()),
             photo_link: Some("The img".to_string()),
             thumb_link: Some("The img".to_string()),
@@ -79,6 +86,7 @@ pub struct ArticleUpdateModel {
     pub link: String,
     pub author: String,
     pub publish_at: DateTime<Utc>,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -92,6 +100,7 @@ impl ArticleUpdateModel {
         source: String,
         author: String,
         publish_at: DateTime<Utc>,
+        categoryids: Vec<i32>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link: Option<String>,
@@ -104,6 +113,7 @@ impl ArticleUpdateModel {
             source,
             author,
             publish_at,
+            categoryids,
             highres_link,
             photo_link,
             thumb_link,
@@ -121,6 +131,7 @@ impl ArticleUpdateModel {
             link: random_string(10),
             author: "author".to_string(),
             publish_at: DateTime::default(),
+            categoryids: vec![],
             highres_link: Some("The img".to_string()),
             photo_link: Some("The img".to_string()),
             thumb_link: Some("The img".to_string()),
@@ -128,7 +139,7 @@ impl ArticleUpdateModel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ArticleModel {
     pub articleid: i32,
     pub extid: String,
@@ -141,9 +152,12 @@ pub struct ArticleModel {
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
+    pub categories: Vec<CategoryModel>,
     pub publish_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    /// Description excerpt highlighting the matches, only set by full-text searches.
+    pub snippet: Option<String>,
 }
 #[cfg(test)]
 impl ArticleModel {
@@ -160,9 +174,11 @@ impl ArticleModel {
             highres_link: Some("highres_link".to_string()),
             photo_link: Some("photo_link".to_string()),
             thumb_link: Some("thumb_link".to_string()),
+            categories: vec![CategoryModel::mock_default()],
             publish_at: DateTime::default(),
             created_at: DateTime::default(),
             updated_at: Some(DateTime::default()),
+            snippet: None,
         }
     }
 }
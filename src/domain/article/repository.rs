@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 
-use crate::domain::error::DomainError;
+use crate::{domain::error::DomainError, repository::article::ArticleSortOption};
 
 use super::model::{ArticleCreateModel, ArticleModel, ArticleUpdateModel};
 
@@ -10,6 +10,10 @@ pub trait ArticleRepository: Send + Sync {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        q: &Option<String>,
+        category: &Option<i32>,
+        sort: &Option<ArticleSortOption>,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
@@ -17,11 +21,13 @@ pub trait ArticleRepository: Send + Sync {
     async fn insert(
         &self,
         article_create_model: &ArticleCreateModel,
+        actor: &str,
     ) -> Result<ArticleModel, DomainError>;
     async fn update_by_articleid(
         &self,
         id: &i32,
         article_update_model: &ArticleUpdateModel,
+        actor: &str,
     ) -> Result<ArticleModel, DomainError>;
-    async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError>;
+    async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
 }
@@ -3,14 +3,27 @@ use std::sync::Arc;
 use crate::domain::article::model::ArticleModel;
 use crate::domain::{
     article::{model::ArticleCreateModel, repository::ArticleRepository},
+    categories::repository::CategoryRepository,
     error::DomainError,
 };
 
 pub async fn execute(
     article_repository: Arc<dyn ArticleRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     article_create_model: ArticleCreateModel,
 ) -> Result<ArticleModel, DomainError> {
-    let article = article_repository.insert(&article_create_model).await?;
+    for categoryid in article_create_model.categoryids.iter() {
+        let has_category = category_repository.find_by_id(categoryid).await?;
+        if has_category.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Category id {categoryid} not found"
+            )));
+        }
+    }
+
+    let article = article_repository.insert(&article_create_model, &actor).await?;
+
     Ok(article)
 }
 
@@ -19,9 +32,13 @@ mod tests {
     use crate::domain::article::model::ArticleUpdateModel;
 
     use super::*;
+    use crate::domain::categories::model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel};
+    use crate::api::utils::random_number;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::article::ArticleSortOption;
     
 
     mock! {
@@ -29,24 +46,42 @@ mod tests {
 
         #[async_trait]
         impl ArticleRepository for FakeArticleRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,q: &Option<String>,category: &Option<i32>,sort: &Option<ArticleSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
             async fn find_by_articleid(&self, id: &i32) -> Result<Option<ArticleModel>, DomainError>;
-            async fn insert(&self,article_create_model: &ArticleCreateModel) -> Result<ArticleModel, DomainError>;
-            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel) -> Result<ArticleModel, DomainError>;
-            async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,article_create_model: &ArticleCreateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
     #[tokio::test]
     async fn article_created() {
+
         let mut article_repository = MockFakeArticleRepository::new();
 
         article_repository
             .expect_insert()
-            .return_once(|_| Ok(ArticleModel::mock_default()));
+            .return_once(|_, _| Ok(ArticleModel::mock_default()));
 
         let result = execute(
             Arc::new(article_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
             ArticleCreateModel::mock_default(),
         )
         .await;
@@ -56,4 +91,30 @@ mod tests {
             Err(err) => unreachable!("{err}"),
         }
     }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_category_not_found() {
+        let article_repository = MockFakeArticleRepository::new();
+        let mut category_repository = MockFakeCategoryRepository::new();
+
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut article_create_model = ArticleCreateModel::mock_default();
+        article_create_model.categoryids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(article_repository),
+            Arc::new(category_repository),
+            random_string(10),
+            article_create_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
 }
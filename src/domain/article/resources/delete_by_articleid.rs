@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{article::repository::ArticleRepository, error::DomainError};
 
 pub async fn execute(
     article_repository: Arc<dyn ArticleRepository>,
+    actor: String,
     article_id: i32,
 ) -> Result<(), DomainError> {
     let has_article = article_repository.find_by_articleid(&article_id).await?;
@@ -13,7 +12,7 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("articleid not found")));
     }
 
-    article_repository.delete_by_articleid(&article_id).await?;
+    article_repository.delete_by_articleid(&article_id, &actor).await?;
 
     Ok(())
 }
@@ -22,6 +21,7 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::article::ArticleSortOption;
     
 
     use crate::{domain::article::model::{
@@ -29,17 +29,18 @@ mod tests {
     }, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeArticleRepository { }
 
         #[async_trait]
         impl ArticleRepository for FakeArticleRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,q: &Option<String>,category: &Option<i32>,sort: &Option<ArticleSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
             async fn find_by_articleid(&self, id: &i32) -> Result<Option<ArticleModel>, DomainError>;
-            async fn insert(&self,article_create_model: &ArticleCreateModel) -> Result<ArticleModel, DomainError>;
-            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel) -> Result<ArticleModel, DomainError>;
-            async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,article_create_model: &ArticleCreateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -53,9 +54,14 @@ mod tests {
 
         article_repository
             .expect_delete_by_articleid()
-            .return_once(|_| Ok(()));
+            .return_once(|_, _| Ok(()));
 
-        let result = execute(Arc::new(article_repository), random_number()).await;
+        let result = execute(
+            Arc::new(article_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -71,7 +77,12 @@ mod tests {
             .expect_find_by_articleid()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(article_repository), random_number()).await;
+        let result = execute(
+            Arc::new(article_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
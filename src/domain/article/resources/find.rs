@@ -7,14 +7,19 @@ use crate::domain::{
         repository::ArticleRepository},
     error::DomainError,
 };
+use crate::repository::article::ArticleSortOption;
 
 pub async fn execute(
     article_repository: Arc<dyn ArticleRepository>,
     name: Option<String>,
+    fuzzy: bool,
+    q: Option<String>,
+    category: Option<i32>,
+    sort: Option<ArticleSortOption>,
     page: u32,
     page_size: u32,
 ) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError> {
-    let article = article_repository.find(&name, &page, &page_size).await?;
+    let article = article_repository.find(&name, &fuzzy, &q, &category, &sort, &page, &page_size).await?;
 
     if article.is_some() {
         return Ok(article);
@@ -38,11 +43,11 @@ mod tests {
 
         #[async_trait]
         impl ArticleRepository for FakeArticleRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,q: &Option<String>,category: &Option<i32>,sort: &Option<ArticleSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
             async fn find_by_articleid(&self, id: &i32) -> Result<Option<ArticleModel>, DomainError>;
-            async fn insert(&self,article_create_model: &ArticleCreateModel) -> Result<ArticleModel, DomainError>;
-            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel) -> Result<ArticleModel, DomainError>;
-            async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,article_create_model: &ArticleCreateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -51,9 +56,9 @@ mod tests {
         let mut article_repository = MockFakeArticleRepository::new();
         article_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(Some((vec![ArticleModel::mock_default()], 1))));
+            .return_once(|_, _, _, _, _, _, _| Ok(Some((vec![ArticleModel::mock_default()], 1))));
 
-        let (article, count) = execute(Arc::new(article_repository), None, 1, 12)
+        let (article, count) = execute(Arc::new(article_repository), None, false, None, None, None, 1, 12)
             .await
             .unwrap()
             .unwrap();
@@ -67,9 +72,9 @@ mod tests {
         let mut article_repository = MockFakeArticleRepository::new();
         article_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(None));
+            .return_once(|_, _, _, _, _, _, _| Ok(None));
 
-        let response = execute(Arc::new(article_repository), None, 1, 12)
+        let response = execute(Arc::new(article_repository), None, false, None, None, None, 1, 12)
             .await
             .unwrap();
 
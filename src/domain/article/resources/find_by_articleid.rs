@@ -22,6 +22,7 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::article::ArticleSortOption;
 
     use crate::{domain::article::model::{ArticleCreateModel, ArticleUpdateModel, ArticleModel}, api::utils::random_number};
 
@@ -32,11 +33,11 @@ mod tests {
 
         #[async_trait]
         impl ArticleRepository for FakeArticleRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,q: &Option<String>,category: &Option<i32>,sort: &Option<ArticleSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError>;
             async fn find_by_articleid(&self, id: &i32) -> Result<Option<ArticleModel>, DomainError>;
-            async fn insert(&self,article_create_model: &ArticleCreateModel) -> Result<ArticleModel, DomainError>;
-            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel) -> Result<ArticleModel, DomainError>;
-            async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,article_create_model: &ArticleCreateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn update_by_articleid(&self,id: &i32,article_update_model: &ArticleUpdateModel,actor: &str) -> Result<ArticleModel, DomainError>;
+            async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
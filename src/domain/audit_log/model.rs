@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+#[cfg(test)]
+use crate::api::utils::random_number;
+#[cfg(test)]
+use crate::api::utils::random_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditLogCreateModel {
+    pub actor: String,
+    pub resource: String,
+    pub resourceid: i32,
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl AuditLogCreateModel {
+    pub fn created<T: Serialize>(
+        actor: String,
+        resource: &str,
+        resourceid: i32,
+        after: &T,
+    ) -> Self {
+        Self {
+            actor,
+            resource: resource.to_owned(),
+            resourceid,
+            action: AuditAction::Create,
+            before: None,
+            after: serde_json::to_value(after).ok(),
+        }
+    }
+
+    pub fn updated<B: Serialize, A: Serialize>(
+        actor: String,
+        resource: &str,
+        resourceid: i32,
+        before: &B,
+        after: &A,
+    ) -> Self {
+        Self {
+            actor,
+            resource: resource.to_owned(),
+            resourceid,
+            action: AuditAction::Update,
+            before: serde_json::to_value(before).ok(),
+            after: serde_json::to_value(after).ok(),
+        }
+    }
+
+    pub fn deleted<T: Serialize>(
+        actor: String,
+        resource: &str,
+        resourceid: i32,
+        before: &T,
+    ) -> Self {
+        Self {
+            actor,
+            resource: resource.to_owned(),
+            resourceid,
+            action: AuditAction::Delete,
+            before: serde_json::to_value(before).ok(),
+            after: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl AuditLogCreateModel {
+    pub fn mock_default() -> Self {
+        Self::created(
+            random_string(10),
+            "organizer",
+            random_number(),
+            &serde_json::json!({ "name": random_string(10) }),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditLogModel {
+    pub auditlogid: i64,
+    pub actor: String,
+    pub resource: String,
+    pub resourceid: i32,
+    pub action: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+impl AuditLogModel {
+    pub fn mock_default() -> Self {
+        Self {
+            auditlogid: random_number() as i64,
+            actor: random_string(10),
+            resource: String::from("organizer"),
+            resourceid: random_number(),
+            action: String::from("create"),
+            before: None,
+            after: Some(serde_json::json!({ "name": random_string(10) })),
+            created_at: DateTime::default(),
+        }
+    }
+}
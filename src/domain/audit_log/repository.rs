@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+use super::model::AuditLogModel;
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn find(
+        &self,
+        resource: &Option<String>,
+        resourceid: &Option<i32>,
+        actor: &Option<String>,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<AuditLogModel>, u32)>, DomainError>;
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    audit_log::{model::AuditLogModel, repository::AuditLogRepository},
+    error::DomainError,
+};
+
+pub async fn execute(
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    resource: Option<String>,
+    resourceid: Option<i32>,
+    actor: Option<String>,
+    page: u32,
+    page_size: u32,
+) -> Result<Option<(Vec<AuditLogModel>, u32)>, DomainError> {
+    let audit_logs = audit_log_repository
+        .find(&resource, &resourceid, &actor, &page, &page_size)
+        .await?;
+
+    if audit_logs.is_some() {
+        return Ok(audit_logs);
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+
+    mock! {
+        pub FakeAuditLogRepository { }
+
+        #[async_trait]
+        impl AuditLogRepository for FakeAuditLogRepository {
+            async fn find(&self,resource: &Option<String>,resourceid: &Option<i32>,actor: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<AuditLogModel>, u32)>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_audit_logs_finded() {
+        let mut audit_log_repository = MockFakeAuditLogRepository::new();
+
+        audit_log_repository
+            .expect_find()
+            .return_once(|_, _, _, _, _| Ok(Some((vec![AuditLogModel::mock_default()], 1))));
+
+        let (audit_logs, count) = execute(
+            Arc::new(audit_log_repository),
+            Some(String::from("organizer")),
+            None,
+            None,
+            1,
+            12,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert!(!audit_logs.is_empty());
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_none_audit_logs_finded() {
+        let mut audit_log_repository = MockFakeAuditLogRepository::new();
+
+        audit_log_repository
+            .expect_find()
+            .return_once(|_, _, _, _, _| Ok(None));
+
+        let response = execute(Arc::new(audit_log_repository), None, None, None, 1, 12)
+            .await
+            .unwrap();
+
+        assert!(response.is_none());
+    }
+}
@@ -1,18 +1,28 @@
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
 #[cfg(test)]
-use crate::api::utils::random_number;
+use crate::api::utils::{random_number, random_string};
 
 #[derive(Debug, Clone)]
 pub struct CategoryCreateModel {
     pub name: String,
+    pub slug: String,
     pub description: Option<String>,
+    pub parent_id: Option<i32>,
 }
 impl CategoryCreateModel {
-    pub fn new(name: String, description: Option<String>) -> Self {
+    pub fn new(
+        name: String,
+        slug: String,
+        description: Option<String>,
+        parent_id: Option<i32>,
+    ) -> Self {
         Self {
             name,
+            slug,
             description,
+            parent_id,
         }
     }
 }
@@ -22,7 +32,9 @@ impl CategoryCreateModel {
     pub fn mock_default() -> Self {
         Self {
             name: "Burgers".to_string(),
+            slug: random_string(10).to_lowercase(),
             description: Some("The Big Burgers".to_string()),
+            parent_id: None,
         }
     }
 }
@@ -30,13 +42,25 @@ impl CategoryCreateModel {
 #[derive(Debug, Clone)]
 pub struct CategoryUpdateModel {
     pub name: String,
+    pub slug: String,
     pub description: Option<String>,
+    pub parent_id: Option<i32>,
+    pub is_active: bool,
 }
 impl CategoryUpdateModel {
-    pub fn new(name: String, description: Option<String>) -> Self {
+    pub fn new(
+        name: String,
+        slug: String,
+        description: Option<String>,
+        parent_id: Option<i32>,
+        is_active: bool,
+    ) -> Self {
         Self {
             name,
+            slug,
             description,
+            parent_id,
+            is_active,
         }
     }
 }
@@ -45,16 +69,21 @@ impl CategoryUpdateModel {
     pub fn mock_default() -> Self {
         Self {
             name: "French fries".to_string(),
+            slug: random_string(10).to_lowercase(),
             description: Some("The French fries".to_string()),
+            parent_id: None,
+            is_active: true,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CategoryModel {
     pub id: i32,
     pub name: String,
+    pub slug: String,
     pub description: Option<String>,
+    pub parent_id: Option<i32>,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -65,10 +94,38 @@ impl CategoryModel {
         Self {
             id: random_number(),
             name: "Burgers".to_string(),
+            slug: random_string(10).to_lowercase(),
             description: Some("The Big Burgers".to_string()),
+            parent_id: None,
             is_active: true,
             created_at: DateTime::default(),
             updated_at: DateTime::default(),
         }
     }
 }
+
+/// A category with its sub-categories, as returned by the tree endpoint.
+#[derive(Debug, Clone)]
+pub struct CategoryTreeModel {
+    pub category: CategoryModel,
+    pub children: Vec<CategoryTreeModel>,
+}
+impl CategoryTreeModel {
+    /// Nests `categories` under their parents. Categories whose parent is not
+    /// part of `categories` (e.g. an inactive parent that was filtered out) are
+    /// left out along with their descendants.
+    pub fn build(categories: Vec<CategoryModel>) -> Vec<Self> {
+        fn children_of(parent_id: Option<i32>, categories: &[CategoryModel]) -> Vec<CategoryTreeModel> {
+            categories
+                .iter()
+                .filter(|category| category.parent_id == parent_id)
+                .map(|category| CategoryTreeModel {
+                    category: category.clone(),
+                    children: children_of(Some(category.id), categories),
+                })
+                .collect()
+        }
+
+        children_of(None, &categories)
+    }
+}
@@ -10,18 +10,28 @@ pub trait CategoryRepository: Send + Sync {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        include_inactive: &bool,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+    async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
     async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+    async fn find_by_slug(
+        &self,
+        slug: String,
+        include_inactive: &bool,
+    ) -> Result<Option<CategoryModel>, DomainError>;
     async fn insert(
         &self,
         category_create_model: &CategoryCreateModel,
+        actor: &str,
     ) -> Result<CategoryModel, DomainError>;
     async fn update_by_id(
         &self,
         id: &i32,
         category_update_model: &CategoryUpdateModel,
+        actor: &str,
     ) -> Result<CategoryModel, DomainError>;
-    async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError>;
+    async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
 }
@@ -8,9 +8,29 @@ use crate::domain::{
 
 pub async fn execute(
     category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     category_create_model: CategoryCreateModel,
 ) -> Result<CategoryModel, DomainError> {
-    let category = category_repository.insert(&category_create_model).await?;
+    let has_slug = category_repository
+        .find_by_slug(category_create_model.slug.clone(), &true)
+        .await?;
+    if has_slug.is_some() {
+        return Err(DomainError::BadRequest(String::from(
+            "Category slug already exists",
+        )));
+    }
+
+    if let Some(parent_id) = category_create_model.parent_id {
+        let has_parent = category_repository.find_by_id(&parent_id).await?;
+        if has_parent.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Parent category id {parent_id} not found"
+            )));
+        }
+    }
+
+    let category = category_repository.insert(&category_create_model, &actor).await?;
+
     Ok(category)
 }
 
@@ -19,6 +39,7 @@ mod tests {
     use crate::domain::categories::model::CategoryUpdateModel;
 
     use super::*;
+    use crate::api::utils::{random_number, random_string};
 
     use async_trait::async_trait;
     use mockall::mock;
@@ -29,11 +50,13 @@ mod tests {
 
         #[async_trait]
         impl CategoryRepository for FakeCategoryRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
             async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
-            async fn insert(&self,category_create_model: &CategoryCreateModel) -> Result<CategoryModel, DomainError>;
-            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel) -> Result<CategoryModel, DomainError>;
-            async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -41,12 +64,17 @@ mod tests {
     async fn it_should_return_category_created() {
         let mut category_repository = MockFakeCategoryRepository::new();
 
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(None));
+
         category_repository
             .expect_insert()
-            .return_once(|_| Ok(CategoryModel::mock_default()));
+            .return_once(|_, _| Ok(CategoryModel::mock_default()));
 
         let result = execute(
             Arc::new(category_repository),
+            random_string(10),
             CategoryCreateModel::mock_default(),
         )
         .await;
@@ -56,4 +84,53 @@ mod tests {
             Err(err) => unreachable!("{err}"),
         }
     }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_parent_not_found() {
+        let mut category_repository = MockFakeCategoryRepository::new();
+
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(None));
+
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut category_create_model = CategoryCreateModel::mock_default();
+        category_create_model.parent_id = Some(random_number());
+
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            category_create_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_slug_exists() {
+        let mut category_repository = MockFakeCategoryRepository::new();
+
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(Some(CategoryModel::mock_default())));
+
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            CategoryCreateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
 }
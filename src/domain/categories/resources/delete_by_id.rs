@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{categories::repository::CategoryRepository, error::DomainError};
 
 pub async fn execute(
     category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     category_id: i32,
 ) -> Result<(), DomainError> {
     let has_category = category_repository.find_by_id(&category_id).await?;
@@ -13,7 +12,7 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Category id not found")));
     }
 
-    category_repository.delete_by_id(&category_id).await?;
+    category_repository.delete_by_id(&category_id, &actor).await?;
 
     Ok(())
 }
@@ -29,17 +28,20 @@ mod tests {
     }, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeCategoryRepository { }
 
         #[async_trait]
         impl CategoryRepository for FakeCategoryRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
             async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
-            async fn insert(&self,category_create_model: &CategoryCreateModel) -> Result<CategoryModel, DomainError>;
-            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel) -> Result<CategoryModel, DomainError>;
-            async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -53,9 +55,14 @@ mod tests {
 
         category_repository
             .expect_delete_by_id()
-            .return_once(|_| Ok(()));
+            .return_once(|_, _| Ok(()));
 
-        let result = execute(Arc::new(category_repository), random_number()).await;
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -71,7 +78,12 @@ mod tests {
             .expect_find_by_id()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(category_repository), random_number()).await;
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
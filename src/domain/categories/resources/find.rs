@@ -8,10 +8,14 @@ use crate::domain::{
 pub async fn execute(
     category_repository: Arc<dyn CategoryRepository>,
     name: Option<String>,
+    fuzzy: bool,
+    include_inactive: bool,
     page: u32,
     page_size: u32,
 ) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError> {
-    let categories = category_repository.find(&name, &page, &page_size).await?;
+    let categories = category_repository
+        .find(&name, &fuzzy, &include_inactive, &page, &page_size)
+        .await?;
 
     if categories.is_some() {
         return Ok(categories);
@@ -35,11 +39,13 @@ mod tests {
 
         #[async_trait]
         impl CategoryRepository for FakeCategoryRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
             async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
-            async fn insert(&self,category_create_model: &CategoryCreateModel) -> Result<CategoryModel, DomainError>;
-            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel) -> Result<CategoryModel, DomainError>;
-            async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -49,9 +55,9 @@ mod tests {
 
         category_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(Some((vec![CategoryModel::mock_default()], 1))));
+            .return_once(|_, _, _, _, _| Ok(Some((vec![CategoryModel::mock_default()], 1))));
 
-        let (categories, count) = execute(Arc::new(category_repository), None, 1, 12)
+        let (categories, count) = execute(Arc::new(category_repository), None, false, false, 1, 12)
             .await
             .unwrap()
             .unwrap();
@@ -65,9 +71,9 @@ mod tests {
         let mut category_repository = MockFakeCategoryRepository::new();
         category_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(None));
+            .return_once(|_, _, _, _, _| Ok(None));
 
-        let response = execute(Arc::new(category_repository), None, 1, 12)
+        let response = execute(Arc::new(category_repository), None, false, false, 1, 12)
             .await
             .unwrap();
 
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    categories::{model::CategoryModel, repository::CategoryRepository},
+    error::DomainError,
+};
+
+pub async fn execute(
+    category_repository: Arc<dyn CategoryRepository>,
+    slug: String,
+    include_inactive: bool,
+) -> Result<Option<CategoryModel>, DomainError> {
+    if let Some(category) = category_repository
+        .find_by_slug(slug, &include_inactive)
+        .await?
+    {
+        return Ok(Some(category));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::random_string,
+        domain::categories::model::{CategoryCreateModel, CategoryUpdateModel},
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_category_finded() {
+        let mut category_repository = MockFakeCategoryRepository::new();
+
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(Some(CategoryModel::mock_default())));
+
+        let result = execute(Arc::new(category_repository), random_string(10), false).await;
+
+        match result {
+            Ok(result) => assert!(result.is_some()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_none_when_slug_not_found() {
+        let mut category_repository = MockFakeCategoryRepository::new();
+
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(None));
+
+        let result = execute(Arc::new(category_repository), random_string(10), false).await;
+
+        match result {
+            Ok(result) => assert!(result.is_none()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    categories::{model::CategoryTreeModel, repository::CategoryRepository},
+    error::DomainError,
+};
+
+pub async fn execute(
+    category_repository: Arc<dyn CategoryRepository>,
+    include_inactive: bool,
+) -> Result<Vec<CategoryTreeModel>, DomainError> {
+    let categories = category_repository.find_all(&include_inactive).await?;
+
+    Ok(CategoryTreeModel::build(categories))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::domain::categories::model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel};
+
+    use super::*;
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_nested_categories() {
+        let mut root = CategoryModel::mock_default();
+        root.id = 1;
+        let mut child = CategoryModel::mock_default();
+        child.id = 2;
+        child.parent_id = Some(1);
+        let mut grandchild = CategoryModel::mock_default();
+        grandchild.id = 3;
+        grandchild.parent_id = Some(2);
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_all()
+            .return_once(move |_| Ok(vec![grandchild, child, root]));
+
+        let tree = execute(Arc::new(category_repository), false).await.unwrap();
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].category.id, 1);
+        assert_eq!(tree[0].children[0].category.id, 2);
+        assert_eq!(tree[0].children[0].children[0].category.id, 3);
+    }
+
+    #[tokio::test]
+    async fn it_should_leave_out_children_of_missing_parents() {
+        let mut child = CategoryModel::mock_default();
+        child.id = 2;
+        child.parent_id = Some(1);
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_all()
+            .return_once(move |_| Ok(vec![child]));
+
+        let tree = execute(Arc::new(category_repository), false).await.unwrap();
+
+        assert!(tree.is_empty());
+    }
+}
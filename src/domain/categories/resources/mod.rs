@@ -2,4 +2,6 @@ pub mod create;
 pub mod delete_by_id;
 pub mod find;
 pub mod find_by_id;
+pub mod find_by_slug;
+pub mod find_tree;
 pub mod update_by_id;
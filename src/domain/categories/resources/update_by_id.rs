@@ -1,6 +1,4 @@
-use std::sync::Arc;
-
-
+use std::{collections::HashSet, sync::Arc};
 
 use crate::domain::{
     categories::{
@@ -12,6 +10,7 @@ use crate::domain::{
 
 pub async fn execute(
     category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     id: i32,
     category_update_model: CategoryUpdateModel,
 ) -> Result<CategoryModel, DomainError> {
@@ -20,8 +19,38 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Category id not found")));
     }
 
+    let has_slug = category_repository
+        .find_by_slug(category_update_model.slug.clone(), &true)
+        .await?;
+    if has_slug.is_some_and(|category| category.id != id) {
+        return Err(DomainError::BadRequest(String::from(
+            "Category slug already exists",
+        )));
+    }
+
+    // Walk up from the new parent: reaching this category again means the
+    // update would make it its own ancestor.
+    let mut visited = HashSet::from([id]);
+    let mut parent_id = category_update_model.parent_id;
+    while let Some(current) = parent_id {
+        if !visited.insert(current) {
+            return Err(DomainError::BadRequest(String::from(
+                "Category parent would create a cycle",
+            )));
+        }
+
+        parent_id = match category_repository.find_by_id(&current).await? {
+            Some(parent) => parent.parent_id,
+            None => {
+                return Err(DomainError::BadRequest(format!(
+                    "Parent category id {current} not found"
+                )))
+            }
+        };
+    }
+
     let category = category_repository
-        .update_by_id(&id, &category_update_model)
+        .update_by_id(&id, &category_update_model, &actor)
         .await?;
 
     Ok(category)
@@ -32,6 +61,7 @@ mod tests {
     use crate::{domain::categories::model::CategoryCreateModel, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
@@ -41,11 +71,13 @@ mod tests {
 
         #[async_trait]
         impl CategoryRepository for FakeCategoryRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
             async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
-            async fn insert(&self,category_create_model: &CategoryCreateModel) -> Result<CategoryModel, DomainError>;
-            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel) -> Result<CategoryModel, DomainError>;
-            async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -61,12 +93,17 @@ mod tests {
             .expect_find_by_id()
             .return_once(|_| Ok(Some(mock_category_model)));
 
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(None));
+
         category_repository
             .expect_update_by_id()
-            .return_once(|_, _| Ok(CategoryModel::mock_default()));
+            .return_once(|_, _, _| Ok(CategoryModel::mock_default()));
 
         let response = execute(
             Arc::new(category_repository),
+            random_string(10),
             random_number(),
             mock_request_category_update,
         )
@@ -85,6 +122,7 @@ mod tests {
 
         let result = execute(
             Arc::new(category_repository),
+            random_string(10),
             random_number(),
             CategoryUpdateModel::mock_default(),
         )
@@ -95,4 +133,72 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_parent_creates_cycle() {
+        let mut category = CategoryModel::mock_default();
+        category.id = 1;
+        let mut child = CategoryModel::mock_default();
+        child.id = 2;
+        child.parent_id = Some(1);
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .returning(move |id| match id {
+                1 => Ok(Some(category.clone())),
+                2 => Ok(Some(child.clone())),
+                _ => Ok(None),
+            });
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(None));
+        category_repository.expect_update_by_id().never();
+
+        let mut category_update_model = CategoryUpdateModel::mock_default();
+        category_update_model.parent_id = Some(2);
+
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            1,
+            category_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_slug_belongs_to_another_category() {
+        let mut category = CategoryModel::mock_default();
+        category.id = 1;
+        let mut other = CategoryModel::mock_default();
+        other.id = 2;
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(Some(category)));
+        category_repository
+            .expect_find_by_slug()
+            .return_once(|_, _| Ok(Some(other)));
+        category_repository.expect_update_by_id().never();
+
+        let result = execute(
+            Arc::new(category_repository),
+            random_string(10),
+            1,
+            CategoryUpdateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
 }
@@ -12,6 +12,9 @@ pub struct CityCreateModel {
     pub name: String,
     pub slug: String,
     pub stateid: i32,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub time_zone: Option<String>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -23,6 +26,9 @@ impl CityCreateModel {
         slug: String,
         stateid: i32,
         extid: String,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        time_zone: Option<String>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link:Option<String>,
@@ -32,6 +38,9 @@ impl CityCreateModel {
             slug,
             stateid,
             extid,
+            latitude,
+            longitude,
+            time_zone,
             highres_link,
             photo_link,
             thumb_link,
@@ -47,6 +56,9 @@ impl CityCreateModel {
             name: random_string(10),
             slug: random_string(10),
             extid: random_string(10),
+            latitude: None,
+            longitude: None,
+            time_zone: None,
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -59,6 +71,9 @@ pub struct CityUpdateModel {
     pub name: String,
     pub slug: String,
     pub stateid: i32,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub time_zone: Option<String>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -68,6 +83,9 @@ impl CityUpdateModel {
         name: String, 
         slug: String,
         stateid: i32,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        time_zone: Option<String>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link:Option<String>,
@@ -76,6 +94,9 @@ impl CityUpdateModel {
             name,
             slug,
             stateid,
+            latitude,
+            longitude,
+            time_zone,
             highres_link,
             photo_link,
             thumb_link,
@@ -88,6 +109,9 @@ impl CityUpdateModel {
         Self {
             name: "Ohio 1".to_string(),
             slug: "ohio 1".to_string(),
+            latitude: None,
+            longitude: None,
+            time_zone: None,
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -96,7 +120,7 @@ impl CityUpdateModel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CityModel {
     pub cityid: i32,
     pub name: String,
@@ -106,6 +130,13 @@ pub struct CityModel {
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
+    pub state_name: Option<String>,
+    pub state_symbol: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub time_zone: Option<String>,
+    /// Distance from the searched point, only set by radius searches.
+    pub distance_km: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -121,6 +152,12 @@ impl CityModel {
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
+            state_name: Some(random_string(10)),
+            state_symbol: Some(random_string(2)),
+            latitude: Some(45.5019),
+            longitude: Some(-73.5674),
+            time_zone: Some("America/Toronto".to_string()),
+            distance_km: None,
             created_at: DateTime::default(),
             updated_at: Some(DateTime::default()),
         }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 
-use crate::domain::error::DomainError;
+use crate::{domain::error::DomainError, repository::city::CitySortOption};
 
 use super::model::{CityCreateModel, CityModel, CityUpdateModel, CityDetailModel};
 
@@ -10,6 +10,13 @@ pub trait CityRepository: Send + Sync {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        stateid: &Option<i32>,
+        state_symbol: &Option<String>,
+        latitude: &Option<f64>,
+        longitude: &Option<f64>,
+        radius_km: &Option<f64>,
+        sort: &Option<CitySortOption>,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
@@ -18,11 +25,13 @@ pub trait CityRepository: Send + Sync {
     async fn insert(
         &self,
         city_create_model: &CityCreateModel,
+        actor: &str,
     ) -> Result<CityModel, DomainError>;
     async fn update_by_cityid(
         &self,
         id: &i32,
         city_update_model: &CityUpdateModel,
+        actor: &str,
     ) -> Result<CityModel, DomainError>;
-    async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+    async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
 }
@@ -8,9 +8,11 @@ use crate::domain::{
 
 pub async fn execute(
     city_repository: Arc<dyn CityRepository>,
+    actor: String,
     city_create_model: CityCreateModel,
 ) -> Result<CityModel, DomainError> {
-    let category = city_repository.insert(&city_create_model).await?;
+    let category = city_repository.insert(&city_create_model, &actor).await?;
+
     Ok(category)
 }
 
@@ -19,9 +21,11 @@ mod tests {
     use crate::domain::city::model::{CityUpdateModel, CityDetailModel};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::city::CitySortOption;
     
 
     mock! {
@@ -29,12 +33,12 @@ mod tests {
 
         #[async_trait]
         impl CityRepository for FakeCityRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,stateid: &Option<i32>,state_symbol: &Option<String>,latitude: &Option<f64>,longitude: &Option<f64>,radius_km: &Option<f64>,sort: &Option<CitySortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError>;
             async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError>;
-            async fn insert(&self,city_create_model: &CityCreateModel) -> Result<CityModel, DomainError>;
-            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel) -> Result<CityModel, DomainError>;
-            async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,city_create_model: &CityCreateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -44,10 +48,11 @@ mod tests {
 
         city_repository
             .expect_insert()
-            .return_once(|_| Ok(CityModel::mock_default()));
+            .return_once(|_, _| Ok(CityModel::mock_default()));
 
         let result = execute(
             Arc::new(city_repository),
+            random_string(10),
             CityCreateModel::mock_default(),
         )
         .await;
@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{city::repository::CityRepository, error::DomainError};
 
 pub async fn execute(
     city_repository: Arc<dyn CityRepository>,
+    actor: String,
     city_id: i32,
 ) -> Result<(), DomainError> {
     let has_category = city_repository.find_by_cityid(&city_id).await?;
@@ -13,7 +12,7 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Category id not found")));
     }
 
-    city_repository.delete_by_cityid(&city_id).await?;
+    city_repository.delete_by_cityid(&city_id, &actor).await?;
 
     Ok(())
 }
@@ -22,6 +21,7 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::city::CitySortOption;
     
 
     use crate::{domain::city::model::{
@@ -29,18 +29,19 @@ mod tests {
     }, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeCityRepository { }
 
         #[async_trait]
         impl CityRepository for FakeCityRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,stateid: &Option<i32>,state_symbol: &Option<String>,latitude: &Option<f64>,longitude: &Option<f64>,radius_km: &Option<f64>,sort: &Option<CitySortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError>;
             async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError>;
-            async fn insert(&self,city_create_model: &CityCreateModel) -> Result<CityModel, DomainError>;
-            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel) -> Result<CityModel, DomainError>;
-            async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,city_create_model: &CityCreateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -54,9 +55,14 @@ mod tests {
 
         city_repository
             .expect_delete_by_cityid()
-            .return_once(|_| Ok(()));
+            .return_once(|_, _| Ok(()));
 
-        let result = execute(Arc::new(city_repository), random_number()).await;
+        let result = execute(
+            Arc::new(city_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -72,7 +78,12 @@ mod tests {
             .expect_find_by_cityid()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(city_repository), random_number()).await;
+        let result = execute(
+            Arc::new(city_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
@@ -1,17 +1,40 @@
 use std::sync::Arc;
 
-use crate::domain::{
-    city::{model::CityModel, repository::CityRepository},
-    error::DomainError,
+use crate::{
+    domain::{
+        city::{model::CityModel, repository::CityRepository},
+        error::DomainError,
+    },
+    repository::city::CitySortOption,
 };
 
 pub async fn execute(
     city_repository: Arc<dyn CityRepository>,
     name: Option<String>,
+    fuzzy: bool,
+    stateid: Option<i32>,
+    state_symbol: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    radius_km: Option<f64>,
+    sort: Option<CitySortOption>,
     page: u32,
     page_size: u32,
 ) -> Result<Option<(Vec<CityModel>, u32)>, DomainError> {
-    let article = city_repository.find(&name, &page, &page_size).await?;
+    let article = city_repository
+        .find(
+            &name,
+            &fuzzy,
+            &stateid,
+            &state_symbol,
+            &latitude,
+            &longitude,
+            &radius_km,
+            &sort,
+            &page,
+            &page_size,
+        )
+        .await?;
 
     if article.is_some() {
         return Ok(article);
@@ -35,12 +58,12 @@ mod tests {
 
         #[async_trait]
         impl CityRepository for FakeCityRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,stateid: &Option<i32>,state_symbol: &Option<String>,latitude: &Option<f64>,longitude: &Option<f64>,radius_km: &Option<f64>,sort: &Option<CitySortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError>;
             async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError>;
-            async fn insert(&self,city_create_model: &CityCreateModel) -> Result<CityModel, DomainError>;
-            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel) -> Result<CityModel, DomainError>;
-            async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,city_create_model: &CityCreateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -50,9 +73,9 @@ mod tests {
 
         city_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(Some((vec![CityModel::mock_default()], 1))));
+            .return_once(|_, _, _, _, _, _, _, _, _, _| Ok(Some((vec![CityModel::mock_default()], 1))));
 
-        let (article, count) = execute(Arc::new(city_repository), None, 1, 12)
+        let (article, count) = execute(Arc::new(city_repository), None, false, None, None, None, None, None, None, 1, 12)
             .await
             .unwrap()
             .unwrap();
@@ -66,9 +89,9 @@ mod tests {
         let mut city_repository = MockFakeCityRepository::new();
         city_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(None));
+            .return_once(|_, _, _, _, _, _, _, _, _, _| Ok(None));
 
-        let response = execute(Arc::new(city_repository), None, 1, 12)
+        let response = execute(Arc::new(city_repository), None, false, None, None, None, None, None, None, 1, 12)
             .await
             .unwrap();
 
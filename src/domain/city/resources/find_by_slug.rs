@@ -21,6 +21,7 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::city::CitySortOption;
 
     use crate::{domain::city::model::{CityCreateModel, CityUpdateModel,CityDetailModel,CityModel}, api::utils::{random_string}};
 
@@ -31,12 +32,12 @@ mod tests {
 
         #[async_trait]
         impl CityRepository for FakeCityRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,stateid: &Option<i32>,state_symbol: &Option<String>,latitude: &Option<f64>,longitude: &Option<f64>,radius_km: &Option<f64>,sort: &Option<CitySortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError>;
             async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError>;
-            async fn insert(&self,city_create_model: &CityCreateModel) -> Result<CityModel, DomainError>;
-            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel) -> Result<CityModel, DomainError>;
-            async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,city_create_model: &CityCreateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
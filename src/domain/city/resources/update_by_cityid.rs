@@ -1,7 +1,5 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{
     city::{
         model::{CityModel, CityUpdateModel},
@@ -12,6 +10,7 @@ use crate::domain::{
 
 pub async fn execute(
     city_repository: Arc<dyn CityRepository>,
+    actor: String,
     id: i32,
     city_update_model: CityUpdateModel,
 ) -> Result<CityModel, DomainError> {
@@ -21,7 +20,7 @@ pub async fn execute(
     }
 
     let category = city_repository
-        .update_by_cityid(&id, &city_update_model)
+        .update_by_cityid(&id, &city_update_model, &actor)
         .await?;
 
     Ok(category)
@@ -32,21 +31,23 @@ mod tests {
     use crate::{domain::city::model::{CityCreateModel,CityDetailModel}, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::repository::city::CitySortOption;
 
     mock! {
         pub FakeCityRepository { }
 
         #[async_trait]
         impl CityRepository for FakeCityRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,stateid: &Option<i32>,state_symbol: &Option<String>,latitude: &Option<f64>,longitude: &Option<f64>,radius_km: &Option<f64>,sort: &Option<CitySortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<CityModel>, u32)>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError>;
             async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError>;
-            async fn insert(&self,city_create_model: &CityCreateModel) -> Result<CityModel, DomainError>;
-            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel) -> Result<CityModel, DomainError>;
-            async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,city_create_model: &CityCreateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn update_by_cityid(&self,id: &i32,city_update_model: &CityUpdateModel,actor: &str) -> Result<CityModel, DomainError>;
+            async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -64,10 +65,11 @@ mod tests {
 
         city_repository
             .expect_update_by_cityid()
-            .return_once(|_, _| Ok(CityModel::mock_default()));
+            .return_once(|_, _, _| Ok(CityModel::mock_default()));
 
         let response = execute(
             Arc::new(city_repository),
+            random_string(10),
             random_number(),
             mock_request_city_update,
         )
@@ -86,6 +88,7 @@ mod tests {
 
         let result = execute(
             Arc::new(city_repository),
+            random_string(10),
             random_number(),
             CityUpdateModel::mock_default(),
         )
@@ -9,6 +9,12 @@ pub enum DomainError {
     #[error("{}", _0)]
     BadRequest(String),
 
+    #[error("{}", _0)]
+    Unauthorized(String),
+
+    #[error("{}", _0)]
+    Forbidden(String),
+
     #[error("{}", _0)]
     InternalServerError(String),
 }
@@ -1,15 +1,28 @@
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::categories::model::CategoryModel,
+    repository::event::{DateRangeOption, EventSortOption, EventStatusOption},
+};
 
 #[cfg(test)]
 use crate::api::utils::random_number;
 #[cfg(test)]
 use crate::api::utils::random_string;
 
+/// Currency of event prices when none is given.
+pub const DEFAULT_CURRENCY: &str = "CAD";
+
 #[derive(Debug, Clone)]
 pub struct EventCreateModel {
     pub name: String,
     pub description: String,
     pub location: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub extid: String,
     pub groupid: i32,
     pub in_person: bool,
@@ -19,8 +32,13 @@ pub struct EventCreateModel {
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    pub price_min: Option<i32>,
+    pub price_max: Option<i32>,
+    pub currency: String,
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -30,6 +48,8 @@ impl EventCreateModel {
         name: String, 
         description: String,
         location: String,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
         groupid: i32,
         extid: String,
         link: String,
@@ -41,14 +61,22 @@ impl EventCreateModel {
         fee: bool,
         rsvp_limit: i32,
         time: DateTime<Utc>,
+        categoryids: Vec<i32>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link: Option<String>,
+        price_min: Option<i32>,
+        price_max: Option<i32>,
+        currency: Option<String>,
+        ticket_url: Option<String>,
     ) -> Self {
+        let price_max = price_max.or(price_min);
         Self {
             name,
             description,
             location, 
+            latitude,
+            longitude,
             groupid,
             extid, 
             in_person, 
@@ -57,9 +85,14 @@ impl EventCreateModel {
             duration, 
             link, 
             waitlist_count, 
-            fee, 
+            fee: price_max.map_or(fee, |price_max| price_max > 0),
+            price_min,
+            price_max,
+            currency: currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string()),
+            ticket_url,
             yes_rsvp_count, 
             rsvp_limit, 
+            categoryids,
             highres_link, 
             photo_link,
             thumb_link,
@@ -74,6 +107,8 @@ impl EventCreateModel {
             name: "event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             groupid: random_number(),
             extid: random_string(10),
             in_person: true,
@@ -83,8 +118,13 @@ impl EventCreateModel {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: Some(0),
+            currency: DEFAULT_CURRENCY.to_string(),
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
+            categoryids: vec![],
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -97,6 +137,8 @@ pub struct EventUpdateModel {
     pub name: String,
     pub description: String,
     pub location: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub groupid: i32,
     pub in_person: bool,
     pub is_online: bool,
@@ -105,8 +147,13 @@ pub struct EventUpdateModel {
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    pub price_min: Option<i32>,
+    pub price_max: Option<i32>,
+    pub currency: String,
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -116,6 +163,8 @@ impl EventUpdateModel {
         name: String, 
         description: String,
         location: String,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
         groupid: i32,
         link: String,
         in_person: bool,
@@ -126,14 +175,22 @@ impl EventUpdateModel {
         fee: bool,
         rsvp_limit: i32,
         time: DateTime<Utc>,
+        categoryids: Vec<i32>,
         highres_link: Option<String>,
         photo_link: Option<String>,
         thumb_link: Option<String>,
+        price_min: Option<i32>,
+        price_max: Option<i32>,
+        currency: Option<String>,
+        ticket_url: Option<String>,
     ) -> Self {
+        let price_max = price_max.or(price_min);
         Self {
             name,
             description,
             location, 
+            latitude,
+            longitude,
             groupid,
             in_person, 
             is_online, 
@@ -141,15 +198,49 @@ impl EventUpdateModel {
             duration, 
             link, 
             waitlist_count, 
-            fee, 
+            fee: price_max.map_or(fee, |price_max| price_max > 0),
+            price_min,
+            price_max,
+            currency: currency.unwrap_or_else(|| DEFAULT_CURRENCY.to_string()),
+            ticket_url,
             yes_rsvp_count, 
             rsvp_limit, 
+            categoryids,
             highres_link, 
             photo_link,
             thumb_link,
         }
     }
 }
+impl From<EventCreateModel> for EventUpdateModel {
+    fn from(value: EventCreateModel) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            location: value.location,
+            latitude: value.latitude,
+            longitude: value.longitude,
+            groupid: value.groupid,
+            in_person: value.in_person,
+            is_online: value.is_online,
+            time: value.time,
+            duration: value.duration,
+            link: value.link,
+            waitlist_count: value.waitlist_count,
+            fee: value.fee,
+            price_min: value.price_min,
+            price_max: value.price_max,
+            currency: value.currency,
+            ticket_url: value.ticket_url,
+            yes_rsvp_count: value.yes_rsvp_count,
+            rsvp_limit: value.rsvp_limit,
+            categoryids: value.categoryids,
+            highres_link: value.highres_link,
+            photo_link: value.photo_link,
+            thumb_link: value.thumb_link,
+        }
+    }
+}
 #[cfg(test)]
 impl EventUpdateModel {
     pub fn mock_default() -> Self {
@@ -157,6 +248,8 @@ impl EventUpdateModel {
             name: "Event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             groupid: random_number(),
             in_person: true,
             is_online: true,
@@ -165,8 +258,13 @@ impl EventUpdateModel {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: Some(0),
+            currency: DEFAULT_CURRENCY.to_string(),
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
+            categoryids: vec![],
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
@@ -174,12 +272,79 @@ impl EventUpdateModel {
     }
 }
 
+/// Where an event stands. Cancelled, postponed and rescheduled events are
+/// kept so links to them keep resolving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventLifecycleStatus {
+    Scheduled,
+    Cancelled,
+    Postponed,
+    /// Replaced by the event in `rescheduled_to`.
+    Rescheduled,
+}
+impl EventLifecycleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Cancelled => "cancelled",
+            Self::Postponed => "postponed",
+            Self::Rescheduled => "rescheduled",
+        }
+    }
+}
+impl FromStr for EventLifecycleStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "scheduled" => Ok(Self::Scheduled),
+            "cancelled" => Ok(Self::Cancelled),
+            "postponed" => Ok(Self::Postponed),
+            "rescheduled" => Ok(Self::Rescheduled),
+            _ => Err(format!("unknown event lifecycle status {value}")),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
+pub struct EventLifecycleUpdateModel {
+    pub lifecycle_status: EventLifecycleStatus,
+    pub lifecycle_reason: Option<String>,
+    pub rescheduled_to: Option<i32>,
+}
+impl EventLifecycleUpdateModel {
+    pub fn new(
+        lifecycle_status: EventLifecycleStatus,
+        lifecycle_reason: Option<String>,
+        rescheduled_to: Option<i32>,
+    ) -> Self {
+        Self {
+            lifecycle_status,
+            lifecycle_reason,
+            rescheduled_to,
+        }
+    }
+}
+#[cfg(test)]
+impl EventLifecycleUpdateModel {
+    pub fn mock_default() -> Self {
+        Self {
+            lifecycle_status: EventLifecycleStatus::Cancelled,
+            lifecycle_reason: Some("Venue closed".to_string()),
+            rescheduled_to: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct EventModel {
     pub eventid: i32,
     pub name: String,
     pub description: String,
     pub location: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub extid: String,
     pub groupid: i32,
     pub in_person: bool,
@@ -189,11 +354,19 @@ pub struct EventModel {
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    pub price_min: Option<i32>,
+    pub price_max: Option<i32>,
+    pub currency: String,
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
+    pub categories: Vec<CategoryModel>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
+    pub lifecycle_status: EventLifecycleStatus,
+    pub lifecycle_reason: Option<String>,
+    pub rescheduled_to: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -206,6 +379,8 @@ impl EventModel {
             name: "Event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             extid: random_string(10),
             in_person: true,
             is_online: true,
@@ -214,11 +389,19 @@ impl EventModel {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: Some(0),
+            currency: DEFAULT_CURRENCY.to_string(),
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
+            categories: vec![CategoryModel::mock_default()],
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
+            lifecycle_status: EventLifecycleStatus::Scheduled,
+            lifecycle_reason: None,
+            rescheduled_to: None,
             created_at: DateTime::default(),
             updated_at: Some(DateTime::default()),
         }
@@ -231,8 +414,15 @@ pub struct EventDetailModel {
     pub name: String,
     pub description: String,
     pub location: String,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
     pub group_name: Option<String>,
     pub group_slug: Option<String>,
+    pub city_name: Option<String>,
+    pub city_slug: Option<String>,
+    pub state_name: Option<String>,
+    pub state_symbol: Option<String>,
+    pub extid: String,
     pub in_person: bool,
     pub is_online: bool,
     pub time: DateTime<Utc>,
@@ -240,11 +430,27 @@ pub struct EventDetailModel {
     pub link: String,
     pub waitlist_count: i32,
     pub fee: bool,
+    pub price_min: Option<i32>,
+    pub price_max: Option<i32>,
+    pub currency: String,
+    pub ticket_url: Option<String>,
     pub yes_rsvp_count: i32,
     pub rsvp_limit: i32,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
+    pub lifecycle_status: EventLifecycleStatus,
+    pub lifecycle_reason: Option<String>,
+    pub rescheduled_to: Option<i32>,
+    /// Distance from the searched point, only set by radius searches.
+    pub distance_km: Option<f64>,
+    /// Zone the event was matched in: the requested one, else its city's, else UTC.
+    pub time_zone: String,
+    /// Description excerpt highlighting the matches, only set by full-text searches.
+    pub snippet: Option<String>,
+    /// Set on occurrences of a series, with the start the rule gave them.
+    pub seriesid: Option<i32>,
+    pub occurrence: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -255,9 +461,16 @@ impl EventDetailModel {
             eventid: random_number(),
             group_name: Some(random_string(5)),
             group_slug: Some(random_string(5)),
+            city_name: Some("Montreal".to_string()),
+            city_slug: Some("montreal".to_string()),
+            state_name: Some("Quebec".to_string()),
+            state_symbol: Some("qc".to_string()),
+            extid: random_string(10),
             name: "Event".to_string(),
             description: "The Big Event".to_string(),
             location: "boulvar".to_string(),
+            latitude: None,
+            longitude: None,
             in_person: true,
             is_online: true,
             time: DateTime::default(),
@@ -265,13 +478,56 @@ impl EventDetailModel {
             link: random_string(10),
             waitlist_count: 5,
             fee: false,
+            price_min: Some(0),
+            price_max: Some(0),
+            currency: DEFAULT_CURRENCY.to_string(),
+            ticket_url: None,
             yes_rsvp_count: 5,
             rsvp_limit: 5,
             highres_link: Some("".to_string()),
             photo_link: Some("".to_string()),
             thumb_link: Some("".to_string()),
+            lifecycle_status: EventLifecycleStatus::Scheduled,
+            lifecycle_reason: None,
+            rescheduled_to: None,
+            distance_km: None,
+            time_zone: "America/Toronto".to_string(),
+            snippet: None,
+            seriesid: None,
+            occurrence: None,
             created_at: DateTime::default(),
             updated_at: Some(DateTime::default()),
         }
     }
 }
+
+/// Criteria of an event search. Unset fields do not filter.
+#[derive(Debug, Clone, Default)]
+pub struct EventFindFilter {
+    pub name: Option<String>,
+    /// Ranks names by similarity instead of matching them exactly.
+    pub fuzzy: bool,
+    /// Full-text query over names and descriptions.
+    pub q: Option<String>,
+    pub in_person: Option<bool>,
+    pub is_online: Option<bool>,
+    pub group_slug: Option<String>,
+    pub city_slug: Option<String>,
+    pub state_symbol: Option<String>,
+    pub location: Option<String>,
+    pub has_fee: Option<bool>,
+    pub max_price: Option<i32>,
+    pub rsvp_limit: Option<u32>,
+    pub status: Option<EventStatusOption>,
+    pub lifecycle_status: Option<EventLifecycleStatus>,
+    pub time_frame: Option<DateRangeOption>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    /// Zone calendar time frames are observed in.
+    pub time_zone: Option<String>,
+    pub category: Option<i32>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub radius_km: Option<f64>,
+    pub sort: Option<EventSortOption>,
+}
@@ -1,26 +1,17 @@
 use async_trait::async_trait;
-use chrono::{Utc, DateTime};
 
+use crate::domain::{error::DomainError, upsert::UpsertModel};
 
-use crate::{domain::error::DomainError, repository::event::{EventStatusOption, DateRangeOption}};
-
-use super::model::{EventCreateModel, EventModel, EventUpdateModel, EventDetailModel};
+use super::model::{
+    EventCreateModel, EventDetailModel, EventFindFilter, EventLifecycleUpdateModel, EventModel,
+    EventUpdateModel,
+};
 
 #[async_trait]
 pub trait EventRepository: Send + Sync {
     async fn find(
         &self,
-        name: &Option<String>,
-        in_person: &Option<bool>,
-        is_online: &Option<bool>,
-        group_slug: &Option<String>,
-        location: &Option<String>,
-        has_fee: &Option<bool>,
-        rsvp_limit: &Option<u32>,
-        status: &Option<EventStatusOption>,
-        time_frame: &Option<DateRangeOption>,
-        start_date: &Option<DateTime<Utc>>,
-        end_date: &Option<DateTime<Utc>>,
+        filter: &EventFindFilter,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
@@ -28,11 +19,32 @@ pub trait EventRepository: Send + Sync {
     async fn insert(
         &self,
         event_create_model: &EventCreateModel,
+        actor: &str,
     ) -> Result<EventModel, DomainError>;
     async fn update_by_eventid(
         &self,
         id: &i32,
         event_update_model: &EventUpdateModel,
+        actor: &str,
+    ) -> Result<EventModel, DomainError>;
+    async fn upsert_by_extid(
+        &self,
+        extid: &str,
+        event_update_model: &EventUpdateModel,
+        actor: &str,
+    ) -> Result<UpsertModel<EventModel>, DomainError>;
+    /// Upserts each event by its extid in one transaction. A failing event,
+    /// audit entry included, is rolled back on its own and reported in its
+    /// slot of the result.
+    async fn bulk_upsert_by_extid(
+        &self,
+        event_create_models: &[EventCreateModel],
+        actor: &str,
+    ) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+    async fn update_lifecycle_by_eventid(
+        &self,
+        id: &i32,
+        event_lifecycle_update_model: &EventLifecycleUpdateModel,
+        actor: &str,
     ) -> Result<EventModel, DomainError>;
-    async fn delete_by_eventid(&self, id: &i32) -> Result<(), DomainError>;
 }
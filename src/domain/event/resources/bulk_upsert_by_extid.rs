@@ -0,0 +1,197 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::domain::{
+    categories::repository::CategoryRepository,
+    error::DomainError,
+    event::{
+        model::{EventCreateModel, EventModel},
+        repository::EventRepository,
+    },
+    upsert::UpsertModel,
+};
+
+/// Upserts a batch of events by their extid. Each event gets its own result,
+/// in the order given, so one bad event does not fail the others.
+pub async fn execute(
+    event_repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
+    event_create_models: Vec<EventCreateModel>,
+) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError> {
+    let mut results: Vec<Option<Result<UpsertModel<EventModel>, DomainError>>> =
+        event_create_models.iter().map(|_| None).collect();
+    let mut has_categories: HashMap<i32, bool> = HashMap::new();
+    let mut positions = vec![];
+    let mut valid_event_create_models = vec![];
+
+    for (position, event_create_model) in event_create_models.into_iter().enumerate() {
+        let mut missing_categoryid = None;
+        for categoryid in event_create_model.categoryids.iter() {
+            let has_category = match has_categories.get(categoryid) {
+                Some(has_category) => *has_category,
+                None => {
+                    let has_category = category_repository.find_by_id(categoryid).await?.is_some();
+                    has_categories.insert(*categoryid, has_category);
+                    has_category
+                }
+            };
+            if !has_category {
+                missing_categoryid = Some(*categoryid);
+                break;
+            }
+        }
+
+        match missing_categoryid {
+            Some(categoryid) => {
+                results[position] = Some(Err(DomainError::BadRequest(format!(
+                    "Category id {categoryid} not found"
+                ))));
+            }
+            None => {
+                positions.push(position);
+                valid_event_create_models.push(event_create_model);
+            }
+        }
+    }
+
+    if !valid_event_create_models.is_empty() {
+        let upserted = event_repository
+            .bulk_upsert_by_extid(&valid_event_create_models, &actor)
+            .await?;
+
+        for (position, result) in positions.into_iter().zip(upserted) {
+            results[position] = Some(result);
+        }
+    }
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::event::model::{
+            EventDetailModel, EventFindFilter, EventLifecycleUpdateModel, EventUpdateModel,
+        },
+    };
+
+    use super::*;
+    use crate::domain::categories::model::{
+        CategoryCreateModel, CategoryModel, CategoryUpdateModel,
+    };
+    use crate::domain::upsert::UpsertOutcome;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub FakeEventRepository { }
+
+        #[async_trait]
+        impl EventRepository for FakeEventRepository {
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_a_result_per_event() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_bulk_upsert_by_extid()
+            .withf(|event_create_models, _| event_create_models.len() == 2)
+            .return_once(|_, _| {
+                Ok(vec![
+                    Ok(UpsertModel {
+                        outcome: UpsertOutcome::Created,
+                        before: None,
+                        record: EventModel::mock_default(),
+                    }),
+                    Err(DomainError::InternalServerError(String::from("db error"))),
+                ])
+            });
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .times(1)
+            .return_once(|_| Ok(None));
+
+        let mut missing_category = EventCreateModel::mock_default();
+        missing_category.categoryids = vec![random_number()];
+        let mut same_missing_category = EventCreateModel::mock_default();
+        same_missing_category.categoryids = vec![random_number()];
+
+        let results = execute(
+            Arc::new(event_repository),
+            Arc::new(category_repository),
+            random_string(10),
+            vec![
+                EventCreateModel::mock_default(),
+                missing_category,
+                EventCreateModel::mock_default(),
+                same_missing_category,
+            ],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results.len(), 4);
+        assert!(matches!(
+            &results[0],
+            Ok(upserted) if upserted.outcome == UpsertOutcome::Created
+        ));
+        assert!(matches!(&results[1], Err(DomainError::BadRequest(_))));
+        assert!(matches!(
+            &results[2],
+            Err(DomainError::InternalServerError(_))
+        ));
+        assert!(matches!(&results[3], Err(DomainError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_call_repository_when_no_event_is_valid() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository.expect_bulk_upsert_by_extid().never();
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut event_create_model = EventCreateModel::mock_default();
+        event_create_model.categoryids = vec![random_number()];
+
+        let results = execute(
+            Arc::new(event_repository),
+            Arc::new(category_repository),
+            random_string(10),
+            vec![event_create_model],
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(&results[0], Err(DomainError::BadRequest(_))));
+    }
+}
@@ -1,19 +1,36 @@
 use std::sync::Arc;
 
-
-
-use crate::domain::{event::repository::EventRepository, error::DomainError};
-
+use crate::domain::{
+    error::DomainError,
+    event::{
+        model::{EventLifecycleStatus, EventLifecycleUpdateModel},
+        repository::EventRepository,
+    },
+};
+
+/// Cancels event `event_id`. Events are never removed, so links to a
+/// cancelled one keep resolving; an event already cancelled is left as is.
 pub async fn execute(
     event_repository: Arc<dyn EventRepository>,
+    actor: String,
     event_id: i32,
 ) -> Result<(), DomainError> {
-    let has_event = event_repository.find_by_eventid(&event_id).await?;
-    if has_event.is_none() {
-        return Err(DomainError::NotFound(String::from("Event id not found")));
+    let event = match event_repository.find_by_eventid(&event_id).await? {
+        Some(event) => event,
+        None => return Err(DomainError::NotFound(String::from("Event id not found"))),
+    };
+
+    if event.lifecycle_status == EventLifecycleStatus::Cancelled {
+        return Ok(());
     }
 
-    event_repository.delete_by_eventid(&event_id).await?;
+    event_repository
+        .update_lifecycle_by_eventid(
+            &event_id,
+            &EventLifecycleUpdateModel::new(EventLifecycleStatus::Cancelled, None, None),
+            &actor,
+        )
+        .await?;
 
     Ok(())
 }
@@ -21,46 +38,34 @@ pub async fn execute(
 #[cfg(test)]
 mod tests {
     use async_trait::async_trait;
-    use chrono::{DateTime, Utc};
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
     
 
     use crate::{domain::event::model::{
-        EventCreateModel, EventModel, EventUpdateModel, EventDetailModel,
-    }, api::utils::random_number, repository::event::{EventStatusOption, DateRangeOption}};
+        EventCreateModel, EventModel, EventUpdateModel, EventDetailModel, EventFindFilter,
+    }, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeEventRepository { }
 
         #[async_trait]
         impl EventRepository for FakeEventRepository {
-            async fn find(
-        &self,
-        name: &Option<String>,
-        in_person: &Option<bool>,
-        is_online: &Option<bool>,
-        group_slug: &Option<String>,
-        location: &Option<String>,
-        has_fee: &Option<bool>,
-        rsvp_limit: &Option<u32>,
-        status: &Option<EventStatusOption>,
-        time_frame: &Option<DateRangeOption>,
-        start_date: &Option<DateTime<Utc>>,
-        end_date: &Option<DateTime<Utc>>,
-        page: &u32,
-        page_size: &u32,
-    ) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
             async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
-            async fn insert(&self,event_create_model: &EventCreateModel) -> Result<EventModel, DomainError>;
-            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel) -> Result<EventModel, DomainError>;
-            async fn delete_by_eventid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
         }
     }
 
     #[tokio::test]
-    async fn it_should_return_void_event_deleted() {
+    async fn it_should_cancel_event_deleted() {
         let mut event_repository = MockFakeEventRepository::new();
 
         event_repository
@@ -68,10 +73,42 @@ mod tests {
             .return_once(|_| Ok(Some(EventModel::mock_default())));
 
         event_repository
-            .expect_delete_by_eventid()
-            .return_once(|_| Ok(()));
+            .expect_update_lifecycle_by_eventid()
+            .withf(|_, event_lifecycle_update_model, _| {
+                event_lifecycle_update_model.lifecycle_status == EventLifecycleStatus::Cancelled
+            })
+            .return_once(|_, _, _| Ok(EventModel::mock_default()));
+
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
+
+        match result {
+            Ok(()) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_leave_cancelled_event_as_is() {
+        let mut event_repository = MockFakeEventRepository::new();
+
+        let mut event = EventModel::mock_default();
+        event.lifecycle_status = EventLifecycleStatus::Cancelled;
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(event)));
+        event_repository.expect_update_lifecycle_by_eventid().never();
 
-        let result = execute(Arc::new(event_repository), random_number()).await;
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -87,7 +124,12 @@ mod tests {
             .expect_find_by_eventid()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(event_repository), random_number()).await;
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
@@ -20,10 +20,11 @@ pub async fn execute(
 #[cfg(test)]
 mod tests {
     use async_trait::async_trait;
-    use chrono::{Utc, DateTime};
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::domain::event::model::EventLifecycleUpdateModel;
 
-    use crate::{domain::event::model::{EventCreateModel, EventUpdateModel, EventDetailModel}, api::utils::random_number, repository::event::{DateRangeOption, EventStatusOption}};
+    use crate::{domain::event::model::{EventCreateModel, EventUpdateModel, EventDetailModel, EventFindFilter}, api::utils::random_number};
 
     use super::*;
 
@@ -32,26 +33,13 @@ mod tests {
 
         #[async_trait]
         impl EventRepository for FakeEventRepository {
-            async fn find(
-        &self,
-        name: &Option<String>,
-        in_person: &Option<bool>,
-        is_online: &Option<bool>,
-        group_slug: &Option<String>,
-        location: &Option<String>,
-        has_fee: &Option<bool>,
-        rsvp_limit: &Option<u32>,
-        status: &Option<EventStatusOption>,
-        time_frame: &Option<DateRangeOption>,
-        start_date: &Option<DateTime<Utc>>,
-        end_date: &Option<DateTime<Utc>>,
-        page: &u32,
-        page_size: &u32,
-    ) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
             async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
-            async fn insert(&self,event_create_model: &EventCreateModel) -> Result<EventModel, DomainError>;
-            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel) -> Result<EventModel, DomainError>;
-            async fn delete_by_eventid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
         }
     }
 
@@ -1,5 +1,8 @@
+pub mod bulk_upsert_by_extid;
 pub mod create;
 pub mod delete_by_eventid;
 pub mod find;
 pub mod find_by_eventid;
 pub mod update_by_eventid;
+pub mod update_lifecycle_by_eventid;
+pub mod upsert_by_extid;
@@ -1,17 +1,18 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{
     event::{
         model::{EventModel, EventUpdateModel},
         repository::EventRepository,
     },
+    categories::repository::CategoryRepository,
     error::DomainError,
 };
 
 pub async fn execute(
     event_repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     id: i32,
     event_update_model: EventUpdateModel,
 ) -> Result<EventModel, DomainError> {
@@ -20,8 +21,17 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Event id not found")));
     }
 
+    for categoryid in event_update_model.categoryids.iter() {
+        let has_category = category_repository.find_by_id(categoryid).await?;
+        if has_category.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Category id {categoryid} not found"
+            )));
+        }
+    }
+
     let event = event_repository
-        .update_by_eventid(&id, &event_update_model)
+        .update_by_eventid(&id, &event_update_model, &actor)
         .await?;
 
     Ok(event)
@@ -29,39 +39,44 @@ pub async fn execute(
 
 #[cfg(test)]
 mod tests {
-    use crate::{domain::event::model::{EventCreateModel, EventDetailModel}, api::utils::random_number, repository::event::{EventStatusOption, DateRangeOption}};
+    use crate::{domain::event::model::{EventCreateModel, EventDetailModel, EventFindFilter}, api::utils::random_number};
 
     use super::*;
+    use crate::domain::categories::model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel};
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
-    use chrono::{DateTime, Utc};
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::domain::event::model::EventLifecycleUpdateModel;
 
     mock! {
         pub FakeEventRepository { }
 
         #[async_trait]
         impl EventRepository for FakeEventRepository {
-            async fn find(
-        &self,
-        name: &Option<String>,
-        in_person: &Option<bool>,
-        is_online: &Option<bool>,
-        group_slug: &Option<String>,
-        location: &Option<String>,
-        has_fee: &Option<bool>,
-        rsvp_limit: &Option<u32>,
-        status: &Option<EventStatusOption>,
-        time_frame: &Option<DateRangeOption>,
-        start_date: &Option<DateTime<Utc>>,
-        end_date: &Option<DateTime<Utc>>,
-        page: &u32,
-        page_size: &u32,
-    ) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
             async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
-            async fn insert(&self,event_create_model: &EventCreateModel) -> Result<EventModel, DomainError>;
-            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel) -> Result<EventModel, DomainError>;
-            async fn delete_by_eventid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -79,10 +94,12 @@ mod tests {
 
         event_repository
             .expect_update_by_eventid()
-            .return_once(|_, _| Ok(EventModel::mock_default()));
+            .return_once(|_, _, _| Ok(EventModel::mock_default()));
 
         let response = execute(
             Arc::new(event_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
             random_number(),
             mock_request_event_update,
         )
@@ -101,6 +118,8 @@ mod tests {
 
         let result = execute(
             Arc::new(event_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
             random_number(),
             EventUpdateModel::mock_default(),
         )
@@ -111,4 +130,34 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_category_not_found() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut event_update_model = EventUpdateModel::mock_default();
+        event_update_model.categoryids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(event_repository),
+            Arc::new(category_repository),
+            random_string(10),
+            random_number(),
+            event_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
 }
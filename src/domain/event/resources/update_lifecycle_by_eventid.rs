@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    event::{
+        model::{EventLifecycleStatus, EventLifecycleUpdateModel, EventModel},
+        repository::EventRepository,
+    },
+};
+
+/// Cancels, postpones, reschedules or restores event `id`. A rescheduled event
+/// must point to the event replacing it, and only a rescheduled one may.
+pub async fn execute(
+    event_repository: Arc<dyn EventRepository>,
+    actor: String,
+    id: i32,
+    event_lifecycle_update_model: EventLifecycleUpdateModel,
+) -> Result<EventModel, DomainError> {
+    let has_event = event_repository.find_by_eventid(&id).await?;
+    if has_event.is_none() {
+        return Err(DomainError::NotFound(String::from("Event id not found")));
+    }
+
+    match (
+        event_lifecycle_update_model.lifecycle_status,
+        event_lifecycle_update_model.rescheduled_to,
+    ) {
+        (EventLifecycleStatus::Rescheduled, None) => {
+            return Err(DomainError::BadRequest(String::from(
+                "rescheduled_to must be provided for rescheduled events",
+            )));
+        }
+        (EventLifecycleStatus::Rescheduled, Some(rescheduled_to)) => {
+            if rescheduled_to == id {
+                return Err(DomainError::BadRequest(String::from(
+                    "An event cannot be rescheduled to itself",
+                )));
+            }
+            if event_repository
+                .find_by_eventid(&rescheduled_to)
+                .await?
+                .is_none()
+            {
+                return Err(DomainError::BadRequest(format!(
+                    "Event id {rescheduled_to} not found"
+                )));
+            }
+        }
+        (_, Some(_)) => {
+            return Err(DomainError::BadRequest(String::from(
+                "rescheduled_to is only allowed for rescheduled events",
+            )));
+        }
+        (_, None) => {}
+    }
+
+    let event = event_repository
+        .update_lifecycle_by_eventid(&id, &event_lifecycle_update_model, &actor)
+        .await?;
+
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::event::model::{EventCreateModel, EventDetailModel, EventFindFilter, EventUpdateModel},
+    };
+
+    use super::*;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+
+    mock! {
+        pub FakeEventRepository { }
+
+        #[async_trait]
+        impl EventRepository for FakeEventRepository {
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_cancelled() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+        event_repository
+            .expect_update_lifecycle_by_eventid()
+            .return_once(|_, _, _| {
+                let mut event = EventModel::mock_default();
+                event.lifecycle_status = EventLifecycleStatus::Cancelled;
+                Ok(event)
+            });
+
+        let response = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+            EventLifecycleUpdateModel::mock_default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.lifecycle_status, EventLifecycleStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_not_found_event() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(None));
+        event_repository
+            .expect_update_lifecycle_by_eventid()
+            .never();
+
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+            EventLifecycleUpdateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_rescheduled_without_target() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+        event_repository
+            .expect_update_lifecycle_by_eventid()
+            .never();
+
+        let mut event_lifecycle_update_model = EventLifecycleUpdateModel::mock_default();
+        event_lifecycle_update_model.lifecycle_status = EventLifecycleStatus::Rescheduled;
+
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+            event_lifecycle_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_target_given_without_rescheduling() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+        event_repository
+            .expect_update_lifecycle_by_eventid()
+            .never();
+
+        let mut event_lifecycle_update_model = EventLifecycleUpdateModel::mock_default();
+        event_lifecycle_update_model.rescheduled_to = Some(random_number() + 1);
+
+        let result = execute(
+            Arc::new(event_repository),
+            random_string(10),
+            random_number(),
+            event_lifecycle_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    categories::repository::CategoryRepository,
+    error::DomainError,
+    event::{
+        model::{EventModel, EventUpdateModel},
+        repository::EventRepository,
+    },
+    upsert::UpsertModel,
+};
+
+/// Creates the event identified by `extid`, or updates it when one exists.
+/// Nothing is written, nor audited, when the event already matches.
+pub async fn execute(
+    event_repository: Arc<dyn EventRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
+    extid: String,
+    event_update_model: EventUpdateModel,
+) -> Result<UpsertModel<EventModel>, DomainError> {
+    for categoryid in event_update_model.categoryids.iter() {
+        let has_category = category_repository.find_by_id(categoryid).await?;
+        if has_category.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Category id {categoryid} not found"
+            )));
+        }
+    }
+
+    let upserted = event_repository
+        .upsert_by_extid(&extid, &event_update_model, &actor)
+        .await?;
+
+    Ok(upserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::event::model::{
+            EventCreateModel, EventDetailModel, EventFindFilter, EventLifecycleUpdateModel,
+        },
+    };
+
+    use super::*;
+    use crate::domain::upsert::UpsertOutcome;
+    use crate::domain::categories::model::{
+        CategoryCreateModel, CategoryModel, CategoryUpdateModel,
+    };
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub FakeEventRepository { }
+
+        #[async_trait]
+        impl EventRepository for FakeEventRepository {
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_created() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_upsert_by_extid()
+            .return_once(|_, _, _| {
+                Ok(UpsertModel {
+                    outcome: UpsertOutcome::Created,
+                    before: None,
+                    record: EventModel::mock_default(),
+                })
+            });
+
+        let response = execute(
+            Arc::new(event_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_string(10),
+            EventUpdateModel::mock_default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.outcome, UpsertOutcome::Created);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unchanged_event() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_upsert_by_extid()
+            .return_once(|_, _, _| {
+                Ok(UpsertModel {
+                    outcome: UpsertOutcome::Unchanged,
+                    before: Some(EventModel::mock_default()),
+                    record: EventModel::mock_default(),
+                })
+            });
+
+        let response = execute(
+            Arc::new(event_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_string(10),
+            EventUpdateModel::mock_default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.outcome, UpsertOutcome::Unchanged);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_category_not_found() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository.expect_upsert_by_extid().never();
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut event_update_model = EventUpdateModel::mock_default();
+        event_update_model.categoryids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(event_repository),
+            Arc::new(category_repository),
+            random_string(10),
+            random_string(10),
+            event_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
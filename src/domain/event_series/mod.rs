@@ -0,0 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+pub mod model;
+pub mod repository;
+pub mod resources;
+pub mod rrule;
@@ -0,0 +1,177 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[cfg(test)]
+use crate::api::utils::random_number;
+
+#[derive(Debug, Clone)]
+pub struct EventSeriesCreateModel {
+    pub eventid: i32,
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    /// Defaults to the time zone of the event's city, then UTC.
+    pub time_zone: Option<String>,
+}
+impl EventSeriesCreateModel {
+    pub fn new(
+        eventid: i32,
+        rrule: String,
+        exdates: Vec<DateTime<Utc>>,
+        time_zone: Option<String>,
+    ) -> Self {
+        Self {
+            eventid,
+            rrule,
+            exdates,
+            time_zone,
+        }
+    }
+}
+
+#[cfg(test)]
+impl EventSeriesCreateModel {
+    pub fn mock_default() -> Self {
+        Self {
+            eventid: random_number(),
+            rrule: "FREQ=WEEKLY".to_string(),
+            exdates: vec![],
+            time_zone: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventSeriesUpdateModel {
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    pub time_zone: String,
+}
+impl EventSeriesUpdateModel {
+    pub fn new(rrule: String, exdates: Vec<DateTime<Utc>>, time_zone: String) -> Self {
+        Self {
+            rrule,
+            exdates,
+            time_zone,
+        }
+    }
+}
+
+#[cfg(test)]
+impl EventSeriesUpdateModel {
+    pub fn mock_default() -> Self {
+        Self {
+            rrule: "FREQ=MONTHLY;BYDAY=1TU".to_string(),
+            exdates: vec![],
+            time_zone: "America/Toronto".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSeriesModel {
+    pub seriesid: i32,
+    pub eventid: i32,
+    pub rrule: String,
+    pub exdates: Vec<DateTime<Utc>>,
+    pub time_zone: String,
+    /// Start of the series' event, which is also its first occurrence.
+    pub time: DateTime<Utc>,
+    pub occurrences: Vec<EventOccurrenceModel>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+#[cfg(test)]
+impl EventSeriesModel {
+    pub fn mock_default() -> Self {
+        Self {
+            seriesid: random_number(),
+            eventid: random_number(),
+            rrule: "FREQ=WEEKLY".to_string(),
+            exdates: vec![],
+            time_zone: "UTC".to_string(),
+            time: DateTime::default(),
+            occurrences: vec![],
+            created_at: DateTime::default(),
+            updated_at: Some(DateTime::default()),
+        }
+    }
+}
+
+/// Changes to a single occurrence, identified by the start the rule gives it.
+#[derive(Debug, Clone)]
+pub struct EventOccurrenceUpsertModel {
+    pub occurrence: DateTime<Utc>,
+    pub cancelled: bool,
+    pub time: Option<DateTime<Utc>>,
+    pub duration: Option<i32>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+}
+impl EventOccurrenceUpsertModel {
+    pub fn new(
+        occurrence: DateTime<Utc>,
+        cancelled: bool,
+        time: Option<DateTime<Utc>>,
+        duration: Option<i32>,
+        name: Option<String>,
+        description: Option<String>,
+        location: Option<String>,
+    ) -> Self {
+        Self {
+            occurrence,
+            cancelled,
+            time,
+            duration,
+            name,
+            description,
+            location,
+        }
+    }
+}
+
+#[cfg(test)]
+impl EventOccurrenceUpsertModel {
+    pub fn mock_default() -> Self {
+        Self {
+            occurrence: DateTime::default(),
+            cancelled: true,
+            time: None,
+            duration: None,
+            name: None,
+            description: None,
+            location: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventOccurrenceModel {
+    pub seriesid: i32,
+    pub occurrence: DateTime<Utc>,
+    pub cancelled: bool,
+    pub time: Option<DateTime<Utc>>,
+    pub duration: Option<i32>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+#[cfg(test)]
+impl EventOccurrenceModel {
+    pub fn mock_default() -> Self {
+        Self {
+            seriesid: random_number(),
+            occurrence: DateTime::default(),
+            cancelled: true,
+            time: None,
+            duration: None,
+            name: None,
+            description: None,
+            location: None,
+            created_at: DateTime::default(),
+            updated_at: None,
+        }
+    }
+}
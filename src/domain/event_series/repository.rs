@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+use super::model::{
+    EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesCreateModel, EventSeriesModel,
+    EventSeriesUpdateModel,
+};
+
+#[async_trait]
+pub trait EventSeriesRepository: Send + Sync {
+    async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+    async fn find_by_eventid(&self, eventid: &i32)
+        -> Result<Option<EventSeriesModel>, DomainError>;
+    async fn insert(
+        &self,
+        event_series_create_model: &EventSeriesCreateModel,
+        actor: &str,
+    ) -> Result<EventSeriesModel, DomainError>;
+    async fn update_by_seriesid(
+        &self,
+        id: &i32,
+        event_series_update_model: &EventSeriesUpdateModel,
+        actor: &str,
+    ) -> Result<EventSeriesModel, DomainError>;
+    async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+    async fn upsert_occurrence(
+        &self,
+        id: &i32,
+        event_occurrence_upsert_model: &EventOccurrenceUpsertModel,
+        actor: &str,
+    ) -> Result<EventOccurrenceModel, DomainError>;
+}
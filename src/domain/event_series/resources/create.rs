@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    event::repository::EventRepository,
+    event_series::{
+        model::{EventSeriesCreateModel, EventSeriesModel},
+        repository::EventSeriesRepository,
+        rrule::RRule,
+    },
+};
+
+pub async fn execute(
+    event_series_repository: Arc<dyn EventSeriesRepository>,
+    event_repository: Arc<dyn EventRepository>,
+    actor: String,
+    event_series_create_model: EventSeriesCreateModel,
+) -> Result<EventSeriesModel, DomainError> {
+    event_series_create_model
+        .rrule
+        .parse::<RRule>()
+        .map_err(DomainError::BadRequest)?;
+
+    let has_event = event_repository
+        .find_by_eventid(&event_series_create_model.eventid)
+        .await?;
+    if has_event.is_none() {
+        return Err(DomainError::NotFound(String::from("Event id not found")));
+    }
+
+    let has_series = event_series_repository
+        .find_by_eventid(&event_series_create_model.eventid)
+        .await?;
+    if has_series.is_some() {
+        return Err(DomainError::BadRequest(String::from(
+            "Event already has a series",
+        )));
+    }
+
+    let event_series = event_series_repository
+        .insert(&event_series_create_model, &actor)
+        .await?;
+
+    Ok(event_series)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::domain::event::model::EventLifecycleUpdateModel;
+
+    use crate::{
+        api::utils::random_string,
+        domain::{
+            event::model::{EventCreateModel, EventDetailModel, EventFindFilter, EventModel, EventUpdateModel},
+            event_series::model::{
+                EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesUpdateModel,
+            },
+        },
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeEventSeriesRepository { }
+
+        #[async_trait]
+        impl EventSeriesRepository for FakeEventSeriesRepository {
+            async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn find_by_eventid(&self, eventid: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn insert(&self,event_series_create_model: &EventSeriesCreateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn update_by_seriesid(&self,id: &i32,event_series_update_model: &EventSeriesUpdateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+            async fn upsert_occurrence(&self,id: &i32,event_occurrence_upsert_model: &EventOccurrenceUpsertModel,actor: &str) -> Result<EventOccurrenceModel, DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeEventRepository { }
+
+        #[async_trait]
+        impl EventRepository for FakeEventRepository {
+            async fn find(&self,filter: &EventFindFilter,page: &u32,page_size: &u32) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError>;
+            async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError>;
+            async fn insert(&self,event_create_model: &EventCreateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn update_by_eventid(&self,id: &i32,event_update_model: &EventUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,event_update_model: &EventUpdateModel,actor: &str) -> Result<UpsertModel<EventModel>, DomainError>;
+            async fn bulk_upsert_by_extid(&self,event_create_models: &[EventCreateModel],actor: &str) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError>;
+            async fn update_lifecycle_by_eventid(&self,id: &i32,event_lifecycle_update_model: &EventLifecycleUpdateModel,actor: &str) -> Result<EventModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_series_created() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(None));
+        event_series_repository
+            .expect_insert()
+            .return_once(|_, _| Ok(EventSeriesModel::mock_default()));
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            Arc::new(event_repository),
+            random_string(10),
+            EventSeriesCreateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_invalid_rrule() {
+        let event_repository = MockFakeEventRepository::new();
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository.expect_insert().never();
+
+        let mut event_series_create_model = EventSeriesCreateModel::mock_default();
+        event_series_create_model.rrule = "FREQ=SECONDLY".to_string();
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            Arc::new(event_repository),
+            random_string(10),
+            event_series_create_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_event_already_has_series() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventModel::mock_default())));
+
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(Some(EventSeriesModel::mock_default())));
+        event_series_repository.expect_insert().never();
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            Arc::new(event_repository),
+            random_string(10),
+            EventSeriesCreateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_event_not_found() {
+        let mut event_repository = MockFakeEventRepository::new();
+        event_repository
+            .expect_find_by_eventid()
+            .return_once(|_| Ok(None));
+
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository.expect_insert().never();
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            Arc::new(event_repository),
+            random_string(10),
+            EventSeriesCreateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
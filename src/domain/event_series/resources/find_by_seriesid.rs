@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    event_series::{model::EventSeriesModel, repository::EventSeriesRepository},
+};
+
+pub async fn execute(
+    event_series_repository: Arc<dyn EventSeriesRepository>,
+    id: i32,
+) -> Result<Option<EventSeriesModel>, DomainError> {
+    if let Some(event_series) = event_series_repository.find_by_seriesid(&id).await? {
+        return Ok(Some(event_series));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::random_number,
+        domain::event_series::model::{
+            EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesCreateModel,
+            EventSeriesUpdateModel,
+        },
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeEventSeriesRepository { }
+
+        #[async_trait]
+        impl EventSeriesRepository for FakeEventSeriesRepository {
+            async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn find_by_eventid(&self, eventid: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn insert(&self,event_series_create_model: &EventSeriesCreateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn update_by_seriesid(&self,id: &i32,event_series_update_model: &EventSeriesUpdateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+            async fn upsert_occurrence(&self,id: &i32,event_occurrence_upsert_model: &EventOccurrenceUpsertModel,actor: &str) -> Result<EventOccurrenceModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_series_finded() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(Some(EventSeriesModel::mock_default())));
+
+        let result = execute(Arc::new(event_series_repository), random_number()).await;
+
+        match result {
+            Ok(result) => assert!(result.is_some()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_no_content_event_series() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(None));
+
+        let result = execute(Arc::new(event_series_repository), random_number()).await;
+
+        match result {
+            Ok(result) => assert!(result.is_none()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+}
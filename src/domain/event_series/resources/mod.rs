@@ -0,0 +1,5 @@
+pub mod create;
+pub mod delete_by_seriesid;
+pub mod find_by_seriesid;
+pub mod update_by_seriesid;
+pub mod upsert_occurrence;
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    event_series::{
+        model::{EventSeriesModel, EventSeriesUpdateModel},
+        repository::EventSeriesRepository,
+        rrule::RRule,
+    },
+};
+
+pub async fn execute(
+    event_series_repository: Arc<dyn EventSeriesRepository>,
+    actor: String,
+    id: i32,
+    event_series_update_model: EventSeriesUpdateModel,
+) -> Result<EventSeriesModel, DomainError> {
+    event_series_update_model
+        .rrule
+        .parse::<RRule>()
+        .map_err(DomainError::BadRequest)?;
+
+    let has_event_series = event_series_repository.find_by_seriesid(&id).await?;
+    if has_event_series.is_none() {
+        return Err(DomainError::NotFound(String::from("Series id not found")));
+    }
+
+    let event_series = event_series_repository
+        .update_by_seriesid(&id, &event_series_update_model, &actor)
+        .await?;
+
+    Ok(event_series)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::event_series::model::{
+            EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesCreateModel,
+            EventSeriesUpdateModel,
+        },
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeEventSeriesRepository { }
+
+        #[async_trait]
+        impl EventSeriesRepository for FakeEventSeriesRepository {
+            async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn find_by_eventid(&self, eventid: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn insert(&self,event_series_create_model: &EventSeriesCreateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn update_by_seriesid(&self,id: &i32,event_series_update_model: &EventSeriesUpdateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+            async fn upsert_occurrence(&self,id: &i32,event_occurrence_upsert_model: &EventOccurrenceUpsertModel,actor: &str) -> Result<EventOccurrenceModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_series_updated() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(Some(EventSeriesModel::mock_default())));
+        event_series_repository
+            .expect_update_by_seriesid()
+            .return_once(|_, _, _| Ok(EventSeriesModel::mock_default()));
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            random_string(10),
+            random_number(),
+            EventSeriesUpdateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_not_found_event_series() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(None));
+        event_series_repository.expect_update_by_seriesid().never();
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            random_string(10),
+            random_number(),
+            EventSeriesUpdateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+
+use crate::domain::{
+    error::DomainError,
+    event_series::{
+        model::{EventOccurrenceModel, EventOccurrenceUpsertModel},
+        repository::EventSeriesRepository,
+        rrule::RRule,
+    },
+};
+
+/// Overrides or cancels the occurrence of series `id` starting at
+/// `event_occurrence_upsert_model.occurrence`, which must be produced by the
+/// series' rule.
+pub async fn execute(
+    event_series_repository: Arc<dyn EventSeriesRepository>,
+    actor: String,
+    id: i32,
+    event_occurrence_upsert_model: EventOccurrenceUpsertModel,
+) -> Result<EventOccurrenceModel, DomainError> {
+    let event_series = match event_series_repository.find_by_seriesid(&id).await? {
+        Some(event_series) => event_series,
+        None => return Err(DomainError::NotFound(String::from("Series id not found"))),
+    };
+
+    let rrule: RRule = event_series
+        .rrule
+        .parse()
+        .map_err(DomainError::InternalServerError)?;
+    let tz: Tz = event_series.time_zone.parse().unwrap_or(Tz::UTC);
+    if !rrule.contains(
+        event_series.time,
+        tz,
+        event_occurrence_upsert_model.occurrence,
+    ) {
+        return Err(DomainError::BadRequest(format!(
+            "{} is not an occurrence of series {id}",
+            event_occurrence_upsert_model.occurrence.to_rfc3339()
+        )));
+    }
+
+    let event_occurrence = event_series_repository
+        .upsert_occurrence(&id, &event_occurrence_upsert_model, &actor)
+        .await?;
+
+    Ok(event_occurrence)
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::event_series::model::{
+            EventSeriesCreateModel, EventSeriesModel, EventSeriesUpdateModel,
+        },
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeEventSeriesRepository { }
+
+        #[async_trait]
+        impl EventSeriesRepository for FakeEventSeriesRepository {
+            async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn find_by_eventid(&self, eventid: &i32) -> Result<Option<EventSeriesModel>, DomainError>;
+            async fn insert(&self,event_series_create_model: &EventSeriesCreateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn update_by_seriesid(&self,id: &i32,event_series_update_model: &EventSeriesUpdateModel,actor: &str) -> Result<EventSeriesModel, DomainError>;
+            async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+            async fn upsert_occurrence(&self,id: &i32,event_occurrence_upsert_model: &EventOccurrenceUpsertModel,actor: &str) -> Result<EventOccurrenceModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_event_occurrence_upserted() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(Some(EventSeriesModel::mock_default())));
+        event_series_repository
+            .expect_upsert_occurrence()
+            .return_once(|_, _, _| Ok(EventOccurrenceModel::mock_default()));
+
+        let mut event_occurrence_upsert_model = EventOccurrenceUpsertModel::mock_default();
+        event_occurrence_upsert_model.occurrence += Duration::weeks(3);
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            random_string(10),
+            random_number(),
+            event_occurrence_upsert_model,
+        )
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_not_an_occurrence() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(Some(EventSeriesModel::mock_default())));
+        event_series_repository.expect_upsert_occurrence().never();
+
+        let mut event_occurrence_upsert_model = EventOccurrenceUpsertModel::mock_default();
+        event_occurrence_upsert_model.occurrence += Duration::days(3);
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            random_string(10),
+            random_number(),
+            event_occurrence_upsert_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_not_found_event_series() {
+        let mut event_series_repository = MockFakeEventSeriesRepository::new();
+        event_series_repository
+            .expect_find_by_seriesid()
+            .return_once(|_| Ok(None));
+        event_series_repository.expect_upsert_occurrence().never();
+
+        let result = execute(
+            Arc::new(event_series_repository),
+            random_string(10),
+            random_number(),
+            EventOccurrenceUpsertModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,514 @@
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+/// Upper bound on the periods walked while expanding, so a rule whose
+/// filters never match cannot loop forever.
+const MAX_PERIODS: i64 = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` entry such as `TU`, `2MO` or `-1FR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+/// `UNTIL` as a UTC instant, or as a floating local date-time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    Instant(DateTime<Utc>),
+    Local(NaiveDateTime),
+}
+
+/// The subset of RFC 5545 recurrence rules supported by event series:
+/// `FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `BYDAY`, `BYMONTHDAY`, `BYMONTH`
+/// and `WKST=MO`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<Until>,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+}
+
+impl FromStr for RRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = vec![];
+        let mut by_month_day = vec![];
+        let mut by_month = vec![];
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("invalid rule part {part}"))?;
+            let value = value.to_ascii_uppercase();
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(format!("unsupported FREQ {value}")),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| format!("invalid INTERVAL {value}"))?
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|count| *count > 0)
+                            .ok_or_else(|| format!("invalid COUNT {value}"))?,
+                    )
+                }
+                "UNTIL" => until = Some(parse_until(&value)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    by_month_day = parse_list(&value, "BYMONTHDAY", |day: &i32| {
+                        *day != 0 && day.abs() <= 31
+                    })?
+                }
+                "BYMONTH" => {
+                    by_month =
+                        parse_list(&value, "BYMONTH", |month: &u32| (1..=12).contains(month))?
+                }
+                "WKST" if value == "MO" => {}
+                _ => return Err(format!("unsupported rule part {part}")),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| String::from("FREQ is required"))?;
+        if count.is_some() && until.is_some() {
+            return Err(String::from("COUNT and UNTIL cannot be combined"));
+        }
+        if by_day.iter().any(|day| day.ordinal.is_some())
+            && !matches!(frequency, Frequency::Monthly | Frequency::Yearly)
+        {
+            return Err(String::from(
+                "BYDAY ordinals are only supported with FREQ=MONTHLY or FREQ=YEARLY",
+            ));
+        }
+        if frequency == Frequency::Yearly && !by_day.is_empty() && by_month.is_empty() {
+            return Err(String::from("BYDAY with FREQ=YEARLY requires BYMONTH"));
+        }
+        if frequency == Frequency::Weekly && !by_month_day.is_empty() {
+            return Err(String::from("BYMONTHDAY cannot be used with FREQ=WEEKLY"));
+        }
+
+        Ok(Self {
+            frequency,
+            interval,
+            count,
+            until,
+            by_day,
+            by_month_day,
+            by_month,
+        })
+    }
+}
+
+impl RRule {
+    /// Starts of the occurrences of a series beginning at `dtstart` that fall
+    /// within `[from, to]`, at most `limit` of them. Occurrences keep the
+    /// wall-clock time of `dtstart` in `tz` across daylight saving changes.
+    pub fn occurrences(
+        &self,
+        dtstart: DateTime<Utc>,
+        tz: Tz,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        limit: usize,
+    ) -> Vec<DateTime<Utc>> {
+        let start = dtstart.with_timezone(&tz).naive_local();
+        let until = self.until.map(|until| match until {
+            Until::Instant(instant) => instant,
+            Until::Local(local) => resolve(tz, local),
+        });
+
+        let mut occurrences = vec![];
+        let mut count = 0;
+        for period in 0..MAX_PERIODS {
+            for date in self.period_dates(start.date(), period) {
+                let local = date.and_time(start.time());
+                if local < start {
+                    continue;
+                }
+
+                let instant = resolve(tz, local);
+                if instant > to || until.is_some_and(|until| instant > until) {
+                    return occurrences;
+                }
+
+                count += 1;
+                if instant >= from {
+                    occurrences.push(instant);
+                    if occurrences.len() >= limit {
+                        return occurrences;
+                    }
+                }
+                if self.count.is_some_and(|max| count >= max) {
+                    return occurrences;
+                }
+            }
+        }
+
+        occurrences
+    }
+
+    /// Whether the series beginning at `dtstart` has an occurrence starting at `instant`.
+    pub fn contains(&self, dtstart: DateTime<Utc>, tz: Tz, instant: DateTime<Utc>) -> bool {
+        self.occurrences(dtstart, tz, instant, instant, 1).first() == Some(&instant)
+    }
+
+    /// Candidate dates of the `period`-th period after the one holding `start`, in order.
+    fn period_dates(&self, start: NaiveDate, period: i64) -> Vec<NaiveDate> {
+        let step = period * i64::from(self.interval);
+
+        let mut dates = match self.frequency {
+            Frequency::Daily => {
+                let date = start + Duration::days(step);
+                let matches = (self.by_month.is_empty() || self.by_month.contains(&date.month()))
+                    && (self.by_month_day.is_empty()
+                        || self
+                            .by_month_day
+                            .iter()
+                            .any(|day| month_day(date.year(), date.month(), *day) == Some(date)))
+                    && (self.by_day.is_empty()
+                        || self.by_day.iter().any(|day| day.weekday == date.weekday()));
+                if matches {
+                    vec![date]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                let week_start = start
+                    - Duration::days(start.weekday().num_days_from_monday().into())
+                    + Duration::weeks(step);
+                let weekdays = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|day| day.weekday).collect()
+                };
+                weekdays
+                    .iter()
+                    .map(|weekday| {
+                        week_start + Duration::days(weekday.num_days_from_monday().into())
+                    })
+                    .filter(|date| {
+                        self.by_month.is_empty() || self.by_month.contains(&date.month())
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let months = i64::from(start.month0()) + step;
+                let year = start.year() + (months / 12) as i32;
+                let month = (months % 12) as u32 + 1;
+                if self.by_month.is_empty() || self.by_month.contains(&month) {
+                    self.month_dates(year, month, start.day())
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Yearly => {
+                let year = start.year() + step as i32;
+                let months = if self.by_month.is_empty() {
+                    vec![start.month()]
+                } else {
+                    self.by_month.clone()
+                };
+                months
+                    .iter()
+                    .flat_map(|month| self.month_dates(year, *month, start.day()))
+                    .collect()
+            }
+        };
+
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    /// Dates of `month` selected by `BYMONTHDAY` and `BYDAY`, else `default_day`.
+    fn month_dates(&self, year: i32, month: u32, default_day: u32) -> Vec<NaiveDate> {
+        if !self.by_month_day.is_empty() {
+            return self
+                .by_month_day
+                .iter()
+                .filter_map(|day| month_day(year, month, *day))
+                .filter(|date| {
+                    self.by_day.is_empty()
+                        || self.by_day.iter().any(|day| day.weekday == date.weekday())
+                })
+                .collect();
+        }
+
+        if !self.by_day.is_empty() {
+            return self
+                .by_day
+                .iter()
+                .flat_map(|day| {
+                    let weekdays: Vec<NaiveDate> = (1..=days_in_month(year, month))
+                        .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+                        .filter(|date| date.weekday() == day.weekday)
+                        .collect();
+                    match day.ordinal {
+                        Some(ordinal) if ordinal > 0 => weekdays
+                            .get(ordinal as usize - 1)
+                            .copied()
+                            .into_iter()
+                            .collect(),
+                        Some(ordinal) => weekdays
+                            .len()
+                            .checked_sub(ordinal.unsigned_abs() as usize)
+                            .and_then(|index| weekdays.get(index).copied())
+                            .into_iter()
+                            .collect(),
+                        None => weekdays,
+                    }
+                })
+                .collect();
+        }
+
+        NaiveDate::from_ymd_opt(year, month, default_day)
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Maps a local date-time to UTC, taking the earlier instant when it is
+/// ambiguous and moving past the gap when it does not exist.
+fn resolve(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    let mut candidate = local;
+    for _ in 0..24 {
+        match tz.from_local_datetime(&candidate) {
+            LocalResult::Single(time) => return time.with_timezone(&Utc),
+            LocalResult::Ambiguous(earliest, _) => return earliest.with_timezone(&Utc),
+            LocalResult::None => candidate += Duration::hours(1),
+        }
+    }
+    Utc.from_utc_datetime(&local)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    };
+    next.and_then(|date| date.pred_opt())
+        .map_or(28, |date| date.day())
+}
+
+/// The `day`-th day of the month, counting from the end when negative.
+fn month_day(year: i32, month: u32, day: i32) -> Option<NaiveDate> {
+    let day = if day > 0 {
+        day
+    } else {
+        days_in_month(year, month) as i32 + 1 + day
+    };
+    u32::try_from(day)
+        .ok()
+        .and_then(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
+fn parse_until(value: &str) -> Result<Until, String> {
+    let invalid = || format!("invalid UNTIL {value}");
+
+    if let Some(value) = value.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .map(|until| Until::Instant(Utc.from_utc_datetime(&until)))
+            .map_err(|_| invalid());
+    }
+    if value.contains('T') {
+        return NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .map(Until::Local)
+            .map_err(|_| invalid());
+    }
+
+    // A date includes the whole day.
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(Until::Local)
+        .ok_or_else(invalid)
+}
+
+fn parse_by_day(value: &str) -> Result<ByDay, String> {
+    let invalid = || format!("invalid BYDAY {value}");
+    if value.len() < 2 {
+        return Err(invalid());
+    }
+
+    let (ordinal, weekday) = value.split_at(value.len() - 2);
+    let weekday = match weekday {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(invalid()),
+    };
+    let ordinal = if ordinal.is_empty() {
+        None
+    } else {
+        Some(
+            ordinal
+                .parse::<i32>()
+                .ok()
+                .filter(|ordinal| *ordinal != 0 && ordinal.abs() <= 5)
+                .ok_or_else(invalid)?,
+        )
+    };
+
+    Ok(ByDay { ordinal, weekday })
+}
+
+fn parse_list<T: FromStr>(
+    value: &str,
+    name: &str,
+    valid: impl Fn(&T) -> bool,
+) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|item| {
+            item.parse()
+                .ok()
+                .filter(|item| valid(item))
+                .ok_or_else(|| format!("invalid {name} {item}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn expand(rule: &str, dtstart: &str, tz: Tz, from: &str, to: &str) -> Vec<DateTime<Utc>> {
+        rule.parse::<RRule>()
+            .unwrap()
+            .occurrences(utc(dtstart), tz, utc(from), utc(to), 100)
+    }
+
+    #[test]
+    fn it_should_reject_unsupported_rules() {
+        assert!("BYDAY=MO".parse::<RRule>().is_err());
+        assert!("FREQ=HOURLY".parse::<RRule>().is_err());
+        assert!("FREQ=WEEKLY;BYSETPOS=1".parse::<RRule>().is_err());
+        assert!("FREQ=WEEKLY;BYDAY=1MO".parse::<RRule>().is_err());
+        assert!("FREQ=DAILY;COUNT=2;UNTIL=20260101"
+            .parse::<RRule>()
+            .is_err());
+        assert!("FREQ=DAILY;INTERVAL=0".parse::<RRule>().is_err());
+    }
+
+    #[test]
+    fn it_should_expand_weekly_rule_with_count() {
+        let occurrences = expand(
+            "RRULE:FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4",
+            "2026-03-03T18:00:00Z",
+            Tz::UTC,
+            "2026-01-01T00:00:00Z",
+            "2027-01-01T00:00:00Z",
+        );
+
+        assert_eq!(
+            occurrences,
+            vec![
+                utc("2026-03-03T18:00:00Z"),
+                utc("2026-03-05T18:00:00Z"),
+                utc("2026-03-10T18:00:00Z"),
+                utc("2026-03-12T18:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_expand_last_weekday_of_month_until_date() {
+        let occurrences = expand(
+            "FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20260430",
+            "2026-01-30T17:00:00Z",
+            Tz::UTC,
+            "2026-02-01T00:00:00Z",
+            "2027-01-01T00:00:00Z",
+        );
+
+        assert_eq!(
+            occurrences,
+            vec![
+                utc("2026-02-27T17:00:00Z"),
+                utc("2026-03-27T17:00:00Z"),
+                utc("2026-04-24T17:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_local_time_across_daylight_saving() {
+        let occurrences = expand(
+            "FREQ=WEEKLY;INTERVAL=2",
+            "2026-02-24T23:00:00Z",
+            chrono_tz::America::Toronto,
+            "2026-03-01T00:00:00Z",
+            "2026-03-25T00:00:00Z",
+        );
+
+        // 18:00 in Toronto is 23:00 UTC before 8 March and 22:00 UTC after.
+        assert_eq!(
+            occurrences,
+            vec![utc("2026-03-10T22:00:00Z"), utc("2026-03-24T22:00:00Z")]
+        );
+    }
+
+    #[test]
+    fn it_should_check_whether_instant_is_an_occurrence() {
+        let rule: RRule = "FREQ=DAILY;INTERVAL=3".parse().unwrap();
+        let dtstart = utc("2026-05-01T09:30:00Z");
+
+        assert!(rule.contains(dtstart, Tz::UTC, utc("2026-05-07T09:30:00Z")));
+        assert!(!rule.contains(dtstart, Tz::UTC, utc("2026-05-08T09:30:00Z")));
+        assert!(!rule.contains(dtstart, Tz::UTC, utc("2026-05-07T10:30:00Z")));
+    }
+}
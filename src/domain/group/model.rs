@@ -2,10 +2,13 @@
 use crate::api::utils::random_number;
 #[cfg(test)]
 use crate::api::utils::random_string;
+use crate::domain::categories::model::CategoryModel;
 use crate::domain::city::model::CityModel;
+use crate::domain::organizer::model::OrganizerModel;
 use crate::domain::state::model::StateModel;
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 #[derive(Debug, Clone)]
 pub struct ImageLinks {
     pub highres_link: Option<String>,
@@ -22,7 +25,8 @@ pub struct GroupCreateModel {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    pub organizer: String,
+    pub organizerids: Vec<i32>,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -37,7 +41,8 @@ impl GroupCreateModel {
         private: bool,
         members: i32,
         cityid: i32,
-        organizer: String,
+        organizerids: Vec<i32>,
+        categoryids: Vec<i32>,
         image: ImageLinks,
     ) -> Self {
         Self {
@@ -49,7 +54,8 @@ impl GroupCreateModel {
             private,
             members,
             cityid,
-            organizer,
+            organizerids,
+            categoryids,
             highres_link: image.highres_link,
             photo_link: image.photo_link,
             thumb_link: image.thumb_link,
@@ -65,7 +71,8 @@ impl GroupCreateModel {
             description: "The Big Group".to_string(),
             extid: random_string(10),
             slug: random_string(10),
-            organizer: "organizer".to_string(),
+            organizerids: vec![],
+            categoryids: vec![],
             active: false,
             private: true,
             members: 100,
@@ -86,7 +93,8 @@ pub struct GroupUpdateModel {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    pub organizer: String,
+    pub organizerids: Vec<i32>,
+    pub categoryids: Vec<i32>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -103,7 +111,8 @@ impl GroupUpdateModel {
         private: bool,
         members: i32,
         cityid: i32,
-        organizer: String,
+        organizerids: Vec<i32>,
+        categoryids: Vec<i32>,
         image: ImageLinks,
     ) -> Self {
         Self {
@@ -114,7 +123,8 @@ impl GroupUpdateModel {
             private,
             members,
             cityid,
-            organizer,
+            organizerids,
+            categoryids,
             highres_link: image.highres_link,
             photo_link: image.photo_link,
             thumb_link: image.thumb_link,
@@ -128,7 +138,8 @@ impl GroupUpdateModel {
             name: random_string(10),
             description: "The Big Group".to_string(),
             slug: random_string(10),
-            organizer: "organizer".to_string(),
+            organizerids: vec![],
+            categoryids: vec![],
             active: true,
             private: true,
             members: 100,
@@ -187,7 +198,7 @@ impl GroupPageModel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GroupModel {
     pub groupid: i32,
     pub name: String,
@@ -198,7 +209,8 @@ pub struct GroupModel {
     pub private: bool,
     pub members: i32,
     pub cityid: i32,
-    pub organizer: String,
+    pub organizers: Vec<OrganizerModel>,
+    pub categories: Vec<CategoryModel>,
     pub highres_link: Option<String>,
     pub photo_link: Option<String>,
     pub thumb_link: Option<String>,
@@ -215,7 +227,8 @@ impl GroupModel {
             description: "The Big Group".to_string(),
             extid: random_string(10),
             slug: random_string(10),
-            organizer: "organizer"  .to_string(),
+            organizers: vec![OrganizerModel::mock_default()],
+            categories: vec![CategoryModel::mock_default()],
             active: true,
             private: true,
             members: 100,
@@ -1,6 +1,9 @@
 use async_trait::async_trait;
 
-use crate::domain::error::DomainError;
+use crate::{
+    domain::{error::DomainError, upsert::UpsertModel},
+    repository::group::GroupSortOption,
+};
 
 use super::model::{GroupCreateModel, GroupModel, GroupUpdateModel, GroupPageModel};
 
@@ -9,7 +12,16 @@ pub trait GroupRepository: Send + Sync {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
         city: &Option<String>,
+        category: &Option<i32>,
+        sort: &Option<GroupSortOption>,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+    async fn find_by_organizerid(
+        &self,
+        organizerid: &i32,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
@@ -18,11 +30,19 @@ pub trait GroupRepository: Send + Sync {
     async fn insert(
         &self,
         group_create_model: &GroupCreateModel,
+        actor: &str,
     ) -> Result<GroupModel, DomainError>;
     async fn update_by_groupid(
         &self,
         id: &i32,
         group_update_model: &GroupUpdateModel,
+        actor: &str,
     ) -> Result<GroupModel, DomainError>;
-    async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError>;
+    async fn upsert_by_extid(
+        &self,
+        extid: &str,
+        group_update_model: &GroupUpdateModel,
+        actor: &str,
+    ) -> Result<UpsertModel<GroupModel>, DomainError>;
+    async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
 }
@@ -4,6 +4,7 @@ use crate::domain::{group::repository::GroupRepository, error::DomainError};
 
 pub async fn execute(
     group_repository: Arc<dyn GroupRepository>,
+    actor: String,
     group_id: i32,
 ) -> Result<(), DomainError> {
     let has_group = group_repository.find_by_groupid(&group_id).await?;
@@ -11,7 +12,7 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Group id not found")));
     }
 
-    group_repository.delete_by_groupid(&group_id).await?;
+    group_repository.delete_by_groupid(&group_id, &actor).await?;
 
     Ok(())
 }
@@ -20,24 +21,29 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::repository::group::GroupSortOption;
 
     use crate::{domain::group::model::{
         GroupCreateModel, GroupModel, GroupUpdateModel, GroupPageModel,
     }, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeGroupRepository { }
 
         #[async_trait]
         impl GroupRepository for FakeGroupRepository {
-            async fn find(&self,name: &Option<String>,city: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
             async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
-            async fn insert(&self,group_create_model: &GroupCreateModel) -> Result<GroupModel, DomainError>;
-            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel) -> Result<GroupModel, DomainError>;
-            async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -51,9 +57,14 @@ mod tests {
 
         group_repository
             .expect_delete_by_groupid()
-            .return_once(|_| Ok(()));
+            .return_once(|_, _| Ok(()));
 
-        let result = execute(Arc::new(group_repository), random_number()).await;
+        let result = execute(
+            Arc::new(group_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -69,7 +80,12 @@ mod tests {
             .expect_find_by_groupid()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(group_repository), random_number()).await;
+        let result = execute(
+            Arc::new(group_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
@@ -4,15 +4,19 @@ use crate::domain::{
     group::{model::{GroupPageModel},repository::GroupRepository},
     error::DomainError,
 };
+use crate::repository::group::GroupSortOption;
 
 pub async fn execute(
     group_repository: Arc<dyn GroupRepository>,
     name: Option<String>,
+    fuzzy: bool,
     city: Option<String>,
+    category: Option<i32>,
+    sort: Option<GroupSortOption>,
     page: u32,
     page_size: u32,
 ) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError> {
-    let group = group_repository.find(&name, &city,&page, &page_size).await?;
+    let group = group_repository.find(&name, &fuzzy, &city, &category, &sort, &page, &page_size).await?;
 
     if group.is_some() {
         return Ok(group);
@@ -27,6 +31,7 @@ mod tests {
 
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
     
 
     use crate::domain::group::model::{GroupCreateModel, GroupUpdateModel, GroupModel};
@@ -36,12 +41,14 @@ mod tests {
 
         #[async_trait]
         impl GroupRepository for FakeGroupRepository {
-            async fn find(&self,name: &Option<String>,city: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
             async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
-            async fn insert(&self,group_create_model: &GroupCreateModel) -> Result<GroupModel, DomainError>;
-            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel) -> Result<GroupModel, DomainError>;
-            async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -51,9 +58,9 @@ mod tests {
 
         group_repository
             .expect_find()
-            .return_once(|_, _, _,_| Ok(Some((vec![GroupPageModel::mock_default()], 1))));
+            .return_once(|_, _, _, _, _, _, _| Ok(Some((vec![GroupPageModel::mock_default()], 1))));
 
-        let (group, count) = execute(Arc::new(group_repository), None,None, 1, 12)
+        let (group, count) = execute(Arc::new(group_repository), None, false, None, None, None, 1, 12)
             .await
             .unwrap()
             .unwrap();
@@ -67,9 +74,9 @@ mod tests {
         let mut group_repository = MockFakeGroupRepository::new();
         group_repository
             .expect_find()
-            .return_once(|_, _, _,_| Ok(None));
+            .return_once(|_, _, _, _, _, _, _| Ok(None));
 
-        let response = execute(Arc::new(group_repository), None,None, 1, 12)
+        let response = execute(Arc::new(group_repository), None, false, None, None, None, 1, 12)
             .await
             .unwrap();
 
@@ -19,6 +19,8 @@ pub async fn execute(
 mod tests {
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::repository::group::GroupSortOption;
 
     use crate::{domain::group::model::{GroupCreateModel, GroupUpdateModel, GroupPageModel}, api::utils::random_number};
 
@@ -29,12 +31,14 @@ mod tests {
 
         #[async_trait]
         impl GroupRepository for FakeGroupRepository {
-            async fn find(&self,name: &Option<String>,city: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
             async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
-            async fn insert(&self,group_create_model: &GroupCreateModel) -> Result<GroupModel, DomainError>;
-            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel) -> Result<GroupModel, DomainError>;
-            async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    group::{model::GroupPageModel, repository::GroupRepository},
+    error::DomainError,
+};
+
+pub async fn execute(
+    group_repository: Arc<dyn GroupRepository>,
+    organizerid: i32,
+    page: u32,
+    page_size: u32,
+) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError> {
+    let groups = group_repository
+        .find_by_organizerid(&organizerid, &page, &page_size)
+        .await?;
+
+    if groups.is_some() {
+        return Ok(groups);
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::repository::group::GroupSortOption;
+
+    use crate::{
+        api::utils::random_number,
+        domain::group::model::{GroupCreateModel, GroupModel, GroupUpdateModel},
+    };
+
+    mock! {
+        pub FakeGroupRepository { }
+
+        #[async_trait]
+        impl GroupRepository for FakeGroupRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_groups_finded() {
+        let mut group_repository = MockFakeGroupRepository::new();
+
+        group_repository
+            .expect_find_by_organizerid()
+            .return_once(|_, _, _| Ok(Some((vec![GroupPageModel::mock_default()], 1))));
+
+        let (groups, count) = execute(Arc::new(group_repository), random_number(), 1, 12)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!groups.is_empty());
+        assert!(count == 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_none_finded() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        group_repository
+            .expect_find_by_organizerid()
+            .return_once(|_, _, _| Ok(None));
+
+        let response = execute(Arc::new(group_repository), random_number(), 1, 12)
+            .await
+            .unwrap();
+
+        assert!(response.is_none());
+    }
+}
@@ -2,5 +2,7 @@ pub mod create;
 pub mod delete_by_groupid;
 pub mod find;
 pub mod find_by_groupid;
+pub mod find_by_organizerid;
 pub mod find_by_slug;
 pub mod update_by_groupid;
+pub mod upsert_by_extid;
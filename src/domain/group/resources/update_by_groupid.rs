@@ -1,17 +1,20 @@
 use std::sync::Arc;
 
-
-
 use crate::domain::{
     group::{
         model::{GroupModel, GroupUpdateModel},
         repository::GroupRepository,
     },
+    categories::repository::CategoryRepository,
     error::DomainError,
+    organizer::repository::OrganizerRepository,
 };
 
 pub async fn execute(
     group_repository: Arc<dyn GroupRepository>,
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
     id: i32,
     group_update_model: GroupUpdateModel,
 ) -> Result<GroupModel, DomainError> {
@@ -20,8 +23,26 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("Group id not found")));
     }
 
+    for organizerid in group_update_model.organizerids.iter() {
+        let has_organizer = organizer_repository.find_by_organizerid(organizerid).await?;
+        if has_organizer.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Organizer id {organizerid} not found"
+            )));
+        }
+    }
+
+    for categoryid in group_update_model.categoryids.iter() {
+        let has_category = category_repository.find_by_id(categoryid).await?;
+        if has_category.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Category id {categoryid} not found"
+            )));
+        }
+    }
+
     let group = group_repository
-        .update_by_groupid(&id, &group_update_model)
+        .update_by_groupid(&id, &group_update_model, &actor)
         .await?;
 
     Ok(group)
@@ -29,24 +50,65 @@ pub async fn execute(
 
 #[cfg(test)]
 mod tests {
-    use crate::{domain::group::model::{GroupCreateModel, GroupPageModel}, api::utils::random_number};
+    use crate::{
+        api::utils::random_number,
+        domain::{
+            group::model::{GroupCreateModel, GroupPageModel},
+            organizer::model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel},
+        },
+    };
 
     use super::*;
+    use crate::domain::categories::model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel};
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
+    use crate::domain::upsert::UpsertModel;
+    use crate::repository::group::GroupSortOption;
 
     mock! {
         pub FakeGroupRepository { }
 
         #[async_trait]
         impl GroupRepository for FakeGroupRepository {
-            async fn find(&self,name: &Option<String>,city: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
             async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
             async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
-            async fn insert(&self,group_create_model: &GroupCreateModel) -> Result<GroupModel, DomainError>;
-            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel) -> Result<GroupModel, DomainError>;
-            async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -64,10 +126,13 @@ mod tests {
 
         group_repository
             .expect_update_by_groupid()
-            .return_once(|_, _| Ok(GroupModel::mock_default()));
+            .return_once(|_, _, _| Ok(GroupModel::mock_default()));
 
         let response = execute(
             Arc::new(group_repository),
+            Arc::new(MockFakeOrganizerRepository::new()),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
             random_number().to_owned(),
             mock_request_group_update,
         )
@@ -86,6 +151,9 @@ mod tests {
 
         let result = execute(
             Arc::new(group_repository),
+            Arc::new(MockFakeOrganizerRepository::new()),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
             random_number(),
             GroupUpdateModel::mock_default(),
         )
@@ -96,4 +164,67 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_organizer_not_found() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        group_repository
+            .expect_find_by_groupid()
+            .return_once(|_| Ok(Some(GroupModel::mock_default())));
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(None));
+
+        let mut group_update_model = GroupUpdateModel::mock_default();
+        group_update_model.organizerids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(group_repository),
+            Arc::new(organizer_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_number(),
+            group_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_category_not_found() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        group_repository
+            .expect_find_by_groupid()
+            .return_once(|_| Ok(Some(GroupModel::mock_default())));
+
+        let mut category_repository = MockFakeCategoryRepository::new();
+        category_repository
+            .expect_find_by_id()
+            .return_once(|_| Ok(None));
+
+        let mut group_update_model = GroupUpdateModel::mock_default();
+        group_update_model.categoryids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(group_repository),
+            Arc::new(MockFakeOrganizerRepository::new()),
+            Arc::new(category_repository),
+            random_string(10),
+            random_number(),
+            group_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
 }
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    categories::repository::CategoryRepository,
+    error::DomainError,
+    group::{
+        model::{GroupModel, GroupUpdateModel},
+        repository::GroupRepository,
+    },
+    organizer::repository::OrganizerRepository,
+    upsert::UpsertModel,
+};
+
+/// Creates the group identified by `extid`, or updates it when one exists.
+/// Nothing is written, nor audited, when the group already matches.
+pub async fn execute(
+    group_repository: Arc<dyn GroupRepository>,
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    actor: String,
+    extid: String,
+    group_update_model: GroupUpdateModel,
+) -> Result<UpsertModel<GroupModel>, DomainError> {
+    for organizerid in group_update_model.organizerids.iter() {
+        let has_organizer = organizer_repository
+            .find_by_organizerid(organizerid)
+            .await?;
+        if has_organizer.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Organizer id {organizerid} not found"
+            )));
+        }
+    }
+
+    for categoryid in group_update_model.categoryids.iter() {
+        let has_category = category_repository.find_by_id(categoryid).await?;
+        if has_category.is_none() {
+            return Err(DomainError::BadRequest(format!(
+                "Category id {categoryid} not found"
+            )));
+        }
+    }
+
+    let upserted = group_repository
+        .upsert_by_extid(&extid, &group_update_model, &actor)
+        .await?;
+
+    Ok(upserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::utils::{random_number, random_string},
+        domain::{
+            group::model::{GroupCreateModel, GroupPageModel},
+            organizer::model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel},
+        },
+    };
+
+    use super::*;
+    use crate::domain::upsert::UpsertOutcome;
+    use crate::domain::categories::model::{
+        CategoryCreateModel, CategoryModel, CategoryUpdateModel,
+    };
+
+    use crate::repository::group::GroupSortOption;
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub FakeGroupRepository { }
+
+        #[async_trait]
+        impl GroupRepository for FakeGroupRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,city: &Option<String>,category: &Option<i32>,sort: &Option<GroupSortOption>,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self,organizerid: &i32,page: &u32,page_size: &u32) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError>;
+            async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError>;
+            async fn insert(&self,group_create_model: &GroupCreateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn update_by_groupid(&self,id: &i32,group_update_model: &GroupUpdateModel,actor: &str) -> Result<GroupModel, DomainError>;
+            async fn upsert_by_extid(&self,extid: &str,group_update_model: &GroupUpdateModel,actor: &str) -> Result<UpsertModel<GroupModel>, DomainError>;
+            async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub FakeCategoryRepository { }
+
+        #[async_trait]
+        impl CategoryRepository for FakeCategoryRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,include_inactive: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError>;
+            async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError>;
+            async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError>;
+            async fn find_by_slug(&self, slug: String, include_inactive: &bool) -> Result<Option<CategoryModel>, DomainError>;
+            async fn insert(&self,category_create_model: &CategoryCreateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn update_by_id(&self,id: &i32,category_update_model: &CategoryUpdateModel,actor: &str) -> Result<CategoryModel, DomainError>;
+            async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_group_updated() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        group_repository
+            .expect_upsert_by_extid()
+            .return_once(|_, _, _| {
+                Ok(UpsertModel {
+                    outcome: UpsertOutcome::Updated,
+                    before: Some(GroupModel::mock_default()),
+                    record: GroupModel::mock_default(),
+                })
+            });
+
+        let response = execute(
+            Arc::new(group_repository),
+            Arc::new(MockFakeOrganizerRepository::new()),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_string(10),
+            GroupUpdateModel::mock_default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.outcome, UpsertOutcome::Updated);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_unchanged_group() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        group_repository
+            .expect_upsert_by_extid()
+            .return_once(|_, _, _| {
+                Ok(UpsertModel {
+                    outcome: UpsertOutcome::Unchanged,
+                    before: Some(GroupModel::mock_default()),
+                    record: GroupModel::mock_default(),
+                })
+            });
+
+        let response = execute(
+            Arc::new(group_repository),
+            Arc::new(MockFakeOrganizerRepository::new()),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_string(10),
+            GroupUpdateModel::mock_default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.outcome, UpsertOutcome::Unchanged);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_organizer_not_found() {
+        let mut group_repository = MockFakeGroupRepository::new();
+        group_repository.expect_upsert_by_extid().never();
+
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(None));
+
+        let mut group_update_model = GroupUpdateModel::mock_default();
+        group_update_model.organizerids = vec![random_number()];
+
+        let result = execute(
+            Arc::new(group_repository),
+            Arc::new(organizer_repository),
+            Arc::new(MockFakeCategoryRepository::new()),
+            random_string(10),
+            random_string(10),
+            group_update_model,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
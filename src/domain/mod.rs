@@ -1,9 +1,15 @@
 pub mod error;
 
+pub mod api_key;
+pub mod audit_log;
 pub mod categories;
 pub mod article;
 pub mod city;
 pub mod event;
+pub mod event_series;
 pub mod state;
 pub mod group;
+pub mod organizer;
 pub mod health;
+pub mod rate_limit;
+pub mod upsert;
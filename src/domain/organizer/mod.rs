@@ -0,0 +1,4 @@
+#![allow(clippy::too_many_arguments)]
+pub mod model;
+pub mod repository;
+pub mod resources;
@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[cfg(test)]
+use crate::api::utils::random_number;
+#[cfg(test)]
+use crate::api::utils::random_string;
+
+#[derive(Debug, Clone)]
+pub struct OrganizerCreateModel {
+    pub firstname: String,
+    pub lastname: String,
+    pub bio: String,
+    pub extid: String,
+    pub highres_link: Option<String>,
+    pub photo_link: Option<String>,
+    pub thumb_link: Option<String>,
+}
+impl OrganizerCreateModel {
+    pub fn new(
+        firstname: String,
+        lastname: String,
+        bio: String,
+        extid: String,
+        highres_link: Option<String>,
+        photo_link: Option<String>,
+        thumb_link: Option<String>,
+    ) -> Self {
+        Self {
+            firstname,
+            lastname,
+            bio,
+            extid,
+            highres_link,
+            photo_link,
+            thumb_link,
+        }
+    }
+}
+
+#[cfg(test)]
+impl OrganizerCreateModel {
+    pub fn mock_default() -> Self {
+        Self {
+            firstname: random_string(10),
+            lastname: random_string(10),
+            bio: "The Big Organizer".to_string(),
+            extid: random_string(10),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrganizerUpdateModel {
+    pub firstname: String,
+    pub lastname: String,
+    pub bio: String,
+    pub highres_link: Option<String>,
+    pub photo_link: Option<String>,
+    pub thumb_link: Option<String>,
+}
+impl OrganizerUpdateModel {
+    pub fn new(
+        firstname: String,
+        lastname: String,
+        bio: String,
+        highres_link: Option<String>,
+        photo_link: Option<String>,
+        thumb_link: Option<String>,
+    ) -> Self {
+        Self {
+            firstname,
+            lastname,
+            bio,
+            highres_link,
+            photo_link,
+            thumb_link,
+        }
+    }
+}
+#[cfg(test)]
+impl OrganizerUpdateModel {
+    pub fn mock_default() -> Self {
+        Self {
+            firstname: "John".to_string(),
+            lastname: "Doe".to_string(),
+            bio: "The Big Organizer".to_string(),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrganizerModel {
+    pub organizerid: i32,
+    pub firstname: String,
+    pub lastname: String,
+    pub bio: String,
+    pub extid: String,
+    pub highres_link: Option<String>,
+    pub photo_link: Option<String>,
+    pub thumb_link: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+#[cfg(test)]
+impl OrganizerModel {
+    pub fn mock_default() -> Self {
+        Self {
+            organizerid: random_number(),
+            firstname: random_string(10),
+            lastname: random_string(10),
+            bio: "The Big Organizer".to_string(),
+            extid: random_string(10),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+            created_at: DateTime::default(),
+            updated_at: Some(DateTime::default()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+use super::model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel};
+
+#[async_trait]
+pub trait OrganizerRepository: Send + Sync {
+    async fn find(
+        &self,
+        name: &Option<String>,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+    async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+    async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+    async fn insert(
+        &self,
+        organizer_create_model: &OrganizerCreateModel,
+        actor: &str,
+    ) -> Result<OrganizerModel, DomainError>;
+    async fn update_by_organizerid(
+        &self,
+        id: &i32,
+        organizer_update_model: &OrganizerUpdateModel,
+        actor: &str,
+    ) -> Result<OrganizerModel, DomainError>;
+    async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::domain::organizer::model::OrganizerModel;
+use crate::domain::{
+    error::DomainError,
+    organizer::{model::OrganizerCreateModel, repository::OrganizerRepository},
+};
+
+pub async fn execute(
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    actor: String,
+    organizer_create_model: OrganizerCreateModel,
+) -> Result<OrganizerModel, DomainError> {
+    let organizer = organizer_repository.insert(&organizer_create_model, &actor).await?;
+
+    Ok(organizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::organizer::model::OrganizerUpdateModel;
+
+    use super::*;
+    use crate::api::utils::random_string;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_organizer_created() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_insert()
+            .return_once(|_, _| Ok(OrganizerModel::mock_default()));
+
+        let result = execute(
+            Arc::new(organizer_repository),
+            random_string(10),
+            OrganizerCreateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::domain::{error::DomainError, organizer::repository::OrganizerRepository};
+
+pub async fn execute(
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    actor: String,
+    organizer_id: i32,
+) -> Result<(), DomainError> {
+    let has_organizer = organizer_repository
+        .find_by_organizerid(&organizer_id)
+        .await?;
+    if has_organizer.is_none() {
+        return Err(DomainError::NotFound(String::from(
+            "Organizer id not found",
+        )));
+    }
+
+    organizer_repository
+        .delete_by_organizerid(&organizer_id, &actor)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::random_number,
+        domain::organizer::model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel},
+    };
+
+    use super::*;
+    use crate::api::utils::random_string;
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_void_organizer_deleted() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(Some(OrganizerModel::mock_default())));
+
+        organizer_repository
+            .expect_delete_by_organizerid()
+            .return_once(|_, _| Ok(()));
+
+        let result = execute(
+            Arc::new(organizer_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
+
+        match result {
+            Ok(()) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_organizer_not_found() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(None));
+
+        let result = execute(
+            Arc::new(organizer_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
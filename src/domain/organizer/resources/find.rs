@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    organizer::{model::OrganizerModel, repository::OrganizerRepository},
+};
+
+pub async fn execute(
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    name: Option<String>,
+    page: u32,
+    page_size: u32,
+) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError> {
+    let organizers = organizer_repository.find(&name, &page, &page_size).await?;
+
+    if organizers.is_some() {
+        return Ok(organizers);
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::domain::organizer::model::{OrganizerCreateModel, OrganizerUpdateModel};
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_organizer_finded() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_find()
+            .return_once(|_, _, _| Ok(Some((vec![OrganizerModel::mock_default()], 1))));
+
+        let (organizers, count) = execute(Arc::new(organizer_repository), None, 1, 12)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!organizers.is_empty());
+        assert!(count == 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_none_finded() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+        organizer_repository
+            .expect_find()
+            .return_once(|_, _, _| Ok(None));
+
+        let response = execute(Arc::new(organizer_repository), None, 1, 12)
+            .await
+            .unwrap();
+
+        assert!(response.is_none());
+    }
+}
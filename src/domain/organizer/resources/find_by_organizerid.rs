@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    organizer::{model::OrganizerModel, repository::OrganizerRepository},
+};
+
+pub async fn execute(
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    id: i32,
+) -> Result<Option<OrganizerModel>, DomainError> {
+    if let Some(organizer) = organizer_repository.find_by_organizerid(&id).await? {
+        return Ok(Some(organizer));
+    }
+
+    Ok(None)
+}
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::random_number,
+        domain::organizer::model::{OrganizerCreateModel, OrganizerUpdateModel},
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_organizer_finded() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(Some(OrganizerModel::mock_default())));
+
+        let result = execute(Arc::new(organizer_repository), random_number()).await;
+
+        match result {
+            Ok(_) => {}
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_no_content_organizer() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(None));
+
+        let result = execute(Arc::new(organizer_repository), random_number()).await;
+
+        match result {
+            Ok(result) => {
+                assert!(result.is_none())
+            }
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod create;
+pub mod delete_by_organizerid;
+pub mod find;
+pub mod find_by_extid;
+pub mod find_by_organizerid;
+pub mod update_by_organizerid;
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    organizer::{
+        model::{OrganizerModel, OrganizerUpdateModel},
+        repository::OrganizerRepository,
+    },
+};
+
+pub async fn execute(
+    organizer_repository: Arc<dyn OrganizerRepository>,
+    actor: String,
+    id: i32,
+    organizer_update_model: OrganizerUpdateModel,
+) -> Result<OrganizerModel, DomainError> {
+    let has_organizer = organizer_repository.find_by_organizerid(&id).await?;
+    if has_organizer.is_none() {
+        return Err(DomainError::NotFound(String::from(
+            "Organizer id not found",
+        )));
+    }
+
+    let organizer = organizer_repository
+        .update_by_organizerid(&id, &organizer_update_model, &actor)
+        .await?;
+
+    Ok(organizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{api::utils::random_number, domain::organizer::model::OrganizerCreateModel};
+
+    use super::*;
+    use crate::api::utils::random_string;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub FakeOrganizerRepository { }
+
+        #[async_trait]
+        impl OrganizerRepository for FakeOrganizerRepository {
+            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError>;
+            async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError>;
+            async fn insert(&self,organizer_create_model: &OrganizerCreateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn update_by_organizerid(&self,id: &i32,organizer_update_model: &OrganizerUpdateModel,actor: &str) -> Result<OrganizerModel, DomainError>;
+            async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_organizer_updated() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+
+        let mock_organizer_model = OrganizerModel::mock_default();
+        let mut mock_request_organizer_update = OrganizerUpdateModel::mock_default();
+        mock_request_organizer_update.firstname = mock_organizer_model.firstname.clone();
+
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(Some(mock_organizer_model)));
+
+        organizer_repository
+            .expect_update_by_organizerid()
+            .return_once(|_, _, _| Ok(OrganizerModel::mock_default()));
+
+        let response = execute(
+            Arc::new(organizer_repository),
+            random_string(10),
+            random_number(),
+            mock_request_organizer_update,
+        )
+        .await
+        .unwrap();
+
+        assert!(response.organizerid != 0);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_not_found_organizer() {
+        let mut organizer_repository = MockFakeOrganizerRepository::new();
+        organizer_repository
+            .expect_find_by_organizerid()
+            .return_once(|_| Ok(None));
+
+        let result = execute(
+            Arc::new(organizer_repository),
+            random_string(10),
+            random_number(),
+            OrganizerUpdateModel::mock_default(),
+        )
+        .await;
+
+        match result {
+            Err(DomainError::NotFound(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
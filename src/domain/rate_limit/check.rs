@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+
+use super::{model::RateLimitModel, repository::RateLimitRepository};
+
+pub async fn execute(
+    rate_limit_repository: Arc<dyn RateLimitRepository>,
+    key: String,
+    limit: u32,
+    window_ms: u64,
+) -> Result<RateLimitModel, DomainError> {
+    if limit == 0 {
+        return Err(DomainError::BadRequest(String::from(
+            "Rate limit must be greater than zero",
+        )));
+    }
+
+    rate_limit_repository.hit(key, limit, window_ms).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::api::utils::random_string;
+
+    mock! {
+        pub FakeRateLimitRepository { }
+
+        #[async_trait]
+        impl RateLimitRepository for FakeRateLimitRepository {
+            async fn hit(&self, key: String, limit: u32, window_ms: u64) -> Result<RateLimitModel, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_rate_limit_checked() {
+        let mut rate_limit_repository = MockFakeRateLimitRepository::new();
+        rate_limit_repository
+            .expect_hit()
+            .return_once(|_, _, _| Ok(RateLimitModel::mock_default()));
+
+        let result = execute(
+            Arc::new(rate_limit_repository),
+            random_string(10),
+            10,
+            60_000,
+        )
+        .await
+        .unwrap();
+
+        assert!(result.allowed);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_error_when_limit_is_zero() {
+        let rate_limit_repository = MockFakeRateLimitRepository::new();
+
+        let result = execute(
+            Arc::new(rate_limit_repository),
+            random_string(10),
+            0,
+            60_000,
+        )
+        .await;
+
+        match result {
+            Err(DomainError::BadRequest(_)) => {}
+            _ => unreachable!(),
+        }
+    }
+}
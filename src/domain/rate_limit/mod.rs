@@ -0,0 +1,3 @@
+pub mod check;
+pub mod model;
+pub mod repository;
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone)]
+pub struct RateLimitModel {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_after_ms: u64,
+}
+
+#[cfg(test)]
+impl RateLimitModel {
+    pub fn mock_default() -> Self {
+        Self {
+            allowed: true,
+            limit: 10,
+            remaining: 9,
+            reset_after_ms: 60_000,
+        }
+    }
+}
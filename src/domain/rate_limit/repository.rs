@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+use super::model::RateLimitModel;
+
+#[async_trait]
+pub trait RateLimitRepository: Send + Sync {
+    /// Records a hit for `key` unless `limit` hits were already recorded in the
+    /// last `window_ms` milliseconds.
+    async fn hit(
+        &self,
+        key: String,
+        limit: u32,
+        window_ms: u64,
+    ) -> Result<RateLimitModel, DomainError>;
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 use crate::api::utils::random_number;
@@ -86,7 +87,7 @@ impl StateUpdateModel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StateModel {
     pub stateid: i32,
     pub name: String,
@@ -114,3 +115,55 @@ impl StateModel {
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateCityModel {
+    pub cityid: i32,
+    pub name: String,
+    pub slug: String,
+    pub highres_link: Option<String>,
+    pub photo_link: Option<String>,
+    pub thumb_link: Option<String>,
+    pub group_count: u32,
+    pub upcoming_event_count: u32,
+}
+#[cfg(test)]
+impl StateCityModel {
+    pub fn mock_default() -> Self {
+        Self {
+            cityid: random_number(),
+            name: random_string(10),
+            slug: random_string(10),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+            group_count: 1,
+            upcoming_event_count: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateDetailModel {
+    pub stateid: i32,
+    pub name: String,
+    pub symbol: String,
+    pub highres_link: Option<String>,
+    pub photo_link: Option<String>,
+    pub thumb_link: Option<String>,
+    pub cities: Vec<StateCityModel>,
+}
+#[cfg(test)]
+impl StateDetailModel {
+    pub fn mock_default() -> Self {
+        Self {
+            stateid: random_number(),
+            name: random_string(10),
+            symbol: random_string(2),
+            highres_link: Some("".to_string()),
+            photo_link: Some("".to_string()),
+            thumb_link: Some("".to_string()),
+            cities: vec![StateCityModel::mock_default()],
+        }
+    }
+}
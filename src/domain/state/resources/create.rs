@@ -8,17 +8,20 @@ use crate::domain::{
 
 pub async fn execute(
     state_repository: Arc<dyn StateRepository>,
+    actor: String,
     state_create_model: StateCreateModel,
 ) -> Result<StateModel, DomainError> {
-    let state = state_repository.insert(&state_create_model).await?;
+    let state = state_repository.insert(&state_create_model, &actor).await?;
+
     Ok(state)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::state::model::StateUpdateModel;
+    use crate::domain::state::model::{StateDetailModel, StateUpdateModel};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
@@ -27,11 +30,12 @@ mod tests {
 
         #[async_trait]
         impl StateRepository for FakeStateRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
             async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
-            async fn insert(&self,state_create_model: &StateCreateModel) -> Result<StateModel, DomainError>;
-            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel) -> Result<StateModel, DomainError>;
-            async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -41,10 +45,11 @@ mod tests {
 
         state_repository
             .expect_insert()
-            .return_once(|_| Ok(StateModel::mock_default()));
+            .return_once(|_, _| Ok(StateModel::mock_default()));
 
         let result = execute(
             Arc::new(state_repository),
+            random_string(10),
             StateCreateModel::mock_default(),
         )
         .await;
@@ -4,6 +4,7 @@ use crate::domain::{state::repository::StateRepository, error::DomainError};
 
 pub async fn execute(
     state_repository: Arc<dyn StateRepository>,
+    actor: String,
     state_id: i32,
 ) -> Result<(), DomainError> {
     let has_state = state_repository.find_by_stateid(&state_id).await?;
@@ -11,7 +12,7 @@ pub async fn execute(
         return Err(DomainError::NotFound(String::from("State id not found")));
     }
 
-    state_repository.delete_by_stateid(&state_id).await?;
+    state_repository.delete_by_stateid(&state_id, &actor).await?;
 
     Ok(())
 }
@@ -23,21 +24,23 @@ mod tests {
     use crate::api::utils::random_number;
 
     use crate::domain::state::model::{
-        StateCreateModel, StateModel, StateUpdateModel,
+        StateCreateModel, StateDetailModel, StateModel, StateUpdateModel,
     };
 
     use super::*;
+    use crate::api::utils::random_string;
 
     mock! {
         pub FakeStateRepository { }
 
         #[async_trait]
         impl StateRepository for FakeStateRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
             async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
-            async fn insert(&self,state_create_model: &StateCreateModel) -> Result<StateModel, DomainError>;
-            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel) -> Result<StateModel, DomainError>;
-            async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -51,9 +54,14 @@ mod tests {
 
         state_repository
             .expect_delete_by_stateid()
-            .return_once(|_| Ok(()));
+            .return_once(|_, _| Ok(()));
 
-        let result = execute(Arc::new(state_repository), random_number()).await;
+        let result = execute(
+            Arc::new(state_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Ok(()) => {}
@@ -69,7 +77,12 @@ mod tests {
             .expect_find_by_stateid()
             .return_once(|_| Ok(None));
 
-        let result = execute(Arc::new(state_repository), random_number()).await;
+        let result = execute(
+            Arc::new(state_repository),
+            random_string(10),
+            random_number(),
+        )
+        .await;
 
         match result {
             Err(DomainError::NotFound(_)) => {}
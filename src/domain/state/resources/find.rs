@@ -8,10 +8,11 @@ use crate::domain::{
 pub async fn execute(
     state_repository: Arc<dyn StateRepository>,
     name: Option<String>,
+    fuzzy: bool,
     page: u32,
     page_size: u32,
 ) -> Result<Option<(Vec<StateModel>, u32)>, DomainError> {
-    let state = state_repository.find(&name, &page, &page_size).await?;
+    let state = state_repository.find(&name, &fuzzy, &page, &page_size).await?;
 
     if state.is_some() {
         return Ok(state);
@@ -27,18 +28,19 @@ mod tests {
     use async_trait::async_trait;
     use mockall::mock;
 
-    use crate::domain::state::model::{StateCreateModel, StateUpdateModel};
+    use crate::domain::state::model::{StateCreateModel, StateDetailModel, StateUpdateModel};
 
     mock! {
         pub FakeStateRepository { }
 
         #[async_trait]
         impl StateRepository for FakeStateRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
             async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
-            async fn insert(&self,state_create_model: &StateCreateModel) -> Result<StateModel, DomainError>;
-            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel) -> Result<StateModel, DomainError>;
-            async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -48,9 +50,9 @@ mod tests {
 
         state_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(Some((vec![StateModel::mock_default()], 1))));
+            .return_once(|_, _, _, _| Ok(Some((vec![StateModel::mock_default()], 1))));
 
-        let (state, count) = execute(Arc::new(state_repository), None, 1, 12)
+        let (state, count) = execute(Arc::new(state_repository), None, false, 1, 12)
             .await
             .unwrap()
             .unwrap();
@@ -64,9 +66,9 @@ mod tests {
         let mut state_repository = MockFakeStateRepository::new();
         state_repository
             .expect_find()
-            .return_once(|_, _, _| Ok(None));
+            .return_once(|_, _, _, _| Ok(None));
 
-        let response = execute(Arc::new(state_repository), None, 1, 12)
+        let response = execute(Arc::new(state_repository), None, false, 1, 12)
             .await
             .unwrap();
 
@@ -20,7 +20,7 @@ mod tests {
     use async_trait::async_trait;
     use mockall::mock;
 
-    use crate::{domain::state::model::{StateCreateModel, StateUpdateModel}, api::utils::random_number};
+    use crate::{domain::state::model::{StateCreateModel, StateDetailModel, StateUpdateModel}, api::utils::random_number};
 
     use super::*;
 
@@ -29,11 +29,12 @@ mod tests {
 
         #[async_trait]
         impl StateRepository for FakeStateRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
             async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
-            async fn insert(&self,state_create_model: &StateCreateModel) -> Result<StateModel, DomainError>;
-            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel) -> Result<StateModel, DomainError>;
-            async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
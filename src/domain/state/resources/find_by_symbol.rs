@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::domain::{
+    error::DomainError,
+    state::{model::StateDetailModel, repository::StateRepository},
+};
+
+pub async fn execute(
+    state_repository: Arc<dyn StateRepository>,
+    symbol: String,
+) -> Result<Option<StateDetailModel>, DomainError> {
+    if let Some(state) = state_repository
+        .find_by_symbol(symbol.to_lowercase())
+        .await?
+    {
+        return Ok(Some(state));
+    }
+
+    Ok(None)
+}
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    use crate::{
+        api::utils::random_string,
+        domain::state::model::{StateCreateModel, StateModel, StateUpdateModel},
+    };
+
+    use super::*;
+
+    mock! {
+        pub FakeStateRepository { }
+
+        #[async_trait]
+        impl StateRepository for FakeStateRepository {
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_state_finded() {
+        let mut state_repository = MockFakeStateRepository::new();
+
+        state_repository
+            .expect_find_by_symbol()
+            .withf(|symbol| symbol == "on")
+            .return_once(|_| Ok(Some(StateDetailModel::mock_default())));
+
+        let result = execute(Arc::new(state_repository), "ON".to_string()).await;
+
+        match result {
+            Ok(result) => assert!(result.is_some()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_no_content_state() {
+        let mut state_repository = MockFakeStateRepository::new();
+
+        state_repository
+            .expect_find_by_symbol()
+            .return_once(|_| Ok(None));
+
+        let result = execute(Arc::new(state_repository), random_string(2)).await;
+
+        match result {
+            Ok(result) => assert!(result.is_none()),
+            Err(err) => unreachable!("{err}"),
+        }
+    }
+}
@@ -2,4 +2,5 @@ pub mod create;
 pub mod delete_by_stateid;
 pub mod find;
 pub mod find_by_stateid;
+pub mod find_by_symbol;
 pub mod update_by_stateid;
@@ -10,6 +10,7 @@ use crate::domain::{
 
 pub async fn execute(
     state_repository: Arc<dyn StateRepository>,
+    actor: String,
     id: i32,
     state_update_model: StateUpdateModel,
 ) -> Result<StateModel, DomainError> {
@@ -19,7 +20,7 @@ pub async fn execute(
     }
 
     let state = state_repository
-        .update_by_stateid(&id, &state_update_model)
+        .update_by_stateid(&id, &state_update_model, &actor)
         .await?;
 
     Ok(state)
@@ -27,9 +28,10 @@ pub async fn execute(
 
 #[cfg(test)]
 mod tests {
-    use crate::{domain::state::model::StateCreateModel, api::utils::random_number};
+    use crate::{domain::state::model::{StateCreateModel, StateDetailModel}, api::utils::random_number};
 
     use super::*;
+    use crate::api::utils::random_string;
 
     use async_trait::async_trait;
     use mockall::mock;
@@ -39,11 +41,12 @@ mod tests {
 
         #[async_trait]
         impl StateRepository for FakeStateRepository {
-            async fn find(&self,name: &Option<String>,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
+            async fn find(&self,name: &Option<String>,fuzzy: &bool,page: &u32,page_size: &u32) -> Result<Option<(Vec<StateModel>, u32)>, DomainError>;
             async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError>;
-            async fn insert(&self,state_create_model: &StateCreateModel) -> Result<StateModel, DomainError>;
-            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel) -> Result<StateModel, DomainError>;
-            async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError>;
+            async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError>;
+            async fn insert(&self,state_create_model: &StateCreateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn update_by_stateid(&self,id: &i32,state_update_model: &StateUpdateModel,actor: &str) -> Result<StateModel, DomainError>;
+            async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError>;
         }
     }
 
@@ -61,10 +64,11 @@ mod tests {
 
         state_repository
             .expect_update_by_stateid()
-            .return_once(|_, _| Ok(StateModel::mock_default()));
+            .return_once(|_, _, _| Ok(StateModel::mock_default()));
 
         let response = execute(
             Arc::new(state_repository),
+            random_string(10),
             random_number().to_owned(),
             mock_request_state_update,
         )
@@ -83,6 +87,7 @@ mod tests {
 
         let result = execute(
             Arc::new(state_repository),
+            random_string(10),
             random_number().to_owned(),
             StateUpdateModel::mock_default(),
         )
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+use crate::domain::audit_log::model::AuditLogCreateModel;
+
+/// What inserting or updating a row by its external id did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Created,
+    Updated,
+    /// The row already held the sent values and was left untouched.
+    Unchanged,
+}
+impl UpsertOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpsertOutcome::Created => "created",
+            UpsertOutcome::Updated => "updated",
+            UpsertOutcome::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpsertModel<T> {
+    pub outcome: UpsertOutcome,
+    /// Row as it was before an update, used for the audit log.
+    pub before: Option<T>,
+    pub record: T,
+}
+impl<T: Serialize> UpsertModel<T> {
+    /// Audit log entry recording the upsert, if it wrote anything.
+    pub fn audit_log(
+        &self,
+        actor: String,
+        resource: &str,
+        resourceid: i32,
+    ) -> Option<AuditLogCreateModel> {
+        match self.outcome {
+            UpsertOutcome::Created => Some(AuditLogCreateModel::created(
+                actor,
+                resource,
+                resourceid,
+                &self.record,
+            )),
+            UpsertOutcome::Updated => Some(AuditLogCreateModel::updated(
+                actor,
+                resource,
+                resourceid,
+                &self.before,
+                &self.record,
+            )),
+            UpsertOutcome::Unchanged => None,
+        }
+    }
+}
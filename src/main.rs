@@ -10,7 +10,7 @@ mod repository;
 #[actix_web::main]
 async fn main() -> io::Result<()> {
     dotenv().ok();
-    env_logger::init();
+    lib::init_logger();
 
     let pg_pool_result = postgres::init();
     if pg_pool_result.is_err() {
@@ -21,6 +21,20 @@ async fn main() -> io::Result<()> {
     let pg_pool = Arc::new(pg_pool_result.unwrap());
     let redis_client = Arc::new(redis::init());
 
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, command, name] = args.as_slice() {
+        if command == "create-api-key" {
+            match lib::create_api_key(pg_pool.clone(), redis_client.clone(), name.to_owned()).await {
+                Ok(key) => println!("{key}"),
+                Err(err) => {
+                    log::error!("{}", err);
+                    std::process::exit(1)
+                }
+            }
+            return Ok(());
+        }
+    }
+
     let result = lib::run(pg_pool.clone(), redis_client.clone()).await;
     if result.is_err() {
         log::error!("{}", result.unwrap_err().to_string());
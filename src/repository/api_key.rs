@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use deadpool_postgres::Pool;
+use redis::{AsyncCommands, Client};
+
+use tokio_postgres::Row;
+
+use crate::domain::{
+    api_key::{
+        model::{ApiKeyCreateModel, ApiKeyModel},
+        repository::ApiKeyRepository,
+    },
+    error::DomainError,
+};
+
+const CACHE_TTL_SECONDS: usize = 60 * 5;
+
+const QUERY_FIND_API_KEY_BY_KEY_HASH: &str = "
+    select
+        apikeyid,
+        name,
+        key_hash,
+        active,
+        created_at,
+        updated_at
+    from
+        api_key
+    where
+        key_hash = $1;";
+
+const QUERY_INSERT_API_KEY: &str = "
+    insert into api_key(name,key_hash,active)
+    values
+        ($1,$2,$3)
+    returning
+        apikeyid,
+        name,
+        key_hash,
+        active,
+        created_at,
+        updated_at;";
+
+pub struct PgApiKeyRepository {
+    pool: Arc<Pool>,
+    redis_client: Arc<Client>,
+}
+impl PgApiKeyRepository {
+    pub fn new(pool: Arc<Pool>, redis_client: Arc<Client>) -> Self {
+        Self { pool, redis_client }
+    }
+
+    fn cache_key(key_hash: &str) -> String {
+        format!("api_key:{key_hash}")
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PgApiKeyRepository {
+    async fn find_by_key_hash(&self, key_hash: String) -> Result<Option<ApiKeyModel>, DomainError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+        let cache_key = Self::cache_key(&key_hash);
+        let api_key: Option<String> = conn.get(&cache_key).await?;
+
+        if let Some(serialized_api_key) = api_key {
+            let api_key: ApiKeyModel = serde_json::from_str(&serialized_api_key)?;
+            return Ok(Some(api_key));
+        }
+
+        let client = self.pool.get().await?;
+        let stmt = client.prepare(QUERY_FIND_API_KEY_BY_KEY_HASH).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&key_hash]).await? {
+            let api_key: ApiKeyModel = (&result).into();
+
+            let _: () = conn
+                .set_ex(cache_key, serde_json::to_string(&api_key)?, CACHE_TTL_SECONDS)
+                .await?;
+
+            return Ok(Some(api_key));
+        }
+
+        Ok(None)
+    }
+
+    async fn insert(
+        &self,
+        api_key_create_model: &ApiKeyCreateModel,
+    ) -> Result<ApiKeyModel, DomainError> {
+        let client = self.pool.get().await?;
+        let stmt = client.prepare(QUERY_INSERT_API_KEY).await?;
+        let result = &client
+            .query_one(
+                &stmt,
+                &[
+                    &api_key_create_model.name,
+                    &api_key_create_model.key_hash,
+                    &api_key_create_model.active,
+                ],
+            )
+            .await?;
+
+        Ok(result.into())
+    }
+}
+
+impl From<&Row> for ApiKeyModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            apikeyid: row.get("apikeyid"),
+            name: row.get("name"),
+            key_hash: row.get("key_hash"),
+            active: row.get("active"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
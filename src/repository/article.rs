@@ -1,18 +1,23 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{GenericClient, Pool};
+use serde::{Deserialize, Serialize};
 
 use tokio_postgres::{types::ToSql, Row};
 
+use crate::repository::{audit_log, search};
 use crate::domain::{
     article::{
         model::{ArticleCreateModel, ArticleModel, ArticleUpdateModel},
         repository::ArticleRepository,
     },
+    audit_log::model::AuditLogCreateModel,
+    categories::model::CategoryModel,
     error::DomainError,
 };
 
+// `d.snippet` comes from the lateral join appended in `find`.
 const QUERY_FIND_ARTICLE: &str = "
     select
         articleid,
@@ -29,6 +34,7 @@ const QUERY_FIND_ARTICLE: &str = "
         highres_link,
         photo_link,
         thumb_link,
+        d.snippet,
         count(1) over ()::OID as count
     from
         article";
@@ -114,6 +120,60 @@ const QUERY_DELETE_ARTICLE_BY_ID: &str = "
             where
                 articleid = $1;";
 
+const QUERY_FIND_CATEGORIES_BY_ARTICLEIDS: &str = "
+    select
+        ac.articleid,
+        c.id as category_id,
+        c.name as category_name,
+        c.slug as category_slug,
+        c.description as category_description,
+        c.parent_id as category_parent_id,
+        c.is_active as category_is_active,
+        c.created_at as category_created_at,
+        c.updated_at as category_updated_at
+    from
+        category c
+    join article_category ac on ac.categoryid = c.id
+    where
+        ac.articleid = any($1)
+    order by
+        c.id;";
+
+const QUERY_INSERT_ARTICLE_CATEGORIES: &str = "
+    insert into article_category(articleid,categoryid)
+    select
+        $1, unnest($2::int4[])
+    on conflict do nothing;";
+
+const QUERY_DELETE_ARTICLE_CATEGORIES_BY_ARTICLEID: &str = "
+    delete from
+        article_category
+        where
+            articleid = $1;";
+
+/// Listing order of articles; a leading `-` sorts descending. Ties are broken
+/// on `articleid` so pages never overlap.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum ArticleSortOption {
+    #[serde(rename = "publish_at")]
+    PublishAt,
+    #[serde(rename = "-publish_at")]
+    PublishAtDesc,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDesc,
+}
+
+fn article_order_by(sort: &Option<ArticleSortOption>) -> &'static str {
+    match sort {
+        Some(ArticleSortOption::PublishAt) => "article.publish_at, article.articleid",
+        Some(ArticleSortOption::PublishAtDesc) | None => "article.publish_at desc, article.articleid desc",
+        Some(ArticleSortOption::Name) => "article.name, article.articleid",
+        Some(ArticleSortOption::NameDesc) => "article.name desc, article.articleid",
+    }
+}
+
 pub struct PgArticleRepository {
     pool: Arc<Pool>,
 }
@@ -121,6 +181,46 @@ impl PgArticleRepository {
     pub fn new(pool: Arc<Pool>) -> Self {
         Self { pool }
     }
+
+    /// Loads the categories of every article in `articles` with a single query.
+    async fn find_categories<C: GenericClient>(
+        client: &C,
+        articles: &mut [ArticleModel],
+    ) -> Result<(), DomainError> {
+        let articleids: Vec<i32> = articles.iter().map(|article| article.articleid).collect();
+
+        let stmt = client.prepare(QUERY_FIND_CATEGORIES_BY_ARTICLEIDS).await?;
+        let result = client.query(&stmt, &[&articleids]).await?;
+
+        let mut categories: HashMap<i32, Vec<CategoryModel>> = HashMap::new();
+        for row in result.iter() {
+            categories
+                .entry(row.get("articleid"))
+                .or_default()
+                .push(row.into());
+        }
+
+        for article in articles.iter_mut() {
+            article.categories = categories.remove(&article.articleid).unwrap_or_default();
+        }
+
+        Ok(())
+    }
+
+    async fn find_by_articleid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<ArticleModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_ARTICLE_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            let mut article: ArticleModel = (&result).into();
+            Self::find_categories(client, std::slice::from_mut(&mut article)).await?;
+            return Ok(Some(article));
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -128,6 +228,10 @@ impl ArticleRepository for PgArticleRepository {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        q: &Option<String>,
+        category: &Option<i32>,
+        sort: &Option<ArticleSortOption>,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<ArticleModel>, u32)>, DomainError> {
@@ -136,21 +240,42 @@ impl ArticleRepository for PgArticleRepository {
         let mut queries: Vec<String> = vec![];
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
 
+        // Full-text matches rank before similar names.
+        let mut ranks: Vec<String> = vec![];
+        let mut snippet = String::from("null::text");
+        if let Some(q) = q {
+            queries.push(search::text_matches("article", params.len() + 1));
+            ranks.push(search::text_rank("article", params.len() + 1));
+            snippet = search::text_snippet("article", params.len() + 1);
+            params.push(q);
+        }
+
         if let Some(name) = name {
+            queries.push(search::name_matches("article.name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                ranks.push(search::name_rank("article.name", params.len() + 1));
+            }
+            params.push(name);
+        }
+
+        if let Some(category) = category {
             queries.push(format!(
-                "article.name like '%' || ${} || '%'",
+                "article.articleid in (select articleid from article_category where categoryid = ${})",
                 params.len() + 1
             ));
-            params.push(name);
+            params.push(category);
         }
 
-        let mut query = String::from(QUERY_FIND_ARTICLE);
+        let mut query = format!("{QUERY_FIND_ARTICLE} cross join lateral (select {snippet} as snippet) d");
         if !queries.is_empty() {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
+        let order_by = article_order_by(sort);
+        ranks.push(order_by.to_string());
+        let order_by = ranks.join(", ");
         let offset = page_size * (page - 1);
-        query = format!("{query} limit {page_size} offset {offset}");
+        query = format!("{query} order by {order_by} limit {page_size} offset {offset}");
 
         let stmt = client.prepare(&query).await?;
         let result = client.query(&stmt, &params[..]).await?;
@@ -158,7 +283,15 @@ impl ArticleRepository for PgArticleRepository {
         if !result.is_empty() {
             let count: u32 = result.first().unwrap().get("count");
 
-            let articles: Vec<ArticleModel> = result.iter().map(|row| row.into()).collect();
+            let mut articles: Vec<ArticleModel> = result
+                .iter()
+                .map(|row| {
+                    let mut article: ArticleModel = row.into();
+                    article.snippet = row.get("snippet");
+                    article
+                })
+                .collect();
+            Self::find_categories(&client, &mut articles).await?;
 
             return Ok(Some((articles, count)));
         }
@@ -168,22 +301,18 @@ impl ArticleRepository for PgArticleRepository {
 
     async fn find_by_articleid(&self, id: &i32) -> Result<Option<ArticleModel>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_ARTICLE_BY_ID).await?;
-
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
-            return Ok(Some((&result).into()));
-        }
-
-        return Ok(None);
+        Self::find_by_articleid_in(&client, id).await
     }
 
     async fn insert(
         &self,
         article_create_model: &ArticleCreateModel,
+        actor: &str,
     ) -> Result<ArticleModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_ARTICLE).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_ARTICLE).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -202,18 +331,36 @@ impl ArticleRepository for PgArticleRepository {
             )
             .await?;
 
-        Ok(result.into())
+        let mut article: ArticleModel = result.into();
+
+        let stmt = transaction.prepare(QUERY_INSERT_ARTICLE_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[&article.articleid, &article_create_model.categoryids])
+            .await?;
+        Self::find_categories(&transaction, std::slice::from_mut(&mut article)).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "article", article.articleid, &article),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(article)
     }
 
     async fn update_by_articleid(
         &self,
         articleid: &i32,
         article_update_model: &ArticleUpdateModel,
+        actor: &str,
     ) -> Result<ArticleModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_ARTICLE_BY_ID).await?;
-        let result = &client
-        
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_articleid_in(&transaction, articleid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_ARTICLE_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -232,13 +379,44 @@ impl ArticleRepository for PgArticleRepository {
             )
             .await?;
 
-        Ok(result.into())
+        let mut article: ArticleModel = result.into();
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_ARTICLE_CATEGORIES_BY_ARTICLEID)
+            .await?;
+        transaction.execute(&stmt, &[articleid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_ARTICLE_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[articleid, &article_update_model.categoryids])
+            .await?;
+        Self::find_categories(&transaction, std::slice::from_mut(&mut article)).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "article", *articleid, &before, &article),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(article)
     }
 
-    async fn delete_by_articleid(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_ARTICLE_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
+    async fn delete_by_articleid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_articleid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_ARTICLE_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "article", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
         Ok(())
     }
 }
@@ -258,8 +436,10 @@ impl From<&Row> for ArticleModel {
             highres_link: row.get("highres_link"),
             photo_link: row.get("photo_link"),
             thumb_link: row.get("thumb_link"),
+            categories: vec![],
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
+            snippet: None,
         }
     }
 }
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use deadpool_postgres::{GenericClient, Pool};
+
+use tokio_postgres::{types::ToSql, Row};
+
+use crate::domain::{
+    audit_log::{
+        model::{AuditLogCreateModel, AuditLogModel},
+        repository::AuditLogRepository,
+    },
+    error::DomainError,
+};
+
+const QUERY_FIND_AUDIT_LOG: &str = "
+    select
+        auditlogid,
+        actor,
+        resource,
+        resourceid,
+        action,
+        before,
+        after,
+        created_at,
+        count(1) over ()::OID as count
+    from
+        audit_log";
+
+const QUERY_INSERT_AUDIT_LOG: &str = "
+    insert into audit_log(actor,resource,resourceid,action,before,after)
+    values
+        ($1,$2,$3,$4,$5,$6)
+    returning
+        auditlogid,
+        actor,
+        resource,
+        resourceid,
+        action,
+        before,
+        after,
+        created_at;";
+
+pub struct PgAuditLogRepository {
+    pool: Arc<Pool>,
+}
+impl PgAuditLogRepository {
+    pub fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+
+    #[cfg(test)]
+    pub async fn insert(
+        &self,
+        audit_log_create_model: &AuditLogCreateModel,
+    ) -> Result<AuditLogModel, DomainError> {
+        let client = self.pool.get().await?;
+        record(&client, audit_log_create_model).await
+    }
+}
+
+/// Stores an audit entry through `client`, which is expected to be the
+/// transaction applying the change, so the change and its entry commit or
+/// roll back together.
+pub async fn record<C: GenericClient>(
+    client: &C,
+    audit_log_create_model: &AuditLogCreateModel,
+) -> Result<AuditLogModel, DomainError> {
+    let stmt = client.prepare(QUERY_INSERT_AUDIT_LOG).await?;
+    let result = &client
+        .query_one(
+            &stmt,
+            &[
+                &audit_log_create_model.actor,
+                &audit_log_create_model.resource,
+                &audit_log_create_model.resourceid,
+                &audit_log_create_model.action.as_str(),
+                &audit_log_create_model.before,
+                &audit_log_create_model.after,
+            ],
+        )
+        .await?;
+
+    Ok(result.into())
+}
+
+#[async_trait]
+impl AuditLogRepository for PgAuditLogRepository {
+    async fn find(
+        &self,
+        resource: &Option<String>,
+        resourceid: &Option<i32>,
+        actor: &Option<String>,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<AuditLogModel>, u32)>, DomainError> {
+        let client = self.pool.get().await?;
+
+        let mut queries: Vec<String> = vec![];
+        let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+
+        if let Some(resource) = resource {
+            queries.push(format!("audit_log.resource = ${}", params.len() + 1));
+            params.push(resource);
+        }
+
+        if let Some(resourceid) = resourceid {
+            queries.push(format!("audit_log.resourceid = ${}", params.len() + 1));
+            params.push(resourceid);
+        }
+
+        if let Some(actor) = actor {
+            queries.push(format!("audit_log.actor = ${}", params.len() + 1));
+            params.push(actor);
+        }
+
+        let mut query = String::from(QUERY_FIND_AUDIT_LOG);
+        if !queries.is_empty() {
+            query = format!("{} where {}", query, queries.join(" and "));
+        }
+
+        let offset = page_size * (page - 1);
+        query = format!(
+            "{query} order by audit_log.created_at desc, audit_log.auditlogid desc limit {page_size} offset {offset}"
+        );
+
+        let stmt = client.prepare(&query).await?;
+        let result = client.query(&stmt, &params[..]).await?;
+
+        if !result.is_empty() {
+            let count: u32 = result.first().unwrap().get("count");
+
+            let audit_logs: Vec<AuditLogModel> = result.iter().map(|row| row.into()).collect();
+
+            return Ok(Some((audit_logs, count)));
+        }
+
+        Ok(None)
+    }
+}
+
+impl From<&Row> for AuditLogModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            auditlogid: row.get("auditlogid"),
+            actor: row.get("actor"),
+            resource: row.get("resource"),
+            resourceid: row.get("resourceid"),
+            action: row.get("action"),
+            before: row.get("before"),
+            after: row.get("after"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{GenericClient, Pool};
 
 use tokio_postgres::{types::ToSql, Row};
 
+use crate::repository::{audit_log, search};
 use crate::domain::{
+    audit_log::model::AuditLogCreateModel,
     categories::{
         model::{CategoryCreateModel, CategoryModel, CategoryUpdateModel},
         repository::CategoryRepository,
@@ -17,7 +19,9 @@ const QUERY_FIND_CATEGORY: &str = "
     select
         id as category_id,
         name as category_name,
+        slug as category_slug,
         description as category_description,
+        parent_id as category_parent_id,
         is_active as category_is_active,
         created_at as category_created_at,
         updated_at as category_updated_at,
@@ -29,7 +33,9 @@ const QUERY_FIND_CATEGORY_BY_ID: &str = "
     select
         id as category_id,
         name as category_name,
+        slug as category_slug,
         description as category_description,
+        parent_id as category_parent_id,
         is_active as category_is_active,
         created_at as category_created_at,
         updated_at as category_updated_at
@@ -38,15 +44,32 @@ const QUERY_FIND_CATEGORY_BY_ID: &str = "
     where 
         id = $1;";
 
+const QUERY_FIND_CATEGORY_BY_SLUG: &str = "
+    select
+        id as category_id,
+        name as category_name,
+        slug as category_slug,
+        description as category_description,
+        parent_id as category_parent_id,
+        is_active as category_is_active,
+        created_at as category_created_at,
+        updated_at as category_updated_at
+    from
+        category
+    where 
+        slug = $1";
+
 const QUERY_INSERT_CATEGORY: &str = "
     insert into category
-        (name, description)
+        (name, slug, description, parent_id)
     values
-        ($1,$2)
+        ($1,$2,$3,$4)
     returning
         id as category_id,
         name as category_name,
+        slug as category_slug,
         description as category_description,
+        parent_id as category_parent_id,
         is_active as category_is_active,
         created_at as category_created_at,
         updated_at as category_updated_at;";
@@ -56,14 +79,19 @@ const QUERY_UPDATE_CATEGORY_BY_ID: &str = "
         category 
     set
         name=$2,
-        description=$3,
+        slug=$3,
+        description=$4,
+        parent_id=$5,
+        is_active=$6,
         updated_at=now()
     where
         id = $1
     returning
         id as category_id,
         name as category_name,
+        slug as category_slug,
         description as category_description,
+        parent_id as category_parent_id,
         is_active as category_is_active,
         created_at as category_created_at,
         updated_at as category_updated_at;";
@@ -81,6 +109,19 @@ impl PgCategoryRepository {
     pub fn new(pool: Arc<Pool>) -> Self {
         Self { pool }
     }
+
+    async fn find_by_id_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<CategoryModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_CATEGORY_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            return Ok(Some((&result).into()));
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -88,6 +129,8 @@ impl CategoryRepository for PgCategoryRepository {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        include_inactive: &bool,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<CategoryModel>, u32)>, DomainError> {
@@ -96,19 +139,28 @@ impl CategoryRepository for PgCategoryRepository {
         let mut queries: Vec<String> = vec![];
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
 
+        let mut rank = None;
         if let Some(name) = name {
-            queries.push(format!(
-                "category.name like '%' || ${} || '%'",
-                params.len() + 1
-            ));
+            queries.push(search::name_matches("category.name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                rank = Some(search::name_rank("category.name", params.len() + 1));
+            }
             params.push(name);
         }
 
+        if !include_inactive {
+            queries.push(String::from("category.is_active"));
+        }
+
         let mut query = String::from(QUERY_FIND_CATEGORY);
         if !queries.is_empty() {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
+        if let Some(rank) = rank {
+            query = format!("{query} order by {rank}");
+        }
+
         let offset = page_size * (page - 1);
         query = format!("{query} limit {page_size} offset {offset}");
 
@@ -126,11 +178,41 @@ impl CategoryRepository for PgCategoryRepository {
         return Ok(None);
     }
 
+    async fn find_all(&self, include_inactive: &bool) -> Result<Vec<CategoryModel>, DomainError> {
+        let client = self.pool.get().await?;
+
+        let mut query = String::from(QUERY_FIND_CATEGORY);
+        if !include_inactive {
+            query = format!("{query} where category.is_active");
+        }
+        query = format!("{query} order by category.name, category.id");
+
+        let stmt = client.prepare(&query).await?;
+        let result = client.query(&stmt, &[]).await?;
+
+        Ok(result.iter().map(|row| row.into()).collect())
+    }
+
     async fn find_by_id(&self, id: &i32) -> Result<Option<CategoryModel>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_CATEGORY_BY_ID).await?;
+        Self::find_by_id_in(&client, id).await
+    }
 
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+    async fn find_by_slug(
+        &self,
+        slug: String,
+        include_inactive: &bool,
+    ) -> Result<Option<CategoryModel>, DomainError> {
+        let client = self.pool.get().await?;
+
+        let mut query = String::from(QUERY_FIND_CATEGORY_BY_SLUG);
+        if !include_inactive {
+            query = format!("{query} and is_active");
+        }
+
+        let stmt = client.prepare(&query).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&slug]).await? {
             return Ok(Some((&result).into()));
         }
 
@@ -140,47 +222,85 @@ impl CategoryRepository for PgCategoryRepository {
     async fn insert(
         &self,
         category_create_model: &CategoryCreateModel,
+        actor: &str,
     ) -> Result<CategoryModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_CATEGORY).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_CATEGORY).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
                     &category_create_model.name,
+                    &category_create_model.slug,
                     &category_create_model.description,
+                    &category_create_model.parent_id,
                 ],
             )
             .await?;
+        let category: CategoryModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "category", category.id, &category),
+        )
+        .await?;
+        transaction.commit().await?;
 
-        Ok(result.into())
+        Ok(category)
     }
 
     async fn update_by_id(
         &self,
         id: &i32,
         category_update_model: &CategoryUpdateModel,
+        actor: &str,
     ) -> Result<CategoryModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_CATEGORY_BY_ID).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_id_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_CATEGORY_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
                     id,
                     &category_update_model.name,
+                    &category_update_model.slug,
                     &category_update_model.description,
+                    &category_update_model.parent_id,
+                    &category_update_model.is_active,
                 ],
             )
             .await?;
+        let category: CategoryModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "category", *id, &before, &category),
+        )
+        .await?;
+        transaction.commit().await?;
 
-        Ok(result.into())
+        Ok(category)
     }
 
-    async fn delete_by_id(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_CATEGORY_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
+    async fn delete_by_id(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_id_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_CATEGORY_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "category", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
         Ok(())
     }
 }
@@ -190,7 +310,9 @@ impl From<&Row> for CategoryModel {
         Self {
             id: row.get("category_id"),
             name: row.get("category_name"),
+            slug: row.get("category_slug"),
             description: row.get("category_description"),
+            parent_id: row.get("category_parent_id"),
             is_active: row.get("category_is_active"),
             created_at: row.get("category_created_at"),
             updated_at: row.get("category_updated_at"),
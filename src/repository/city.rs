@@ -2,52 +2,68 @@ use std::sync::Arc;
 
 use redis::Client;
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{GenericClient, Pool};
 use redis::AsyncCommands;
 
 use tokio_postgres::{types::ToSql, Row};
 
-use crate::domain::{
+use serde::{Deserialize, Serialize};
+use crate::{domain::{
+    audit_log::model::AuditLogCreateModel,
     city::{
         model::{CityCreateModel, CityModel, CityUpdateModel, CityDetailModel},
         repository::CityRepository,
     },
     error::DomainError,
-};
+}, repository::{audit_log, geo, search}};
 
+// `d.distance_km` comes from the lateral join appended in `find`.
 const QUERY_FIND_CITY: &str = "
     select
-        cityid,
-        stateid,
-        name,
-        slug,
-        extid,
-        highres_link,
-        photo_link,
-        thumb_link,
-        created_at,
-        updated_at,
+        c.cityid,
+        c.stateid,
+        c.name,
+        c.slug,
+        c.extid,
+        c.highres_link,
+        c.photo_link,
+        c.thumb_link,
+        c.latitude,
+        c.longitude,
+        c.time_zone,
+        c.created_at,
+        c.updated_at,
+        s.name as state_name,
+        s.symbol as state_symbol,
+        d.distance_km,
         count(1) over ()::OID as count
     from
-        city";
+        city c
+    left join state s using(stateid)";
 
 const QUERY_FIND_CITY_BY_ID: &str = "
     select
-        cityid,
-        stateid,
-        name,
-        slug,
-        extid,
-        highres_link,
-        photo_link,
-        thumb_link,
-        created_at,
-        updated_at,
+        c.cityid,
+        c.stateid,
+        c.name,
+        c.slug,
+        c.extid,
+        c.highres_link,
+        c.photo_link,
+        c.thumb_link,
+        c.latitude,
+        c.longitude,
+        c.time_zone,
+        c.created_at,
+        c.updated_at,
+        s.name as state_name,
+        s.symbol as state_symbol,
         count(1) over ()::OID as count
     from
-        city
+        city c
+    left join state s using(stateid)
     where 
-        cityid = $1;";
+        c.cityid = $1;";
 
 const QUERY_FIND_CITY_BY_SLUG: &str = "
         select
@@ -65,45 +81,72 @@ const QUERY_FIND_CITY_BY_SLUG: &str = "
             slug = $1;";
             
 const QUERY_INSERT_CITY: &str = "
-    insert into city(stateid,name,slug,extid,highres_link,photo_link,thumb_link)
-    values
-        ($1,$2,$3,$4,$5,$6,$7)
-    returning
-        cityid,
-        stateid,
-        name,
-        slug,
-        extid,
-        highres_link,
-        photo_link,
-        thumb_link,
-        created_at,
-        updated_at;";
+    with c as (
+        insert into city(stateid,name,slug,extid,highres_link,photo_link,thumb_link,latitude,longitude,time_zone)
+        values
+            ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10)
+        returning
+            *
+    )
+    select
+        c.cityid,
+        c.stateid,
+        c.name,
+        c.slug,
+        c.extid,
+        c.highres_link,
+        c.photo_link,
+        c.thumb_link,
+        c.latitude,
+        c.longitude,
+        c.time_zone,
+        c.created_at,
+        c.updated_at,
+        s.name as state_name,
+        s.symbol as state_symbol
+    from
+        c
+    left join state s using(stateid);";
 
 const QUERY_UPDATE_CITY_BY_ID: &str = "
-    update
-        city 
-    set
-        name=$2,
-        slug=$3,
-        stateid=$4,
-        highres_link=$5,
-        photo_link=$6,
-        thumb_link=$7,
-        updated_at=now()
-    where
-        cityid = $1
-    returning
-        cityid,
-        stateid,
-        name,
-        slug,
-        extid,
-        highres_link,
-        photo_link,
-        thumb_link,
-        created_at,
-        updated_at;";
+    with c as (
+        update
+            city 
+        set
+            name=$2,
+            slug=$3,
+            stateid=$4,
+            highres_link=$5,
+            photo_link=$6,
+            thumb_link=$7,
+            latitude=$8,
+            longitude=$9,
+            time_zone=$10,
+            updated_at=now()
+        where
+            cityid = $1
+        returning
+            *
+    )
+    select
+        c.cityid,
+        c.stateid,
+        c.name,
+        c.slug,
+        c.extid,
+        c.highres_link,
+        c.photo_link,
+        c.thumb_link,
+        c.latitude,
+        c.longitude,
+        c.time_zone,
+        c.created_at,
+        c.updated_at,
+        s.name as state_name,
+        s.symbol as state_symbol
+    from
+        c
+    left join state s using(stateid);";
 
 const QUERY_DELETE_CITY_BY_ID: &str = "
             delete from
@@ -111,6 +154,14 @@ const QUERY_DELETE_CITY_BY_ID: &str = "
             where
                 cityid = $1;";
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum CitySortOption {
+    Name,
+    Groups,
+    Events,
+    Distance,
+}
+
 pub struct PgCityRepository {
     pool: Arc<Pool>,
     redis_client: Arc<Client>,
@@ -119,6 +170,19 @@ impl PgCityRepository {
     pub fn new(pool: Arc<Pool>,redis_client: Arc<Client>) -> Self {
         Self { pool,redis_client }
     }
+
+    async fn find_by_cityid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<CityModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_CITY_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            return Ok(Some((&result).into()));
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -126,6 +190,13 @@ impl CityRepository for PgCityRepository {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
+        stateid: &Option<i32>,
+        state_symbol: &Option<String>,
+        latitude: &Option<f64>,
+        longitude: &Option<f64>,
+        radius_km: &Option<f64>,
+        sort: &Option<CitySortOption>,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<CityModel>, u32)>, DomainError> {
@@ -134,21 +205,66 @@ impl CityRepository for PgCityRepository {
         let mut queries: Vec<String> = vec![];
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
 
+        let mut rank = None;
         if let Some(name) = name {
-            queries.push(format!(
-                "city.name like '%' || ${} || '%'",
-                params.len() + 1
-            ));
+            queries.push(search::name_matches("c.name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                rank = Some(search::name_rank("c.name", params.len() + 1));
+            }
             params.push(name);
         }
 
-        let mut query = String::from(QUERY_FIND_CITY);
+        if let Some(stateid) = stateid {
+            queries.push(format!("c.stateid = ${}", params.len() + 1));
+            params.push(stateid);
+        }
+
+        if let Some(state_symbol) = state_symbol {
+            queries.push(format!("lower(s.symbol) = lower(${})", params.len() + 1));
+            params.push(state_symbol);
+        }
+
+        let distance = match (latitude, longitude) {
+            (Some(latitude), Some(longitude)) => {
+                let distance = geo::distance_km(
+                    "c.latitude",
+                    "c.longitude",
+                    params.len() + 1,
+                    params.len() + 2,
+                );
+                params.push(latitude);
+                params.push(longitude);
+                distance
+            }
+            _ => "null::float8".to_string(),
+        };
+
+        if let Some(radius_km) = radius_km {
+            queries.push(format!("d.distance_km <= ${}", params.len() + 1));
+            params.push(radius_km);
+        }
+
+        let mut query = format!(
+            "{QUERY_FIND_CITY} cross join lateral (select {distance} as distance_km) d"
+        );
         if !queries.is_empty() {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
+        let order_by = match sort {
+            Some(CitySortOption::Groups) => {
+                "(select count(1) from \"group\" g where g.cityid = c.cityid) desc, c.name, c.cityid"
+            }
+            Some(CitySortOption::Events) => {
+                "(select count(1) from event e join \"group\" g using(groupid) where g.cityid = c.cityid and e.time >= now()) desc, c.name, c.cityid"
+            }
+            Some(CitySortOption::Distance) => "d.distance_km nulls last, c.name, c.cityid",
+            Some(CitySortOption::Name) | None => "c.name, c.cityid",
+        };
+        let order_by = rank.map_or(order_by.to_string(), |rank| format!("{rank}, {order_by}"));
+
         let offset = page_size * (page - 1);
-        query = format!("{query} limit {page_size} offset {offset}");
+        query = format!("{query} order by {order_by} limit {page_size} offset {offset}");
 
         let stmt = client.prepare(&query).await?;
         let result = client.query(&stmt, &params[..]).await?;
@@ -156,7 +272,14 @@ impl CityRepository for PgCityRepository {
         if !result.is_empty() {
             let count: u32 = result.first().unwrap().get("count");
 
-            let city_items: Vec<CityModel> = result.iter().map(|row| row.into()).collect();
+            let city_items: Vec<CityModel> = result
+                .iter()
+                .map(|row| {
+                    let mut city: CityModel = row.into();
+                    city.distance_km = row.get("distance_km");
+                    city
+                })
+                .collect();
 
             return Ok(Some((city_items, count)));
         }
@@ -166,13 +289,7 @@ impl CityRepository for PgCityRepository {
 
     async fn find_by_cityid(&self, id: &i32) -> Result<Option<CityModel>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_CITY_BY_ID).await?;
-
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
-            return Ok(Some((&result).into()));
-        }
-
-        return Ok(None);
+        Self::find_by_cityid_in(&client, id).await
     }
 
     async fn find_by_slug(&self, slug: String) -> Result<Option<CityDetailModel>, DomainError> {
@@ -201,10 +318,12 @@ impl CityRepository for PgCityRepository {
     async fn insert(
         &self,
         city_create_model: &CityCreateModel,
+        actor: &str,
     ) -> Result<CityModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_CITY).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_CITY).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -215,22 +334,36 @@ impl CityRepository for PgCityRepository {
                     &city_create_model.highres_link,
                     &city_create_model.photo_link,
                     &city_create_model.thumb_link,
+                    &city_create_model.latitude,
+                    &city_create_model.longitude,
+                    &city_create_model.time_zone,
                 ],
             )
             .await?;
+        let city: CityModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "city", city.cityid, &city),
+        )
+        .await?;
+        transaction.commit().await?;
 
-        Ok(result.into())
+        Ok(city)
     }
 
     async fn update_by_cityid(
         &self,
         cityid: &i32,
         city_update_model: &CityUpdateModel,
+        actor: &str,
     ) -> Result<CityModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_CITY_BY_ID).await?;
-        let result = &client
-        
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_cityid_in(&transaction, cityid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_CITY_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -241,17 +374,39 @@ impl CityRepository for PgCityRepository {
                     &city_update_model.highres_link,
                     &city_update_model.photo_link,
                     &city_update_model.thumb_link,
+                    &city_update_model.latitude,
+                    &city_update_model.longitude,
+                    &city_update_model.time_zone,
                 ],
             )
             .await?;
+        let city: CityModel = result.into();
 
-        Ok(result.into())
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "city", *cityid, &before, &city),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(city)
     }
 
-    async fn delete_by_cityid(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_CITY_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
+    async fn delete_by_cityid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_cityid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_CITY_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "city", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
         Ok(())
     }
 }
@@ -267,6 +422,12 @@ impl From<&Row> for CityModel {
             photo_link: row.get("photo_link"),
             thumb_link: row.get("thumb_link"),
             extid: row.get("extid"),
+            state_name: row.get("state_name"),
+            state_symbol: row.get("state_symbol"),
+            latitude: row.get("latitude"),
+            longitude: row.get("longitude"),
+            time_zone: row.get("time_zone"),
+            distance_km: None,
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         }
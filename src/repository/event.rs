@@ -1,20 +1,128 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use deadpool_postgres::Pool;
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use deadpool_postgres::{GenericClient, Pool};
 
 use serde::{Serialize, Deserialize};
 use tokio_postgres::{types::ToSql, Row};
 
-use crate::domain::{
+use crate::{domain::{
+    audit_log::model::AuditLogCreateModel,
+    categories::model::CategoryModel,
     event::{
-        model::{EventCreateModel, EventModel, EventUpdateModel, EventDetailModel},
+        model::{
+            EventCreateModel, EventDetailModel, EventFindFilter, EventLifecycleStatus,
+            EventLifecycleUpdateModel, EventModel, EventUpdateModel,
+        },
         repository::EventRepository,
     },
+    event_series::rrule::RRule,
     error::DomainError,
-};
+    upsert::{UpsertModel, UpsertOutcome},
+}, repository::{audit_log, geo, search}};
 
+/// Series are expanded at most this many days around now, or around the
+/// requested period when it is only bounded on one side.
+const SERIES_EXPANSION_DAYS: i64 = 366;
+/// Most occurrences expanded per series and request.
+const SERIES_EXPANSION_LIMIT: usize = 1000;
+
+// `events` holds the events outside any series and the expanded occurrences
+// of series, which take their columns from the series' event and from their
+// override, whose name and description are parsed again for full-text search.
+// `$1` to `$5` are the arrays built by `expand_series`.
+const QUERY_EVENTS_WITH_OCCURRENCES: &str = "
+    with events as (
+        select
+            e.eventid,
+            e.name,
+            e.description,
+            e.extid,
+            e.location,
+            e.latitude,
+            e.longitude,
+            e.groupid,
+            e.in_person,
+            e.time,
+            e.duration,
+            e.link,
+            e.waitlist_count,
+            e.is_online,
+            e.yes_rsvp_count,
+            e.fee,
+            e.price_min,
+            e.price_max,
+            e.currency,
+            e.ticket_url,
+            e.created_at,
+            e.updated_at,
+            e.highres_link,
+            e.photo_link,
+            e.thumb_link,
+            e.rsvp_limit,
+            e.lifecycle_status,
+            e.lifecycle_reason,
+            e.rescheduled_to,
+            e.search_en,
+            e.search_fr,
+            null::int4 as seriesid,
+            null::timestamptz as occurrence
+        from
+            event e
+        where
+            not exists(select 1 from event_series s where s.eventid = e.eventid)
+        union all
+        select
+            e.eventid,
+            coalesce(ov.name, e.name),
+            coalesce(ov.description, e.description),
+            e.extid,
+            coalesce(ov.location, e.location),
+            e.latitude,
+            e.longitude,
+            e.groupid,
+            e.in_person,
+            o.time,
+            o.duration,
+            e.link,
+            e.waitlist_count,
+            e.is_online,
+            e.yes_rsvp_count,
+            e.fee,
+            e.price_min,
+            e.price_max,
+            e.currency,
+            e.ticket_url,
+            e.created_at,
+            greatest(e.updated_at, ov.updated_at),
+            e.highres_link,
+            e.photo_link,
+            e.thumb_link,
+            e.rsvp_limit,
+            e.lifecycle_status,
+            e.lifecycle_reason,
+            e.rescheduled_to,
+            case when ov.seriesid is null then e.search_en else
+                setweight(to_tsvector('english', coalesce(ov.name, e.name)), 'A')
+                || setweight(to_tsvector('english', coalesce(ov.description, e.description, '')), 'B')
+            end,
+            case when ov.seriesid is null then e.search_fr else
+                setweight(to_tsvector('french', coalesce(ov.name, e.name)), 'A')
+                || setweight(to_tsvector('french', coalesce(ov.description, e.description, '')), 'B')
+            end,
+            o.seriesid,
+            o.occurrence
+        from
+            unnest($1::int4[], $2::int4[], $3::timestamptz[], $4::timestamptz[], $5::int4[])
+                as o(eventid, seriesid, occurrence, time, duration)
+        join event e using(eventid)
+        left join event_occurrence ov using(seriesid, occurrence)
+    )";
+
+// `d.distance_km`, `d.time_zone` and `d.snippet` come from the lateral join
+// appended in `find`.
 const QUERY_FIND_EVENT: &str = "
     select
         e.eventid,
@@ -22,8 +130,14 @@ const QUERY_FIND_EVENT: &str = "
         e.description,
         g.slug as group_slug,
         g.name as group_name,
+        ci.name as city_name,
+        ci.slug as city_slug,
+        st.name as state_name,
+        st.symbol as state_symbol,
         e.extid,
         e.location,
+        e.latitude,
+        e.longitude,
         e.groupid,
         e.in_person,
         e.time,
@@ -33,16 +147,30 @@ const QUERY_FIND_EVENT: &str = "
         e.is_online,
         e.yes_rsvp_count,
         e.fee,
+        e.price_min,
+        e.price_max,
+        e.currency,
+        e.ticket_url,
         e.created_at,
         e.updated_at,
         e.highres_link,
         e.photo_link,
         e.thumb_link,
         e.rsvp_limit,
+        e.lifecycle_status,
+        e.lifecycle_reason,
+        e.rescheduled_to,
+        e.seriesid,
+        e.occurrence,
+        d.distance_km,
+        d.time_zone,
+        d.snippet,
         count(1) over ()::OID as count
     from
-        event e
-    LEFT JOIN \"group\" g using(groupid)";
+        events e
+    LEFT JOIN \"group\" g using(groupid)
+    LEFT JOIN city ci on ci.cityid = g.cityid
+    LEFT JOIN state st on st.stateid = ci.stateid";
 
 const QUERY_FIND_EVENT_BY_ID: &str = "
     select
@@ -51,6 +179,8 @@ const QUERY_FIND_EVENT_BY_ID: &str = "
         e.description,
         e.extid,
         e.location,
+        e.latitude,
+        e.longitude,
         e.groupid,
         e.in_person,
         e.time,
@@ -60,28 +190,73 @@ const QUERY_FIND_EVENT_BY_ID: &str = "
         e.is_online,
         e.yes_rsvp_count,
         e.fee,
+        e.price_min,
+        e.price_max,
+        e.currency,
+        e.ticket_url,
         e.created_at,
         e.updated_at,
         e.highres_link,
         e.rsvp_limit,
         e.photo_link,
         e.thumb_link,
+        e.lifecycle_status,
+        e.lifecycle_reason,
+        e.rescheduled_to,
         count(1) over ()::OID as count
     from
         event e
     where 
         eventid = $1;";
 
+const QUERY_FIND_EVENT_BY_EXTID: &str = "
+    select
+        e.eventid,
+        e.name,
+        e.description,
+        e.extid,
+        e.location,
+        e.latitude,
+        e.longitude,
+        e.groupid,
+        e.in_person,
+        e.time,
+        e.duration,
+        e.link,
+        e.waitlist_count,
+        e.is_online,
+        e.yes_rsvp_count,
+        e.fee,
+        e.price_min,
+        e.price_max,
+        e.currency,
+        e.ticket_url,
+        e.created_at,
+        e.updated_at,
+        e.highres_link,
+        e.rsvp_limit,
+        e.photo_link,
+        e.thumb_link,
+        e.lifecycle_status,
+        e.lifecycle_reason,
+        e.rescheduled_to
+    from
+        event e
+    where 
+        extid = $1;";
+
 const QUERY_INSERT_EVENT: &str = "
-    insert into event(name,description,extid,location,groupid,in_person,time,duration,link,waitlist_count,is_online,yes_rsvp_count,fee,highres_link,photo_link,thumb_link,rsvp_limit)
+    insert into event(name,description,extid,location,groupid,in_person,time,duration,link,waitlist_count,is_online,yes_rsvp_count,fee,highres_link,photo_link,thumb_link,rsvp_limit,latitude,longitude,price_min,price_max,currency,ticket_url)
     values
-        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17)
+        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23)
     returning
         eventid,
         name,
         description,
         extid,
         location,
+        latitude,
+        longitude,
         groupid,
         in_person,
         time,
@@ -92,11 +267,18 @@ const QUERY_INSERT_EVENT: &str = "
         yes_rsvp_count,
         rsvp_limit,
         fee,
+        price_min,
+        price_max,
+        currency,
+        ticket_url,
         created_at,
         updated_at,
         highres_link,
         photo_link,
-        thumb_link;";
+        thumb_link,
+        lifecycle_status,
+        lifecycle_reason,
+        rescheduled_to;";
 
 const QUERY_UPDATE_EVENT_BY_ID: &str = "
     update
@@ -118,6 +300,165 @@ const QUERY_UPDATE_EVENT_BY_ID: &str = "
         photo_link=$15,
         thumb_link=$16,
         rsvp_limit=$17,
+        latitude=$18,
+        longitude=$19,
+        price_min=$20,
+        price_max=$21,
+        currency=$22,
+        ticket_url=$23,
+        updated_at=now()
+    where
+        eventid = $1
+    returning
+        eventid,
+        name,
+        description,
+        extid,
+        location,
+        latitude,
+        longitude,
+        groupid,
+        in_person,
+        time,
+        duration,
+        link,
+        waitlist_count,
+        is_online,
+        yes_rsvp_count,
+        rsvp_limit,
+        fee,
+        price_min,
+        price_max,
+        currency,
+        ticket_url,
+        created_at,
+        updated_at,
+        highres_link,
+        photo_link,
+        thumb_link,
+        lifecycle_status,
+        lifecycle_reason,
+        rescheduled_to;";
+
+// Only rewrites an existing event when a column or its categories differ, so
+// re-sent events keep their `updated_at`. `$24` holds the sorted category ids.
+const QUERY_UPSERT_EVENT_BY_EXTID: &str = "
+    insert into event(extid,name,description,location,groupid,in_person,time,duration,link,waitlist_count,is_online,yes_rsvp_count,fee,highres_link,photo_link,thumb_link,rsvp_limit,latitude,longitude,price_min,price_max,currency,ticket_url)
+    values
+        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23)
+    on conflict (extid) do update
+    set
+        name=excluded.name,
+        description=excluded.description,
+        location=excluded.location,
+        groupid=excluded.groupid,
+        in_person=excluded.in_person,
+        time=excluded.time,
+        duration=excluded.duration,
+        link=excluded.link,
+        waitlist_count=excluded.waitlist_count,
+        is_online=excluded.is_online,
+        yes_rsvp_count=excluded.yes_rsvp_count,
+        fee=excluded.fee,
+        highres_link=excluded.highres_link,
+        photo_link=excluded.photo_link,
+        thumb_link=excluded.thumb_link,
+        rsvp_limit=excluded.rsvp_limit,
+        latitude=excluded.latitude,
+        longitude=excluded.longitude,
+        price_min=excluded.price_min,
+        price_max=excluded.price_max,
+        currency=excluded.currency,
+        ticket_url=excluded.ticket_url,
+        updated_at=now()
+    where
+        (
+            event.name,
+            event.description,
+            event.location,
+            event.groupid,
+            event.in_person,
+            event.time,
+            event.duration,
+            event.link,
+            event.waitlist_count,
+            event.is_online,
+            event.yes_rsvp_count,
+            event.fee,
+            event.highres_link,
+            event.photo_link,
+            event.thumb_link,
+            event.rsvp_limit,
+            event.latitude,
+            event.longitude,
+            event.price_min,
+            event.price_max,
+            event.currency,
+            event.ticket_url
+        ) is distinct from (
+            excluded.name,
+            excluded.description,
+            excluded.location,
+            excluded.groupid,
+            excluded.in_person,
+            excluded.time,
+            excluded.duration,
+            excluded.link,
+            excluded.waitlist_count,
+            excluded.is_online,
+            excluded.yes_rsvp_count,
+            excluded.fee,
+            excluded.highres_link,
+            excluded.photo_link,
+            excluded.thumb_link,
+            excluded.rsvp_limit,
+            excluded.latitude,
+            excluded.longitude,
+            excluded.price_min,
+            excluded.price_max,
+            excluded.currency,
+            excluded.ticket_url
+        )
+        or array(select ec.categoryid from event_category ec where ec.eventid = event.eventid order by ec.categoryid) is distinct from $24::int4[]
+    returning
+        eventid,
+        name,
+        description,
+        extid,
+        location,
+        latitude,
+        longitude,
+        groupid,
+        in_person,
+        time,
+        duration,
+        link,
+        waitlist_count,
+        is_online,
+        yes_rsvp_count,
+        rsvp_limit,
+        fee,
+        price_min,
+        price_max,
+        currency,
+        ticket_url,
+        created_at,
+        updated_at,
+        highres_link,
+        photo_link,
+        thumb_link,
+        lifecycle_status,
+        lifecycle_reason,
+        rescheduled_to,
+        (xmax = 0) as inserted;";
+
+const QUERY_UPDATE_EVENT_LIFECYCLE_BY_ID: &str = "
+    update
+        event
+    set
+        lifecycle_status=$2,
+        lifecycle_reason=$3,
+        rescheduled_to=$4,
         updated_at=now()
     where
         eventid = $1
@@ -127,6 +468,8 @@ const QUERY_UPDATE_EVENT_BY_ID: &str = "
         description,
         extid,
         location,
+        latitude,
+        longitude,
         groupid,
         in_person,
         time,
@@ -137,69 +480,542 @@ const QUERY_UPDATE_EVENT_BY_ID: &str = "
         yes_rsvp_count,
         rsvp_limit,
         fee,
+        price_min,
+        price_max,
+        currency,
+        ticket_url,
         created_at,
         updated_at,
         highres_link,
         photo_link,
-        thumb_link;";
+        thumb_link,
+        lifecycle_status,
+        lifecycle_reason,
+        rescheduled_to;";
 
-const QUERY_DELETE_EVENT_BY_ID: &str = "
-            delete from
-                event 
-            where
-                eventid = $1;";
+const QUERY_FIND_CATEGORIES_BY_EVENTID: &str = "
+    select
+        c.id as category_id,
+        c.name as category_name,
+        c.slug as category_slug,
+        c.description as category_description,
+        c.parent_id as category_parent_id,
+        c.is_active as category_is_active,
+        c.created_at as category_created_at,
+        c.updated_at as category_updated_at
+    from
+        category c
+    join event_category ec on ec.categoryid = c.id
+    where
+        ec.eventid = $1
+    order by
+        c.id;";
+
+const QUERY_FIND_EVENT_SERIES_TO_EXPAND: &str = "
+    select
+        s.seriesid,
+        s.eventid,
+        s.rrule,
+        s.exdates,
+        s.time_zone,
+        e.time,
+        e.duration
+    from
+        event_series s
+    join event e using(eventid)
+    where
+        e.time <= $1;";
+
+const QUERY_FIND_EVENT_OCCURRENCES_TO_EXPAND: &str = "
+    select
+        seriesid,
+        occurrence,
+        cancelled,
+        time,
+        duration
+    from
+        event_occurrence
+    where
+        seriesid = any($1);";
+
+const QUERY_INSERT_EVENT_CATEGORIES: &str = "
+    insert into event_category(eventid,categoryid)
+    select
+        $1, unnest($2::int4[])
+    on conflict do nothing;";
+
+const QUERY_DELETE_EVENT_CATEGORIES_BY_EVENTID: &str = "
+    delete from
+        event_category
+        where
+            eventid = $1;";
+
+/// Source of the current time used by time based filters.
+pub type Clock = fn() -> DateTime<Utc>;
 
 pub struct PgEventRepository {
     pool: Arc<Pool>,
+    clock: Clock,
 }
 impl PgEventRepository {
     pub fn new(pool: Arc<Pool>) -> Self {
-        Self { pool }
+        Self { pool, clock: Utc::now }
+    }
+
+    #[cfg(test)]
+    pub fn with_clock(pool: Arc<Pool>, clock: Clock) -> Self {
+        Self { pool, clock }
+    }
+
+    async fn find_categories<C: GenericClient>(
+        client: &C,
+        eventid: &i32,
+    ) -> Result<Vec<CategoryModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_CATEGORIES_BY_EVENTID).await?;
+        let result = client.query(&stmt, &[eventid]).await?;
+
+        Ok(result.iter().map(|row| row.into()).collect())
+    }
+
+    async fn find_by_eventid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<EventModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_EVENT_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            let mut event: EventModel = (&result).into();
+            event.categories = Self::find_categories(client, &event.eventid).await?;
+            return Ok(Some(event));
+        }
+
+        Ok(None)
+    }
+
+    async fn find_by_extid<C: GenericClient>(
+        client: &C,
+        extid: &str,
+    ) -> Result<Option<EventModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_EVENT_BY_EXTID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&extid]).await? {
+            let mut event: EventModel = (&result).into();
+            event.categories = Self::find_categories(client, &event.eventid).await?;
+            return Ok(Some(event));
+        }
+
+        Ok(None)
+    }
+
+    /// Inserts or updates the event `extid` through `client`, which is
+    /// expected to be a transaction, and audits the change in it.
+    async fn upsert<C: GenericClient>(
+        client: &C,
+        extid: &str,
+        event_update_model: &EventUpdateModel,
+        actor: &str,
+    ) -> Result<UpsertModel<EventModel>, DomainError> {
+        let before = Self::find_by_extid(client, extid).await?;
+
+        let mut categoryids = event_update_model.categoryids.clone();
+        categoryids.sort_unstable();
+        categoryids.dedup();
+
+        let stmt = client.prepare(QUERY_UPSERT_EVENT_BY_EXTID).await?;
+        let result = client
+            .query_opt(
+                &stmt,
+                &[
+                    &extid,
+                    &event_update_model.name,
+                    &event_update_model.description,
+                    &event_update_model.location,
+                    &event_update_model.groupid,
+                    &event_update_model.in_person,
+                    &event_update_model.time,
+                    &event_update_model.duration,
+                    &event_update_model.link,
+                    &event_update_model.waitlist_count,
+                    &event_update_model.is_online,
+                    &event_update_model.yes_rsvp_count,
+                    &event_update_model.fee,
+                    &event_update_model.highres_link,
+                    &event_update_model.photo_link,
+                    &event_update_model.thumb_link,
+                    &event_update_model.rsvp_limit,
+                    &event_update_model.latitude,
+                    &event_update_model.longitude,
+                    &event_update_model.price_min,
+                    &event_update_model.price_max,
+                    &event_update_model.currency,
+                    &event_update_model.ticket_url,
+                    &categoryids,
+                ],
+            )
+            .await?;
+
+        let Some(row) = result else {
+            let record = Self::find_by_extid(client, extid)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(String::from("Event extid not found")))?;
+            return Ok(UpsertModel {
+                outcome: UpsertOutcome::Unchanged,
+                before,
+                record,
+            });
+        };
+
+        let outcome = if row.get("inserted") {
+            UpsertOutcome::Created
+        } else {
+            UpsertOutcome::Updated
+        };
+        let mut event: EventModel = (&row).into();
+
+        let stmt = client
+            .prepare(QUERY_DELETE_EVENT_CATEGORIES_BY_EVENTID)
+            .await?;
+        client.execute(&stmt, &[&event.eventid]).await?;
+
+        let stmt = client.prepare(QUERY_INSERT_EVENT_CATEGORIES).await?;
+        client
+            .execute(&stmt, &[&event.eventid, &categoryids])
+            .await?;
+
+        event.categories = Self::find_categories(client, &event.eventid).await?;
+
+        let upserted = UpsertModel {
+            outcome,
+            before,
+            record: event,
+        };
+        if let Some(audit_log) =
+            upserted.audit_log(actor.to_owned(), "event", upserted.record.eventid)
+        {
+            audit_log::record(client, &audit_log).await?;
+        }
+
+        Ok(upserted)
+    }
+
+    /// Expands every series into its occurrences overlapping `[from, to]`,
+    /// leaving out excluded dates and cancelled occurrences and moving
+    /// rescheduled ones.
+    async fn expand_series(
+        client: &tokio_postgres::Client,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<ExpandedOccurrences, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_EVENT_SERIES_TO_EXPAND).await?;
+        let series = client.query(&stmt, &[&to]).await?;
+        let seriesids: Vec<i32> = series.iter().map(|row| row.get("seriesid")).collect();
+
+        let stmt = client.prepare(QUERY_FIND_EVENT_OCCURRENCES_TO_EXPAND).await?;
+        let overrides = client.query(&stmt, &[&seriesids]).await?;
+
+        let mut expanded = ExpandedOccurrences::default();
+        for row in series.iter() {
+            let seriesid: i32 = row.get("seriesid");
+            let eventid: i32 = row.get("eventid");
+            let exdates: Vec<DateTime<Utc>> = row.get("exdates");
+            let time_zone: String = row.get("time_zone");
+            let time: DateTime<Utc> = row.get("time");
+            let duration: i32 = row.get("duration");
+
+            // Rules are validated when written, so one that fails to parse is skipped.
+            let rrule = match row.get::<_, String>("rrule").parse::<RRule>() {
+                Ok(rrule) => rrule,
+                Err(_) => continue,
+            };
+            let tz: Tz = time_zone.parse().unwrap_or(Tz::UTC);
+            let series_overrides: Vec<&Row> = overrides
+                .iter()
+                .filter(|row| row.get::<_, i32>("seriesid") == seriesid)
+                .collect();
+
+            let length = Duration::seconds(duration.into());
+            for occurrence in rrule.occurrences(time, tz, from - length, to, SERIES_EXPANSION_LIMIT) {
+                let overridden = series_overrides
+                    .iter()
+                    .any(|row| row.get::<_, DateTime<Utc>>("occurrence") == occurrence);
+                if !overridden && !exdates.contains(&occurrence) {
+                    expanded.push(eventid, seriesid, occurrence, occurrence, duration);
+                }
+            }
+
+            for row in series_overrides {
+                let occurrence: DateTime<Utc> = row.get("occurrence");
+                let cancelled: bool = row.get("cancelled");
+                if cancelled || exdates.contains(&occurrence) || !rrule.contains(time, tz, occurrence) {
+                    continue;
+                }
+
+                let start = row.get::<_, Option<DateTime<Utc>>>("time").unwrap_or(occurrence);
+                let duration = row.get::<_, Option<i32>>("duration").unwrap_or(duration);
+                if start <= to && start + Duration::seconds(duration.into()) >= from {
+                    expanded.push(eventid, seriesid, occurrence, start, duration);
+                }
+            }
+        }
+
+        Ok(expanded)
+    }
+}
+
+/// Occurrences passed to `QUERY_EVENTS_WITH_OCCURRENCES`, one array per column.
+#[derive(Default)]
+struct ExpandedOccurrences {
+    eventids: Vec<i32>,
+    seriesids: Vec<i32>,
+    occurrences: Vec<DateTime<Utc>>,
+    times: Vec<DateTime<Utc>>,
+    durations: Vec<i32>,
+}
+impl ExpandedOccurrences {
+    fn push(
+        &mut self,
+        eventid: i32,
+        seriesid: i32,
+        occurrence: DateTime<Utc>,
+        time: DateTime<Utc>,
+        duration: i32,
+    ) {
+        self.eventids.push(eventid);
+        self.seriesids.push(seriesid);
+        self.occurrences.push(occurrence);
+        self.times.push(time);
+        self.durations.push(duration);
     }
 }
+
 #[derive(Debug, Serialize, Deserialize,Clone)]
 pub enum EventStatusOption {
     Upcomming,
-    Recurrent,
+    /// Started and not yet finished.
+    #[serde(alias = "Recurrent")]
+    InProgress,
     Past,
 }
 
+/// Period of the events to return. Calendar periods follow the local
+/// calendar of the time zone they are evaluated in and include events that
+/// already started, weeks running from Monday to Sunday; rolling periods
+/// start at the current time.
 #[derive(Debug, Serialize, Deserialize,Clone)]
 pub enum DateRangeOption {
     Today,
+    /// The local day after today.
+    Tomorrow,
     ThisWeek,
+    /// Saturday and Sunday of the current week, including while they are under way.
+    ThisWeekend,
+    /// The Monday to Sunday week after the current one.
+    NextWeek,
+    /// From now until 7 days from now.
+    Next7Days,
+    /// From now until 30 days from now.
+    Next30Days,
     ThisMonth,
+    /// The calendar month after the current one.
+    NextMonth,
     Custom,
 }
 
+/// Boundaries of a relative time frame.
+enum Period {
+    /// The current local time truncated to `unit`, shifted by the `start`
+    /// and `end` intervals. The period lies within `reach_days` of now.
+    Local {
+        unit: &'static str,
+        start: &'static str,
+        end: &'static str,
+        reach_days: i64,
+    },
+    /// From now until `days` days from now.
+    Rolling { days: i64 },
+}
+
+impl DateRangeOption {
+    /// `None` for `Custom`, which is bounded by the requested dates.
+    fn period(&self) -> Option<Period> {
+        let local = |unit, start, end, reach_days| Some(Period::Local { unit, start, end, reach_days });
+        match self {
+            DateRangeOption::Today => local("day", "0 days", "1 day", 1),
+            DateRangeOption::Tomorrow => local("day", "1 day", "2 days", 2),
+            DateRangeOption::ThisWeek => local("week", "0 days", "7 days", 7),
+            DateRangeOption::ThisWeekend => local("week", "5 days", "7 days", 7),
+            DateRangeOption::NextWeek => local("week", "7 days", "14 days", 14),
+            DateRangeOption::ThisMonth => local("month", "0 months", "1 month", 31),
+            DateRangeOption::NextMonth => local("month", "1 month", "2 months", 62),
+            DateRangeOption::Next7Days => Some(Period::Rolling { days: 7 }),
+            DateRangeOption::Next30Days => Some(Period::Rolling { days: 30 }),
+            DateRangeOption::Custom => None,
+        }
+    }
+}
+
+/// Listing order of events; a leading `-` sorts descending. Ties are broken
+/// on `eventid` and then `occurrence`, as occurrences of a series share the
+/// event's id.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum EventSortOption {
+    #[serde(rename = "time")]
+    Time,
+    #[serde(rename = "-time")]
+    TimeDesc,
+    #[serde(rename = "yes_rsvp_count")]
+    YesRsvpCount,
+    #[serde(rename = "-yes_rsvp_count")]
+    YesRsvpCountDesc,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDesc,
+    /// Nearest to the searched point first.
+    #[serde(rename = "distance")]
+    Distance,
+}
+
+/// Defaults to chronological order, most recent first for past events.
+fn event_order_by(sort: &Option<EventSortOption>, status: &Option<EventStatusOption>) -> String {
+    let order_by = match (sort, status) {
+        (Some(EventSortOption::Time), _) => "e.time",
+        (Some(EventSortOption::TimeDesc), _) | (None, Some(EventStatusOption::Past)) => "e.time desc",
+        (Some(EventSortOption::YesRsvpCount), _) => "e.yes_rsvp_count",
+        (Some(EventSortOption::YesRsvpCountDesc), _) => "e.yes_rsvp_count desc",
+        (Some(EventSortOption::Name), _) => "e.name",
+        (Some(EventSortOption::NameDesc), _) => "e.name desc",
+        (Some(EventSortOption::Distance), _) => "d.distance_km nulls last",
+        (None, _) => "e.time",
+    };
+    format!("{order_by}, e.eventid, e.occurrence nulls first")
+}
+
+/// Widest interval the status and time frame filters can match, so series are
+/// only expanded where an occurrence may be returned; the filters themselves
+/// are applied to every occurrence by the query.
+fn expansion_window(
+    now: DateTime<Utc>,
+    status: &Option<EventStatusOption>,
+    time_frame: &Option<DateRangeOption>,
+    start_date: &Option<DateTime<Utc>>,
+    end_date: &Option<DateTime<Utc>>,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let horizon = Duration::days(SERIES_EXPANSION_DAYS);
+
+    let (mut from, mut to) = match status {
+        Some(EventStatusOption::Upcomming) => (Some(now), None),
+        Some(EventStatusOption::InProgress) => (Some(now), Some(now)),
+        Some(EventStatusOption::Past) => (None, Some(now)),
+        None => (None, None),
+    };
+
+    // The local unit holding now starts within a unit of it in any time zone.
+    let (frame_from, frame_to) = match time_frame.as_ref().map(|time_frame| (time_frame, time_frame.period())) {
+        Some((_, Some(Period::Local { reach_days, .. }))) => {
+            let reach = Duration::days(reach_days);
+            (Some(now - reach), Some(now + reach))
+        }
+        Some((_, Some(Period::Rolling { days }))) => (Some(now), Some(now + Duration::days(days))),
+        Some((DateRangeOption::Custom, _)) => (*start_date, *end_date),
+        _ => (None, None),
+    };
+    if let Some(frame_from) = frame_from {
+        from = Some(from.map_or(frame_from, |from| from.max(frame_from)));
+    }
+    if let Some(frame_to) = frame_to {
+        to = Some(to.map_or(frame_to, |to| to.min(frame_to)));
+    }
+
+    let to = to.unwrap_or_else(|| from.unwrap_or(now).max(now) + horizon);
+    let from = from.unwrap_or_else(|| to.min(now) - horizon);
+    (from, to)
+}
+
+/// Matches events starting between the `start` and `end` offsets from the
+/// `unit` (day, week or month) holding `now` as observed in `zone`, so "today"
+/// follows the local calendar rather than UTC. Offsets are added to local
+/// times, keeping days whole across daylight saving changes.
+fn local_period_query(zone: &str, now: &str, unit: &str, start: &str, end: &str) -> String {
+    let base = format!("date_trunc('{unit}', {now} at time zone {zone})");
+    format!(
+        "e.time >= (({base} + interval '{start}') at time zone {zone}) and e.time < (({base} + interval '{end}') at time zone {zone})"
+    )
+}
+
 #[async_trait]
 impl EventRepository for PgEventRepository {
     async fn find(
         &self,
-        name: &Option<String>,
-        in_person: &Option<bool>,
-        is_online: &Option<bool>,
-        group_slug: &Option<String>,
-        location: &Option<String>,
-        has_fee: &Option<bool>,
-        rsvp_limit: &Option<u32>,
-        status: &Option<EventStatusOption>,
-        time_frame: &Option<DateRangeOption>,
-        start_date: &Option<DateTime<Utc>>,
-        end_date: &Option<DateTime<Utc>>,
+        filter: &EventFindFilter,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<EventDetailModel>, u32)>, DomainError> {
+        let EventFindFilter {
+            name,
+            fuzzy,
+            q,
+            in_person,
+            is_online,
+            group_slug,
+            city_slug,
+            state_symbol,
+            location,
+            has_fee,
+            max_price,
+            rsvp_limit,
+            status,
+            lifecycle_status,
+            time_frame,
+            start_date,
+            end_date,
+            time_zone,
+            category,
+            latitude,
+            longitude,
+            radius_km,
+            sort,
+        } = filter;
         let client = self.pool.get().await?;
 
+        let now = (self.clock)();
+        let (from, to) = expansion_window(now, status, time_frame, start_date, end_date);
+        let expanded = Self::expand_series(&client, from, to).await?;
+        let lifecycle_status = lifecycle_status.map(|status| status.as_str());
+
         let mut queries: Vec<String> = vec![];
-        let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+        let mut params: Vec<&(dyn ToSql + Sync)> = vec![
+            &expanded.eventids,
+            &expanded.seriesids,
+            &expanded.occurrences,
+            &expanded.times,
+            &expanded.durations,
+        ];
+
+        // Time based filters compare against the clock's time, passed as `$6`
+        // when one of them is applied, rather than `now()`.
+        let compares_now = status.is_some()
+            || time_frame.as_ref().and_then(|time_frame| time_frame.period()).is_some();
+        if compares_now {
+            params.push(&now);
+        }
+        let now_param = "$6::timestamptz";
+
+        // Full-text matches rank before similar names.
+        let mut ranks: Vec<String> = vec![];
+        let mut snippet = String::from("null::text");
+        if let Some(q) = q {
+            queries.push(search::text_matches("e", params.len() + 1));
+            ranks.push(search::text_rank("e", params.len() + 1));
+            snippet = search::text_snippet("e", params.len() + 1);
+            params.push(q);
+        }
 
         if let Some(name) = name {
-            queries.push(format!(
-                "e.name like '%' || ${} || '%'",
-                params.len() + 1
-            ));
+            queries.push(search::name_matches("e.name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                ranks.push(search::name_rank("e.name", params.len() + 1));
+            }
             params.push(name);
         }
 
@@ -227,6 +1043,16 @@ impl EventRepository for PgEventRepository {
             params.push(group_slug);
         }
 
+        if let Some(city_slug) = city_slug {
+            queries.push(format!("ci.slug = ${}", params.len() + 1));
+            params.push(city_slug);
+        }
+
+        if let Some(state_symbol) = state_symbol {
+            queries.push(format!("lower(st.symbol) = lower(${})", params.len() + 1));
+            params.push(state_symbol);
+        }
+
         if let Some(location) = location {
             queries.push(format!(
                 "e.location = ${}",
@@ -243,6 +1069,16 @@ impl EventRepository for PgEventRepository {
             params.push(has_fee);
         }
 
+        // Events priced only through `fee` count as free when it is false and
+        // are left out otherwise, since their price is unknown.
+        if let Some(max_price) = max_price {
+            queries.push(format!(
+                "coalesce(e.price_min, case when e.fee then null else 0 end) <= ${}",
+                params.len() + 1
+            ));
+            params.push(max_price);
+        }
+
         if let Some(rsvp_limit) = rsvp_limit {
             queries.push(format!(
                 "e.rsvp_limit = ${}",
@@ -253,19 +1089,42 @@ impl EventRepository for PgEventRepository {
 
         if let Some(status) = status {
             let status_query = match status {
-                EventStatusOption::Upcomming => "e.time > NOW()",
-                EventStatusOption::Past => "(e.time + INTERVAL '1 second' * e.duration) <= NOW()",
-                EventStatusOption::Recurrent => "(e.time <= NOW() AND (e.time + INTERVAL '1 second' * e.duration) > NOW())",
+                EventStatusOption::Upcomming => format!("e.time > {now_param}"),
+                EventStatusOption::Past => format!("(e.time + INTERVAL '1 second' * e.duration) <= {now_param}"),
+                EventStatusOption::InProgress => format!("(e.time <= {now_param} AND (e.time + INTERVAL '1 second' * e.duration) > {now_param})"),
             };
-            queries.push(status_query.to_string());
+            queries.push(status_query);
         }
 
+        // Cancelled events stay reachable by id but are left out of listings
+        // unless asked for.
+        match &lifecycle_status {
+            Some(lifecycle_status) => {
+                queries.push(format!("e.lifecycle_status = ${}", params.len() + 1));
+                params.push(lifecycle_status);
+            }
+            None => queries.push("e.lifecycle_status <> 'cancelled'".to_string()),
+        }
+
+        // Time frames are evaluated in the requested zone, falling back to the
+        // zone of the event's city and then to UTC.
+        let zone = match time_zone {
+            Some(time_zone) => {
+                params.push(time_zone);
+                format!("${}::text", params.len())
+            }
+            None => "coalesce(ci.time_zone, 'UTC')".to_string(),
+        };
+
         if let Some(time_frame) = time_frame {
-            let time_frame_query = match time_frame {
-                DateRangeOption::Today => "e.time >= CURRENT_DATE AND e.time < CURRENT_DATE + INTERVAL '1 day'".to_string(),
-                DateRangeOption::ThisWeek => "e.time >= DATE_TRUNC('week', CURRENT_DATE) AND e.time < DATE_TRUNC('week', CURRENT_DATE) + INTERVAL '1 week'".to_string(),
-                DateRangeOption::ThisMonth => "e.time >= DATE_TRUNC('month', CURRENT_DATE) AND e.time < DATE_TRUNC('month', CURRENT_DATE) + INTERVAL '1 month'".to_string(),
-                DateRangeOption::Custom => {
+            let time_frame_query = match time_frame.period() {
+                Some(Period::Local { unit, start, end, .. }) => {
+                    local_period_query(&zone, now_param, unit, start, end)
+                }
+                Some(Period::Rolling { days }) => {
+                    format!("e.time >= {now_param} and e.time < {now_param} + interval '{days} days'")
+                }
+                None => {
                     let query_str = format!(
                         "e.time >= ${} AND e.time <= ${}",
                         params.len() + 1,
@@ -285,13 +1144,46 @@ impl EventRepository for PgEventRepository {
             }
         }
 
-        let mut query = String::from(QUERY_FIND_EVENT);
+        if let Some(category) = category {
+            queries.push(format!(
+                "e.eventid in (select eventid from event_category where categoryid = ${})",
+                params.len() + 1
+            ));
+            params.push(category);
+        }
+
+        let distance = match (latitude, longitude) {
+            (Some(latitude), Some(longitude)) => {
+                let distance = geo::distance_km(
+                    "e.latitude",
+                    "e.longitude",
+                    params.len() + 1,
+                    params.len() + 2,
+                );
+                params.push(latitude);
+                params.push(longitude);
+                distance
+            }
+            _ => "null::float8".to_string(),
+        };
+
+        if let Some(radius_km) = radius_km {
+            queries.push(format!("d.distance_km <= ${}", params.len() + 1));
+            params.push(radius_km);
+        }
+
+        let mut query = format!(
+            "{QUERY_EVENTS_WITH_OCCURRENCES} {QUERY_FIND_EVENT} cross join lateral (select {distance} as distance_km, {zone} as time_zone, {snippet} as snippet) d"
+        );
         if !queries.is_empty() {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
+        let order_by = event_order_by(sort, status);
+        ranks.push(order_by.to_string());
+        let order_by = ranks.join(", ");
         let offset = page_size * (page - 1);
-        query = format!("{query} limit {page_size} offset {offset}");
+        query = format!("{query} order by {order_by} limit {page_size} offset {offset}");
 
         let stmt = client.prepare(&query).await?;
         let result = client.query(&stmt, &params[..]).await?;
@@ -309,22 +1201,18 @@ impl EventRepository for PgEventRepository {
 
     async fn find_by_eventid(&self, id: &i32) -> Result<Option<EventModel>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_EVENT_BY_ID).await?;
-
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
-            return Ok(Some((&result).into()));
-        }
-
-        return Ok(None);
+        Self::find_by_eventid_in(&client, id).await
     }
 
     async fn insert(
         &self,
         event_create_model: &EventCreateModel,
+        actor: &str,
     ) -> Result<EventModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_EVENT).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_EVENT).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -345,22 +1233,46 @@ impl EventRepository for PgEventRepository {
                     &event_create_model.photo_link,
                     &event_create_model.thumb_link,
                     &event_create_model.rsvp_limit,
+                    &event_create_model.latitude,
+                    &event_create_model.longitude,
+                    &event_create_model.price_min,
+                    &event_create_model.price_max,
+                    &event_create_model.currency,
+                    &event_create_model.ticket_url,
                 ],
             )
             .await?;
 
-        Ok(result.into())
+        let mut event: EventModel = result.into();
+
+        let stmt = transaction.prepare(QUERY_INSERT_EVENT_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[&event.eventid, &event_create_model.categoryids])
+            .await?;
+        event.categories = Self::find_categories(&transaction, &event.eventid).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "event", event.eventid, &event),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event)
     }
 
     async fn update_by_eventid(
         &self,
         eventid: &i32,
         event_update_model: &EventUpdateModel,
+        actor: &str,
     ) -> Result<EventModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_EVENT_BY_ID).await?;
-        let result = &client
-        
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_eventid_in(&transaction, eventid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_EVENT_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -381,19 +1293,124 @@ impl EventRepository for PgEventRepository {
                     &event_update_model.photo_link,
                     &event_update_model.thumb_link,
                     &event_update_model.rsvp_limit,
+                    &event_update_model.latitude,
+                    &event_update_model.longitude,
+                    &event_update_model.price_min,
+                    &event_update_model.price_max,
+                    &event_update_model.currency,
+                    &event_update_model.ticket_url,
                 ],
             )
             .await?;
 
-        Ok(result.into())
+        let mut event: EventModel = result.into();
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_EVENT_CATEGORIES_BY_EVENTID)
+            .await?;
+        transaction.execute(&stmt, &[eventid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_EVENT_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[eventid, &event_update_model.categoryids])
+            .await?;
+        event.categories = Self::find_categories(&transaction, eventid).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "event", *eventid, &before, &event),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event)
     }
 
-    async fn delete_by_eventid(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_EVENT_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
-        Ok(())
+    async fn upsert_by_extid(
+        &self,
+        extid: &str,
+        event_update_model: &EventUpdateModel,
+        actor: &str,
+    ) -> Result<UpsertModel<EventModel>, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let upserted = Self::upsert(&transaction, extid, event_update_model, actor).await?;
+        transaction.commit().await?;
+
+        Ok(upserted)
+    }
+
+    async fn bulk_upsert_by_extid(
+        &self,
+        event_create_models: &[EventCreateModel],
+        actor: &str,
+    ) -> Result<Vec<Result<UpsertModel<EventModel>, DomainError>>, DomainError> {
+        let mut client = self.pool.get().await?;
+        let mut transaction = client.transaction().await?;
+
+        let mut results = Vec::with_capacity(event_create_models.len());
+        for event_create_model in event_create_models {
+            let savepoint = transaction.savepoint("event_bulk_upsert").await?;
+            let event_update_model = EventUpdateModel::from(event_create_model.clone());
+            match Self::upsert(&savepoint, &event_create_model.extid, &event_update_model, actor)
+                .await
+            {
+                Ok(upserted) => {
+                    savepoint.commit().await?;
+                    results.push(Ok(upserted));
+                }
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    results.push(Err(err));
+                }
+            }
+        }
+        transaction.commit().await?;
+
+        Ok(results)
     }
+
+    async fn update_lifecycle_by_eventid(
+        &self,
+        id: &i32,
+        event_lifecycle_update_model: &EventLifecycleUpdateModel,
+        actor: &str,
+    ) -> Result<EventModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_eventid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_EVENT_LIFECYCLE_BY_ID).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    id,
+                    &event_lifecycle_update_model.lifecycle_status.as_str(),
+                    &event_lifecycle_update_model.lifecycle_reason,
+                    &event_lifecycle_update_model.rescheduled_to,
+                ],
+            )
+            .await?;
+
+        let mut event: EventModel = result.into();
+        event.categories = Self::find_categories(&transaction, &event.eventid).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "event", *id, &before, &event),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event)
+    }
+}
+
+fn lifecycle_status(row: &Row) -> EventLifecycleStatus {
+    row.get::<_, &str>("lifecycle_status")
+        .parse()
+        .unwrap_or(EventLifecycleStatus::Scheduled)
 }
 
 impl From<&Row> for EventModel {
@@ -404,6 +1421,8 @@ impl From<&Row> for EventModel {
             description: row.get("description"),
             extid: row.get("extid"),
             location: row.get("location"),
+            latitude: row.get("latitude"),
+            longitude: row.get("longitude"),
             groupid: row.get("groupid"),
             in_person: row.get("in_person"),
             time: row.get("time"),
@@ -413,13 +1432,20 @@ impl From<&Row> for EventModel {
             is_online: row.get("is_online"),
             yes_rsvp_count: row.get("yes_rsvp_count"),
             fee: row.get("fee"),
+            price_min: row.get("price_min"),
+            price_max: row.get("price_max"),
+            currency: row.get("currency"),
+            ticket_url: row.get("ticket_url"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
             highres_link: row.get("highres_link"),
             photo_link: row.get("photo_link"),
             thumb_link: row.get("thumb_link"),
             rsvp_limit: row.get("rsvp_limit"),
-            
+            lifecycle_status: lifecycle_status(row),
+            lifecycle_reason: row.get("lifecycle_reason"),
+            rescheduled_to: row.get("rescheduled_to"),
+            categories: vec![],
         }
     }
 }
@@ -432,8 +1458,15 @@ impl From<&Row> for EventDetailModel {
             name: row.get("name"),
             description: row.get("description"),
             location: row.get("location"),
+            latitude: row.get("latitude"),
+            longitude: row.get("longitude"),
             group_name: row.get("group_name"),
             group_slug: row.get("group_slug"),
+            city_name: row.get("city_name"),
+            city_slug: row.get("city_slug"),
+            state_name: row.get("state_name"),
+            state_symbol: row.get("state_symbol"),
+            extid: row.get("extid"),
             in_person: row.get("in_person"),
             time: row.get("time"),
             duration: row.get("duration"),
@@ -442,13 +1475,166 @@ impl From<&Row> for EventDetailModel {
             is_online: row.get("is_online"),
             yes_rsvp_count: row.get("yes_rsvp_count"),
             fee: row.get("fee"),
+            price_min: row.get("price_min"),
+            price_max: row.get("price_max"),
+            currency: row.get("currency"),
+            ticket_url: row.get("ticket_url"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
             highres_link: row.get("highres_link"),
             photo_link: row.get("photo_link"),
             thumb_link: row.get("thumb_link"),
+            lifecycle_status: lifecycle_status(row),
+            lifecycle_reason: row.get("lifecycle_reason"),
+            rescheduled_to: row.get("rescheduled_to"),
+            distance_km: row.get("distance_km"),
+            snippet: row.get("snippet"),
+            time_zone: row.get("time_zone"),
+            seriesid: row.get("seriesid"),
+            occurrence: row.get("occurrence"),
             rsvp_limit: row.get("rsvp_limit"),
             
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        api::{tests::utils::get_pool, utils::random_string},
+        domain::event::{model::{EventCreateModel, EventFindFilter}, repository::EventRepository},
+    };
+
+    use super::{DateRangeOption, PgEventRepository};
+
+    // A Wednesday, four days before daylight saving time starts in Toronto.
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 6, 15, 0, 0).unwrap()
+    }
+
+    fn times() -> Vec<DateTime<Utc>> {
+        [
+            (3, 6, 10, 0),
+            (3, 6, 18, 0),
+            (3, 7, 9, 0),
+            (3, 9, 20, 0),
+            (3, 10, 23, 30),
+            (3, 11, 0, 0),
+            (3, 11, 4, 30),
+            (3, 13, 14, 0),
+            (3, 13, 16, 0),
+            (3, 20, 12, 0),
+            (4, 1, 0, 0),
+            (4, 4, 12, 0),
+            (4, 30, 23, 0),
+            (5, 1, 0, 0),
+        ]
+        .into_iter()
+        .map(|(month, day, hour, min)| {
+            Utc.with_ymd_and_hms(2024, month, day, hour, min, 0).unwrap()
+        })
+        .collect()
+    }
+
+    async fn seed() -> (PgEventRepository, String) {
+        let repository = PgEventRepository::with_clock(get_pool().await, now);
+        let name = random_string(10);
+
+        for time in times() {
+            let mut event_model = EventCreateModel::mock_default();
+            event_model.name = name.clone();
+            event_model.time = time;
+            repository.insert(&event_model, "test").await.unwrap();
+        }
+
+        (repository, name)
+    }
+
+    /// Indexes in `times` of the events found in `time_frame`.
+    async fn find(
+        repository: &PgEventRepository,
+        name: &str,
+        time_frame: DateRangeOption,
+        time_zone: &str,
+    ) -> Vec<usize> {
+        let times = times();
+        let filter = EventFindFilter {
+            name: Some(name.to_string()),
+            time_frame: Some(time_frame),
+            time_zone: Some(time_zone.to_string()),
+            ..Default::default()
+        };
+        let result = repository
+            .find(&filter, &1, &100)
+            .await
+            .unwrap();
+
+        result
+            .map(|(events, _)| events)
+            .unwrap_or_default()
+            .iter()
+            .map(|event| times.iter().position(|time| *time == event.time).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn it_should_find_events_in_calendar_time_frames() {
+        let (repository, name) = seed().await;
+
+        assert_eq!(find(&repository, &name, DateRangeOption::Today, "UTC").await, vec![0, 1]);
+        assert_eq!(find(&repository, &name, DateRangeOption::Tomorrow, "UTC").await, vec![2]);
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::ThisWeek, "UTC").await,
+            vec![0, 1, 2, 3, 4]
+        );
+        assert_eq!(find(&repository, &name, DateRangeOption::ThisWeekend, "UTC").await, vec![3, 4]);
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::NextWeek, "UTC").await,
+            vec![5, 6, 7, 8]
+        );
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::ThisMonth, "UTC").await,
+            (0..=9).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::NextMonth, "UTC").await,
+            vec![10, 11, 12]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_find_events_in_rolling_time_frames() {
+        let (repository, name) = seed().await;
+
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::Next7Days, "UTC").await,
+            (1..=7).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::Next30Days, "UTC").await,
+            (1..=11).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_follow_the_local_calendar_of_the_time_zone() {
+        let (repository, name) = seed().await;
+
+        // Already Thursday there.
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::Today, "Pacific/Kiritimati").await,
+            vec![0, 1, 2]
+        );
+        // The weekend ends at Monday midnight daylight time, an hour earlier in UTC.
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::ThisWeekend, "America/Toronto").await,
+            vec![3, 4, 5]
+        );
+        assert_eq!(
+            find(&repository, &name, DateRangeOption::NextMonth, "America/Toronto").await,
+            vec![11, 12, 13]
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use deadpool_postgres::{GenericClient, Pool};
+
+use tokio_postgres::Row;
+
+use crate::domain::{
+    audit_log::model::AuditLogCreateModel,
+    error::DomainError,
+    event_series::{
+        model::{
+            EventOccurrenceModel, EventOccurrenceUpsertModel, EventSeriesCreateModel,
+            EventSeriesModel, EventSeriesUpdateModel,
+        },
+        repository::EventSeriesRepository,
+    },
+};
+use crate::repository::audit_log;
+
+const QUERY_FIND_EVENT_SERIES_BY_ID: &str = "
+    select
+        s.seriesid,
+        s.eventid,
+        s.rrule,
+        s.exdates,
+        s.time_zone,
+        e.time,
+        s.created_at,
+        s.updated_at
+    from
+        event_series s
+    join event e using(eventid)
+    where
+        s.seriesid = $1;";
+
+const QUERY_FIND_EVENT_SERIES_BY_EVENTID: &str = "
+    select
+        s.seriesid,
+        s.eventid,
+        s.rrule,
+        s.exdates,
+        s.time_zone,
+        e.time,
+        s.created_at,
+        s.updated_at
+    from
+        event_series s
+    join event e using(eventid)
+    where
+        s.eventid = $1;";
+
+const QUERY_FIND_EVENT_OCCURRENCES_BY_SERIESID: &str = "
+    select
+        seriesid,
+        occurrence,
+        cancelled,
+        time,
+        duration,
+        name,
+        description,
+        location,
+        created_at,
+        updated_at
+    from
+        event_occurrence
+    where
+        seriesid = $1
+    order by
+        occurrence;";
+
+const QUERY_INSERT_EVENT_SERIES: &str = "
+    with s as (
+        insert into event_series(eventid,rrule,exdates,time_zone)
+        select
+            $1,
+            $2,
+            $3,
+            coalesce(
+                $4,
+                (select ci.time_zone from event e join \"group\" g using(groupid) join city ci on ci.cityid = g.cityid where e.eventid = $1),
+                'UTC'
+            )
+        returning
+            *
+    )
+    select
+        s.seriesid,
+        s.eventid,
+        s.rrule,
+        s.exdates,
+        s.time_zone,
+        e.time,
+        s.created_at,
+        s.updated_at
+    from
+        s
+    join event e using(eventid);";
+
+const QUERY_UPDATE_EVENT_SERIES_BY_ID: &str = "
+    with s as (
+        update
+            event_series
+        set
+            rrule=$2,
+            exdates=$3,
+            time_zone=$4,
+            updated_at=now()
+        where
+            seriesid = $1
+        returning
+            *
+    )
+    select
+        s.seriesid,
+        s.eventid,
+        s.rrule,
+        s.exdates,
+        s.time_zone,
+        e.time,
+        s.created_at,
+        s.updated_at
+    from
+        s
+    join event e using(eventid);";
+
+const QUERY_DELETE_EVENT_SERIES_BY_ID: &str = "
+    delete from
+        event_series
+    where
+        seriesid = $1;";
+
+const QUERY_UPSERT_EVENT_OCCURRENCE: &str = "
+    insert into event_occurrence(seriesid,occurrence,cancelled,time,duration,name,description,location)
+    values
+        ($1,$2,$3,$4,$5,$6,$7,$8)
+    on conflict (seriesid, occurrence) do update
+    set
+        cancelled=excluded.cancelled,
+        time=excluded.time,
+        duration=excluded.duration,
+        name=excluded.name,
+        description=excluded.description,
+        location=excluded.location,
+        updated_at=now()
+    returning
+        seriesid,
+        occurrence,
+        cancelled,
+        time,
+        duration,
+        name,
+        description,
+        location,
+        created_at,
+        updated_at;";
+
+pub struct PgEventSeriesRepository {
+    pool: Arc<Pool>,
+}
+impl PgEventSeriesRepository {
+    pub fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+
+    async fn find_occurrences<C: GenericClient>(
+        client: &C,
+        seriesid: &i32,
+    ) -> Result<Vec<EventOccurrenceModel>, DomainError> {
+        let stmt = client
+            .prepare(QUERY_FIND_EVENT_OCCURRENCES_BY_SERIESID)
+            .await?;
+        let result = client.query(&stmt, &[seriesid]).await?;
+
+        Ok(result.iter().map(|row| row.into()).collect())
+    }
+
+    async fn find_by_seriesid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<EventSeriesModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_EVENT_SERIES_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            let mut event_series: EventSeriesModel = (&result).into();
+            event_series.occurrences = Self::find_occurrences(client, id).await?;
+            return Ok(Some(event_series));
+        }
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl EventSeriesRepository for PgEventSeriesRepository {
+    async fn find_by_seriesid(&self, id: &i32) -> Result<Option<EventSeriesModel>, DomainError> {
+        let client = self.pool.get().await?;
+        Self::find_by_seriesid_in(&client, id).await
+    }
+
+    async fn find_by_eventid(
+        &self,
+        eventid: &i32,
+    ) -> Result<Option<EventSeriesModel>, DomainError> {
+        let client = self.pool.get().await?;
+        let stmt = client.prepare(QUERY_FIND_EVENT_SERIES_BY_EVENTID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[eventid]).await? {
+            let mut event_series: EventSeriesModel = (&result).into();
+            event_series.occurrences =
+                Self::find_occurrences(&client, &event_series.seriesid).await?;
+            return Ok(Some(event_series));
+        }
+
+        return Ok(None);
+    }
+
+    async fn insert(
+        &self,
+        event_series_create_model: &EventSeriesCreateModel,
+        actor: &str,
+    ) -> Result<EventSeriesModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_EVENT_SERIES).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    &event_series_create_model.eventid,
+                    &event_series_create_model.rrule,
+                    &event_series_create_model.exdates,
+                    &event_series_create_model.time_zone,
+                ],
+            )
+            .await?;
+        let event_series: EventSeriesModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(
+                actor.to_owned(),
+                "event_series",
+                event_series.seriesid,
+                &event_series,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event_series)
+    }
+
+    async fn update_by_seriesid(
+        &self,
+        id: &i32,
+        event_series_update_model: &EventSeriesUpdateModel,
+        actor: &str,
+    ) -> Result<EventSeriesModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_seriesid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_EVENT_SERIES_BY_ID).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    id,
+                    &event_series_update_model.rrule,
+                    &event_series_update_model.exdates,
+                    &event_series_update_model.time_zone,
+                ],
+            )
+            .await?;
+
+        let mut event_series: EventSeriesModel = result.into();
+        event_series.occurrences = Self::find_occurrences(&transaction, id).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(
+                actor.to_owned(),
+                "event_series",
+                *id,
+                &before,
+                &event_series,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event_series)
+    }
+
+    async fn delete_by_seriesid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_seriesid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_EVENT_SERIES_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "event_series", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn upsert_occurrence(
+        &self,
+        id: &i32,
+        event_occurrence_upsert_model: &EventOccurrenceUpsertModel,
+        actor: &str,
+    ) -> Result<EventOccurrenceModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let previous = Self::find_occurrences(&transaction, id)
+            .await?
+            .into_iter()
+            .find(|occurrence| occurrence.occurrence == event_occurrence_upsert_model.occurrence);
+
+        let stmt = transaction.prepare(QUERY_UPSERT_EVENT_OCCURRENCE).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    id,
+                    &event_occurrence_upsert_model.occurrence,
+                    &event_occurrence_upsert_model.cancelled,
+                    &event_occurrence_upsert_model.time,
+                    &event_occurrence_upsert_model.duration,
+                    &event_occurrence_upsert_model.name,
+                    &event_occurrence_upsert_model.description,
+                    &event_occurrence_upsert_model.location,
+                ],
+            )
+            .await?;
+        let event_occurrence: EventOccurrenceModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(
+                actor.to_owned(),
+                "event_series",
+                *id,
+                &previous,
+                &event_occurrence,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(event_occurrence)
+    }
+}
+
+impl From<&Row> for EventSeriesModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            seriesid: row.get("seriesid"),
+            eventid: row.get("eventid"),
+            rrule: row.get("rrule"),
+            exdates: row.get("exdates"),
+            time_zone: row.get("time_zone"),
+            time: row.get("time"),
+            occurrences: vec![],
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
+
+impl From<&Row> for EventOccurrenceModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            seriesid: row.get("seriesid"),
+            occurrence: row.get("occurrence"),
+            cancelled: row.get("cancelled"),
+            time: row.get("time"),
+            duration: row.get("duration"),
+            name: row.get("name"),
+            description: row.get("description"),
+            location: row.get("location"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/// Mean Earth radius used by the haversine formula.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Builds a haversine great-circle distance expression, in kilometres, between
+/// the given coordinate columns and the point bound to the given parameters.
+/// Evaluates to null when the row has no coordinates.
+pub fn distance_km(
+    latitude_column: &str,
+    longitude_column: &str,
+    latitude_param: usize,
+    longitude_param: usize,
+) -> String {
+    format!(
+        "{EARTH_RADIUS_KM} * 2 * asin(least(1, sqrt(
+            power(sin(radians({latitude_column} - ${latitude_param}::float8) / 2), 2)
+            + cos(radians(${latitude_param}::float8)) * cos(radians({latitude_column}))
+            * power(sin(radians({longitude_column} - ${longitude_param}::float8) / 2), 2)
+        )))"
+    )
+}
@@ -1,16 +1,22 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{GenericClient, Pool};
+use serde::{Deserialize, Serialize};
 
 use tokio_postgres::{types::ToSql, Row};
 
+use crate::repository::{audit_log, search};
 use crate::domain::{
+    audit_log::model::AuditLogCreateModel,
+    categories::model::CategoryModel,
     group::{
         model::{GroupCreateModel, GroupModel, GroupUpdateModel, GroupPageModel},
         repository::GroupRepository,
     },
     error::DomainError,
+    organizer::model::OrganizerModel,
+    upsert::{UpsertModel, UpsertOutcome},
 };
 
 const QUERY_FIND_GROUP: &str = "
@@ -21,7 +27,12 @@ const QUERY_FIND_GROUP: &str = "
         g.photo_link as group_photo_link,
         g.thumb_link as group_thumb_link,
         g.highres_link as group_highres_link,
-        g.organizer,
+        coalesce((
+            SELECT string_agg(o.firstname || ' ' || o.lastname, ', ' ORDER BY o.organizerid)
+            FROM group_organizer gor
+            JOIN organizer o USING (organizerid)
+            WHERE gor.groupid = g.groupid
+        ), '') AS organizer,
         g.groupid,
         c.slug AS city_slug,
         c.name AS city_name,
@@ -34,6 +45,9 @@ const QUERY_FIND_GROUP: &str = "
     LEFT JOIN state s USING (stateid)
     LEFT JOIN \"event\" e ON g.groupid = e.groupid";
 
+const QUERY_FIND_GROUP_GROUP_BY: &str = "
+    GROUP BY g.name,g.members,g.slug,g.photo_link,g.thumb_link,g.highres_link,g.groupid,c.slug,c.name,s.symbol";
+
 const QUERY_FIND_GROUP_BY_ID: &str = "
     select
         groupid,
@@ -44,7 +58,6 @@ const QUERY_FIND_GROUP_BY_ID: &str = "
         private,
         members,
         cityid,
-        organizer,
         created_at,
         updated_at,
         highres_link,
@@ -57,6 +70,27 @@ const QUERY_FIND_GROUP_BY_ID: &str = "
     where 
         groupid = $1;";
 
+const QUERY_FIND_GROUP_BY_EXTID: &str = "
+    select
+        groupid,
+        name,
+        description,
+        extid,
+        slug,
+        private,
+        members,
+        cityid,
+        created_at,
+        updated_at,
+        highres_link,
+        photo_link,
+        thumb_link,
+        active
+    from
+        \"group\"
+    where 
+        extid = $1;";
+
 const QUERY_FIND_GROUP_BY_SLUG: &str = "
 select
         groupid,
@@ -67,7 +101,6 @@ select
         private,
         members,
         cityid,
-        organizer,
         created_at,
         updated_at,
         highres_link,
@@ -81,9 +114,9 @@ select
         \"group\".slug =  $1;";
 
 const QUERY_INSERT_GROUP: &str = "
-    insert into \"group\"(name,description,extid,slug,private,members,cityid,organizer,highres_link,photo_link,thumb_link,active)
+    insert into \"group\"(name,description,extid,slug,private,members,cityid,highres_link,photo_link,thumb_link,active)
     values
-        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12)
+        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11)
     returning
         groupid,
         name,
@@ -93,7 +126,6 @@ const QUERY_INSERT_GROUP: &str = "
         private,
         members,
         cityid,
-        organizer,
         created_at,
         updated_at,
         highres_link,
@@ -111,11 +143,10 @@ const QUERY_UPDATE_GROUP_BY_ID: &str = "
         private=$5,
         members=$6,
         cityid=$7,
-        organizer=$8,
-        highres_link=$9,
-        photo_link=$10,
-        thumb_link=$11,
-        active=$12,
+        highres_link=$8,
+        photo_link=$9,
+        thumb_link=$10,
+        active=$11,
         updated_at=now()
     where
         groupid = $1
@@ -128,7 +159,6 @@ const QUERY_UPDATE_GROUP_BY_ID: &str = "
         private,
         members,
         cityid,
-        organizer,
         created_at,
         updated_at,
         highres_link,
@@ -136,12 +166,166 @@ const QUERY_UPDATE_GROUP_BY_ID: &str = "
         thumb_link, 
         active;";
 
+// Only rewrites an existing group when a column, its organizers or its
+// categories differ, so re-sent groups keep their `updated_at`. `$12` and
+// `$13` hold the sorted organizer and category ids.
+const QUERY_UPSERT_GROUP_BY_EXTID: &str = "
+    insert into \"group\"(extid,name,description,slug,private,members,cityid,highres_link,photo_link,thumb_link,active)
+    values
+        ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11)
+    on conflict (extid) do update
+    set
+        name=excluded.name,
+        description=excluded.description,
+        slug=excluded.slug,
+        private=excluded.private,
+        members=excluded.members,
+        cityid=excluded.cityid,
+        highres_link=excluded.highres_link,
+        photo_link=excluded.photo_link,
+        thumb_link=excluded.thumb_link,
+        active=excluded.active,
+        updated_at=now()
+    where
+        (
+            \"group\".name,
+            \"group\".description,
+            \"group\".slug,
+            \"group\".private,
+            \"group\".members,
+            \"group\".cityid,
+            \"group\".highres_link,
+            \"group\".photo_link,
+            \"group\".thumb_link,
+            \"group\".active
+        ) is distinct from (
+            excluded.name,
+            excluded.description,
+            excluded.slug,
+            excluded.private,
+            excluded.members,
+            excluded.cityid,
+            excluded.highres_link,
+            excluded.photo_link,
+            excluded.thumb_link,
+            excluded.active
+        )
+        or array(select gor.organizerid from group_organizer gor where gor.groupid = \"group\".groupid order by gor.organizerid) is distinct from $12::int4[]
+        or array(select gc.categoryid from group_category gc where gc.groupid = \"group\".groupid order by gc.categoryid) is distinct from $13::int4[]
+    returning
+        groupid,
+        name,
+        description,
+        extid,
+        slug,
+        private,
+        members,
+        cityid,
+        created_at,
+        updated_at,
+        highres_link,
+        photo_link,
+        thumb_link,
+        active,
+        (xmax = 0) as inserted;";
+
 const QUERY_DELETE_GROUP_BY_ID: &str = "
     delete from
         \"group\" 
         where
             groupid = $1;";
 
+const QUERY_FIND_ORGANIZERS_BY_GROUPID: &str = "
+    select
+        o.organizerid,
+        o.firstname,
+        o.lastname,
+        o.bio,
+        o.extid,
+        o.highres_link,
+        o.photo_link,
+        o.thumb_link,
+        o.created_at,
+        o.updated_at
+    from
+        organizer o
+    join group_organizer gor using (organizerid)
+    where
+        gor.groupid = $1
+    order by
+        o.organizerid;";
+
+const QUERY_INSERT_GROUP_ORGANIZERS: &str = "
+    insert into group_organizer(groupid,organizerid)
+    select
+        $1, unnest($2::int4[])
+    on conflict do nothing;";
+
+const QUERY_DELETE_GROUP_ORGANIZERS_BY_GROUPID: &str = "
+    delete from
+        group_organizer
+        where
+            groupid = $1;";
+
+const QUERY_FIND_CATEGORIES_BY_GROUPID: &str = "
+    select
+        c.id as category_id,
+        c.name as category_name,
+        c.slug as category_slug,
+        c.description as category_description,
+        c.parent_id as category_parent_id,
+        c.is_active as category_is_active,
+        c.created_at as category_created_at,
+        c.updated_at as category_updated_at
+    from
+        category c
+    join group_category gc on gc.categoryid = c.id
+    where
+        gc.groupid = $1
+    order by
+        c.id;";
+
+const QUERY_INSERT_GROUP_CATEGORIES: &str = "
+    insert into group_category(groupid,categoryid)
+    select
+        $1, unnest($2::int4[])
+    on conflict do nothing;";
+
+const QUERY_DELETE_GROUP_CATEGORIES_BY_GROUPID: &str = "
+    delete from
+        group_category
+        where
+            groupid = $1;";
+
+/// Listing order of groups; a leading `-` sorts descending. Ties are broken
+/// on `groupid` so pages never overlap.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum GroupSortOption {
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDesc,
+    #[serde(rename = "members")]
+    Members,
+    #[serde(rename = "-members")]
+    MembersDesc,
+    #[serde(rename = "event_count")]
+    EventCount,
+    #[serde(rename = "-event_count")]
+    EventCountDesc,
+}
+
+fn group_order_by(sort: &Option<GroupSortOption>) -> &'static str {
+    match sort {
+        Some(GroupSortOption::Name) | None => "g.name, g.groupid",
+        Some(GroupSortOption::NameDesc) => "g.name desc, g.groupid",
+        Some(GroupSortOption::Members) => "g.members, g.groupid",
+        Some(GroupSortOption::MembersDesc) => "g.members desc, g.groupid",
+        Some(GroupSortOption::EventCount) => "event_count, g.groupid",
+        Some(GroupSortOption::EventCountDesc) => "event_count desc, g.groupid",
+    }
+}
+
 pub struct PgGroupRepository {
     pool: Arc<Pool>,
 }
@@ -149,6 +333,58 @@ impl PgGroupRepository {
     pub fn new(pool: Arc<Pool>) -> Self {
         Self { pool }
     }
+
+    async fn find_organizers<C: GenericClient>(
+        client: &C,
+        groupid: &i32,
+    ) -> Result<Vec<OrganizerModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_ORGANIZERS_BY_GROUPID).await?;
+        let result = client.query(&stmt, &[groupid]).await?;
+
+        Ok(result.iter().map(|row| row.into()).collect())
+    }
+
+    async fn find_by_extid<C: GenericClient>(
+        client: &C,
+        extid: &str,
+    ) -> Result<Option<GroupModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_GROUP_BY_EXTID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&extid]).await? {
+            let mut group: GroupModel = (&result).into();
+            group.organizers = Self::find_organizers(client, &group.groupid).await?;
+            group.categories = Self::find_categories(client, &group.groupid).await?;
+            return Ok(Some(group));
+        }
+
+        Ok(None)
+    }
+
+    async fn find_categories<C: GenericClient>(
+        client: &C,
+        groupid: &i32,
+    ) -> Result<Vec<CategoryModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_CATEGORIES_BY_GROUPID).await?;
+        let result = client.query(&stmt, &[groupid]).await?;
+
+        Ok(result.iter().map(|row| row.into()).collect())
+    }
+
+    async fn find_by_groupid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<GroupModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_GROUP_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            let mut group: GroupModel = (&result).into();
+            group.organizers = Self::find_organizers(client, &group.groupid).await?;
+            group.categories = Self::find_categories(client, &group.groupid).await?;
+            return Ok(Some(group));
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait]
@@ -156,7 +392,10 @@ impl GroupRepository for PgGroupRepository {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
         city: &Option<String>,
+        category: &Option<i32>,
+        sort: &Option<GroupSortOption>,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError> {
@@ -165,11 +404,12 @@ impl GroupRepository for PgGroupRepository {
         let mut queries: Vec<String> = vec![];
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
 
+        let mut rank = None;
         if let Some(name) = name {
-            queries.push(format!(
-                "\"g\".name like '%' || ${} || '%'",
-                params.len() + 1
-            ));
+            queries.push(search::name_matches("\"g\".name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                rank = Some(search::name_rank("\"g\".name", params.len() + 1));
+            }
             params.push(name);
         }
         
@@ -180,14 +420,25 @@ impl GroupRepository for PgGroupRepository {
             ));
             params.push(city);
         }
+
+        if let Some(category) = category {
+            queries.push(format!(
+                "g.groupid in (select groupid from group_category where categoryid = ${})",
+                params.len() + 1
+            ));
+            params.push(category);
+        }
         let mut query = String::from(QUERY_FIND_GROUP);
         if !queries.is_empty() {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
-        query = format!("{query} GROUP BY g.name,g.members,g.slug,g.photo_link,g.thumb_link,g.highres_link,g.organizer,g.groupid,c.slug,c.name,s.symbol");
+        let order_by = group_order_by(sort);
+        let order_by = rank.map_or(order_by.to_string(), |rank| format!("{rank}, {order_by}"));
         let offset = page_size * (page - 1);
-        query = format!("{query} limit {page_size} offset {offset}");
+        query = format!(
+            "{query} {QUERY_FIND_GROUP_GROUP_BY} order by {order_by} limit {page_size} offset {offset}"
+        );
 
         let stmt = client.prepare(&query).await?;
         let result = client.query(&stmt, &params[..]).await?;
@@ -203,23 +454,47 @@ impl GroupRepository for PgGroupRepository {
         return Ok(None);
     }
 
-    async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError> {
+    async fn find_by_organizerid(
+        &self,
+        organizerid: &i32,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<GroupPageModel>, u32)>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_GROUP_BY_ID).await?;
 
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
-            return Ok(Some((&result).into()));
+        let offset = page_size * (page - 1);
+        let query = format!(
+            "{QUERY_FIND_GROUP} where g.groupid in (select groupid from group_organizer where organizerid = $1) {QUERY_FIND_GROUP_GROUP_BY} order by g.name, g.groupid limit {page_size} offset {offset}"
+        );
+
+        let stmt = client.prepare(&query).await?;
+        let result = client.query(&stmt, &[organizerid]).await?;
+
+        if !result.is_empty() {
+            let count: u32 = result.first().unwrap().get("count");
+
+            let groups: Vec<GroupPageModel> = result.iter().map(|row| row.into()).collect();
+
+            return Ok(Some((groups, count)));
         }
 
         return Ok(None);
     }
 
+    async fn find_by_groupid(&self, id: &i32) -> Result<Option<GroupModel>, DomainError> {
+        let client = self.pool.get().await?;
+        Self::find_by_groupid_in(&client, id).await
+    }
+
     async fn find_by_slug(&self, slug: String) -> Result<Option<GroupModel>, DomainError> {
         let client = self.pool.get().await?;
         let stmt = client.prepare(QUERY_FIND_GROUP_BY_SLUG).await?;
 
         if let Some(result) = client.query_opt(&stmt, &[&slug]).await? {
-            return Ok(Some((&result).into()));
+            let mut group: GroupModel = (&result).into();
+            group.organizers = Self::find_organizers(&client, &group.groupid).await?;
+            group.categories = Self::find_categories(&client, &group.groupid).await?;
+            return Ok(Some(group));
         }
 
         return Ok(None);
@@ -228,10 +503,12 @@ impl GroupRepository for PgGroupRepository {
     async fn insert(
         &self,
         group_create_model: &GroupCreateModel,
+        actor: &str,
     ) -> Result<GroupModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_GROUP).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -242,7 +519,6 @@ impl GroupRepository for PgGroupRepository {
                     &group_create_model.private,
                     &group_create_model.members,
                     &group_create_model.cityid,
-                    &group_create_model.organizer,
                     &group_create_model.highres_link,
                     &group_create_model.photo_link,
                     &group_create_model.thumb_link,
@@ -250,18 +526,44 @@ impl GroupRepository for PgGroupRepository {
                 ],
             )
             .await?;
-        Ok(result.into())
+
+        let mut group: GroupModel = result.into();
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_ORGANIZERS).await?;
+        transaction
+            .execute(&stmt, &[&group.groupid, &group_create_model.organizerids])
+            .await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[&group.groupid, &group_create_model.categoryids])
+            .await?;
+
+        group.organizers = Self::find_organizers(&transaction, &group.groupid).await?;
+        group.categories = Self::find_categories(&transaction, &group.groupid).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "group", group.groupid, &group),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(group)
     }
 
     async fn update_by_groupid(
         &self,
         groupid: &i32,
         group_update_model: &GroupUpdateModel,
+        actor: &str,
     ) -> Result<GroupModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_GROUP_BY_ID).await?;
-        let result = &client
-        
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_groupid_in(&transaction, groupid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_GROUP_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -272,23 +574,161 @@ impl GroupRepository for PgGroupRepository {
                     &group_update_model.private,
                     &group_update_model.members,
                     &group_update_model.cityid,
-                    &group_update_model.organizer,
                     &group_update_model.highres_link,
                     &group_update_model.photo_link,
                     &group_update_model.thumb_link,
                     &group_update_model.active,
-                   
                 ],
             )
             .await?;
 
-        Ok(result.into())
+        let mut group: GroupModel = result.into();
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_GROUP_ORGANIZERS_BY_GROUPID)
+            .await?;
+        transaction.execute(&stmt, &[groupid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_ORGANIZERS).await?;
+        transaction
+            .execute(&stmt, &[groupid, &group_update_model.organizerids])
+            .await?;
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_GROUP_CATEGORIES_BY_GROUPID)
+            .await?;
+        transaction.execute(&stmt, &[groupid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[groupid, &group_update_model.categoryids])
+            .await?;
+
+        group.organizers = Self::find_organizers(&transaction, groupid).await?;
+        group.categories = Self::find_categories(&transaction, groupid).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(actor.to_owned(), "group", *groupid, &before, &group),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(group)
+    }
+
+    async fn upsert_by_extid(
+        &self,
+        extid: &str,
+        group_update_model: &GroupUpdateModel,
+        actor: &str,
+    ) -> Result<UpsertModel<GroupModel>, DomainError> {
+        let mut client = self.pool.get().await?;
+
+        let mut organizerids = group_update_model.organizerids.clone();
+        organizerids.sort_unstable();
+        organizerids.dedup();
+        let mut categoryids = group_update_model.categoryids.clone();
+        categoryids.sort_unstable();
+        categoryids.dedup();
+
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_extid(&transaction, extid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPSERT_GROUP_BY_EXTID).await?;
+        let result = transaction
+            .query_opt(
+                &stmt,
+                &[
+                    &extid,
+                    &group_update_model.name,
+                    &group_update_model.description,
+                    &group_update_model.slug,
+                    &group_update_model.private,
+                    &group_update_model.members,
+                    &group_update_model.cityid,
+                    &group_update_model.highres_link,
+                    &group_update_model.photo_link,
+                    &group_update_model.thumb_link,
+                    &group_update_model.active,
+                    &organizerids,
+                    &categoryids,
+                ],
+            )
+            .await?;
+
+        let Some(row) = result else {
+            let record = Self::find_by_extid(&transaction, extid)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(String::from("Group extid not found")))?;
+            transaction.commit().await?;
+            return Ok(UpsertModel {
+                outcome: UpsertOutcome::Unchanged,
+                before,
+                record,
+            });
+        };
+
+        let outcome = if row.get("inserted") {
+            UpsertOutcome::Created
+        } else {
+            UpsertOutcome::Updated
+        };
+        let mut group: GroupModel = (&row).into();
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_GROUP_ORGANIZERS_BY_GROUPID)
+            .await?;
+        transaction.execute(&stmt, &[&group.groupid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_ORGANIZERS).await?;
+        transaction
+            .execute(&stmt, &[&group.groupid, &organizerids])
+            .await?;
+
+        let stmt = transaction
+            .prepare(QUERY_DELETE_GROUP_CATEGORIES_BY_GROUPID)
+            .await?;
+        transaction.execute(&stmt, &[&group.groupid]).await?;
+
+        let stmt = transaction.prepare(QUERY_INSERT_GROUP_CATEGORIES).await?;
+        transaction
+            .execute(&stmt, &[&group.groupid, &categoryids])
+            .await?;
+
+        group.organizers = Self::find_organizers(&transaction, &group.groupid).await?;
+        group.categories = Self::find_categories(&transaction, &group.groupid).await?;
+
+        let upserted = UpsertModel {
+            outcome,
+            before,
+            record: group,
+        };
+        if let Some(audit_log) =
+            upserted.audit_log(actor.to_owned(), "group", upserted.record.groupid)
+        {
+            audit_log::record(&transaction, &audit_log).await?;
+        }
+        transaction.commit().await?;
+
+        Ok(upserted)
     }
 
-    async fn delete_by_groupid(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_GROUP_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
+    async fn delete_by_groupid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_groupid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_GROUP_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "group", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
         Ok(())
     }
 }
@@ -305,7 +745,8 @@ impl From<&Row> for GroupModel {
             private:    row.get("private"),
             members:    row.get("members"),
             cityid: row.get("cityid"),
-            organizer:  row.get("organizer"),
+            organizers: vec![],
+            categories: vec![],
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
             highres_link:   row.get("highres_link"),
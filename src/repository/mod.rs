@@ -1,9 +1,16 @@
+pub mod api_key;
+pub mod audit_log;
 pub mod categories;
 pub mod state;
 pub mod article;
 pub mod city;
 pub mod group;
+pub mod organizer;
 pub mod event;
+pub mod event_series;
+pub mod geo;
 pub mod health;
 pub mod postgres;
+pub mod rate_limit;
 pub mod redis;
+pub mod search;
@@ -0,0 +1,314 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use deadpool_postgres::{GenericClient, Pool};
+
+use tokio_postgres::{types::ToSql, Row};
+
+use crate::{
+    domain::{
+        audit_log::model::AuditLogCreateModel,
+        error::DomainError,
+        organizer::{
+            model::{OrganizerCreateModel, OrganizerModel, OrganizerUpdateModel},
+            repository::OrganizerRepository,
+        },
+    },
+    repository::audit_log,
+};
+
+const QUERY_FIND_ORGANIZER: &str = "
+    select
+        organizerid,
+        firstname,
+        lastname,
+        bio,
+        extid,
+        highres_link,
+        photo_link,
+        thumb_link,
+        created_at,
+        updated_at,
+        count(1) over ()::OID as count
+    from
+        organizer";
+
+const QUERY_FIND_ORGANIZER_BY_ID: &str = "
+    select
+        organizerid,
+        firstname,
+        lastname,
+        bio,
+        extid,
+        highres_link,
+        photo_link,
+        thumb_link,
+        created_at,
+        updated_at
+    from
+        organizer
+    where
+        organizerid = $1;";
+
+const QUERY_FIND_ORGANIZER_BY_EXTID: &str = "
+    select
+        organizerid,
+        firstname,
+        lastname,
+        bio,
+        extid,
+        highres_link,
+        photo_link,
+        thumb_link,
+        created_at,
+        updated_at
+    from
+        organizer
+    where
+        extid = $1;";
+
+const QUERY_INSERT_ORGANIZER: &str = "
+    insert into organizer(firstname,lastname,bio,extid,highres_link,photo_link,thumb_link)
+    values
+        ($1,$2,$3,$4,$5,$6,$7)
+    returning
+        organizerid,
+        firstname,
+        lastname,
+        bio,
+        extid,
+        highres_link,
+        photo_link,
+        thumb_link,
+        created_at,
+        updated_at;";
+
+const QUERY_UPDATE_ORGANIZER_BY_ID: &str = "
+    update
+        organizer
+    set
+        firstname=$2,
+        lastname=$3,
+        bio=$4,
+        highres_link=$5,
+        photo_link=$6,
+        thumb_link=$7,
+        updated_at=now()
+    where
+        organizerid = $1
+    returning
+        organizerid,
+        firstname,
+        lastname,
+        bio,
+        extid,
+        highres_link,
+        photo_link,
+        thumb_link,
+        created_at,
+        updated_at;";
+
+const QUERY_DELETE_ORGANIZER_BY_ID: &str = "
+            delete from
+                organizer
+            where
+                organizerid = $1;";
+
+pub struct PgOrganizerRepository {
+    pool: Arc<Pool>,
+}
+impl PgOrganizerRepository {
+    pub fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+
+    async fn find_by_organizerid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<OrganizerModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_ORGANIZER_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            return Ok(Some((&result).into()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl OrganizerRepository for PgOrganizerRepository {
+    async fn find(
+        &self,
+        name: &Option<String>,
+        page: &u32,
+        page_size: &u32,
+    ) -> Result<Option<(Vec<OrganizerModel>, u32)>, DomainError> {
+        let client = self.pool.get().await?;
+
+        let mut queries: Vec<String> = vec![];
+        let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
+
+        if let Some(name) = name {
+            queries.push(format!(
+                "(organizer.firstname || ' ' || organizer.lastname) like '%' || ${} || '%'",
+                params.len() + 1
+            ));
+            params.push(name);
+        }
+
+        let mut query = String::from(QUERY_FIND_ORGANIZER);
+        if !queries.is_empty() {
+            query = format!("{} where {}", query, queries.join(" and "));
+        }
+
+        let offset = page_size * (page - 1);
+        query = format!("{query} limit {page_size} offset {offset}");
+
+        let stmt = client.prepare(&query).await?;
+        let result = client.query(&stmt, &params[..]).await?;
+
+        if !result.is_empty() {
+            let count: u32 = result.first().unwrap().get("count");
+
+            let organizers: Vec<OrganizerModel> = result.iter().map(|row| row.into()).collect();
+
+            return Ok(Some((organizers, count)));
+        }
+
+        return Ok(None);
+    }
+
+    async fn find_by_organizerid(&self, id: &i32) -> Result<Option<OrganizerModel>, DomainError> {
+        let client = self.pool.get().await?;
+        Self::find_by_organizerid_in(&client, id).await
+    }
+
+    async fn find_by_extid(&self, extid: String) -> Result<Option<OrganizerModel>, DomainError> {
+        let client = self.pool.get().await?;
+        let stmt = client.prepare(QUERY_FIND_ORGANIZER_BY_EXTID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&extid]).await? {
+            return Ok(Some((&result).into()));
+        }
+
+        return Ok(None);
+    }
+
+    async fn insert(
+        &self,
+        organizer_create_model: &OrganizerCreateModel,
+        actor: &str,
+    ) -> Result<OrganizerModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_ORGANIZER).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    &organizer_create_model.firstname,
+                    &organizer_create_model.lastname,
+                    &organizer_create_model.bio,
+                    &organizer_create_model.extid,
+                    &organizer_create_model.highres_link,
+                    &organizer_create_model.photo_link,
+                    &organizer_create_model.thumb_link,
+                ],
+            )
+            .await?;
+        let organizer: OrganizerModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(
+                actor.to_owned(),
+                "organizer",
+                organizer.organizerid,
+                &organizer,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(organizer)
+    }
+
+    async fn update_by_organizerid(
+        &self,
+        organizerid: &i32,
+        organizer_update_model: &OrganizerUpdateModel,
+        actor: &str,
+    ) -> Result<OrganizerModel, DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_organizerid_in(&transaction, organizerid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_ORGANIZER_BY_ID).await?;
+        let result = &transaction
+            .query_one(
+                &stmt,
+                &[
+                    organizerid,
+                    &organizer_update_model.firstname,
+                    &organizer_update_model.lastname,
+                    &organizer_update_model.bio,
+                    &organizer_update_model.highres_link,
+                    &organizer_update_model.photo_link,
+                    &organizer_update_model.thumb_link,
+                ],
+            )
+            .await?;
+        let organizer: OrganizerModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(
+                actor.to_owned(),
+                "organizer",
+                *organizerid,
+                &before,
+                &organizer,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(organizer)
+    }
+
+    async fn delete_by_organizerid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let before = Self::find_by_organizerid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_ORGANIZER_BY_ID).await?;
+        transaction.execute(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "organizer", *id, &before),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl From<&Row> for OrganizerModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            organizerid: row.get("organizerid"),
+            firstname: row.get("firstname"),
+            lastname: row.get("lastname"),
+            bio: row.get("bio"),
+            extid: row.get("extid"),
+            highres_link: row.get("highres_link"),
+            photo_link: row.get("photo_link"),
+            thumb_link: row.get("thumb_link"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+}
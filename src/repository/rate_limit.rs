@@ -0,0 +1,102 @@
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use rand::distributions::{Alphanumeric, DistString};
+use redis::Client;
+
+use crate::domain::{
+    error::DomainError,
+    rate_limit::{model::RateLimitModel, repository::RateLimitRepository},
+};
+
+pub struct RedisRateLimitRepository {
+    redis_client: Arc<Client>,
+}
+impl RedisRateLimitRepository {
+    pub fn new(redis_client: Arc<Client>) -> Self {
+        Self { redis_client }
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for RedisRateLimitRepository {
+    async fn hit(
+        &self,
+        key: String,
+        limit: u32,
+        window_ms: u64,
+    ) -> Result<RateLimitModel, DomainError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| DomainError::InternalServerError(err.to_string()))?
+            .as_millis() as u64;
+        let window_start = now.saturating_sub(window_ms);
+
+        let member = format!(
+            "{now}-{}",
+            Alphanumeric.sample_string(&mut rand::thread_rng(), 8)
+        );
+
+        // Sliding window: drop hits older than the window and record this one
+        // before counting, in one transaction, so concurrent requests each
+        // see the others' hits and no more than `limit` of them get in.
+        let (count, oldest): (u32, Vec<(String, u64)>) = redis::pipe()
+            .atomic()
+            .cmd("ZREMRANGEBYSCORE")
+            .arg(&key)
+            .arg(0)
+            .arg(window_start)
+            .ignore()
+            .cmd("ZADD")
+            .arg(&key)
+            .arg(now)
+            .arg(&member)
+            .ignore()
+            .cmd("PEXPIRE")
+            .arg(&key)
+            .arg(window_ms)
+            .ignore()
+            .cmd("ZCARD")
+            .arg(&key)
+            .cmd("ZRANGE")
+            .arg(&key)
+            .arg(0)
+            .arg(0)
+            .arg("WITHSCORES")
+            .query_async(&mut conn)
+            .await?;
+
+        let reset_after_ms = oldest
+            .first()
+            .map(|(_, score)| (score + window_ms).saturating_sub(now))
+            .unwrap_or(window_ms);
+
+        if count > limit {
+            // Rejected hits do not count against the window.
+            redis::cmd("ZREM")
+                .arg(&key)
+                .arg(&member)
+                .query_async::<_, ()>(&mut conn)
+                .await?;
+
+            return Ok(RateLimitModel {
+                allowed: false,
+                limit,
+                remaining: 0,
+                reset_after_ms,
+            });
+        }
+
+        Ok(RateLimitModel {
+            allowed: true,
+            limit,
+            remaining: limit - count,
+            reset_after_ms,
+        })
+    }
+}
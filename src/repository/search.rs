@@ -0,0 +1,70 @@
+/// Builds a filter matching rows whose `column` contains the text bound to
+/// the given parameter, ignoring case and accents. Fuzzy filters also match
+/// rows holding a word similar to it, so misspelt names are still found.
+/// Both forms are served by the trigram indexes on `f_unaccent(name)`.
+pub fn name_matches(column: &str, param: usize, fuzzy: bool) -> String {
+    let contains = format!("f_unaccent({column}) ilike '%' || f_unaccent(${param}) || '%'");
+    if fuzzy {
+        format!("({contains} or f_unaccent(${param}) <% f_unaccent({column}))")
+    } else {
+        contains
+    }
+}
+
+/// Builds an order by term ranking the rows most similar to the text bound
+/// to the given parameter first.
+pub fn name_rank(column: &str, param: usize) -> String {
+    format!("word_similarity(f_unaccent(${param}), f_unaccent({column})) desc")
+}
+
+/// `ts_headline` options: up to two fragments of the description, with the
+/// matched words wrapped in `<mark>`.
+const HEADLINE_OPTIONS: &str =
+    "StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=30, MinWords=10";
+
+fn text_query(config: &str, param: usize) -> String {
+    format!("websearch_to_tsquery('{config}', ${param})")
+}
+
+/// Builds a filter matching rows whose `search_en` or `search_fr` vector, of
+/// the table aliased `table`, matches the web search syntax query bound to
+/// the given parameter.
+pub fn text_matches(table: &str, param: usize) -> String {
+    format!(
+        "({table}.search_en @@ {} or {table}.search_fr @@ {})",
+        text_query("english", param),
+        text_query("french", param)
+    )
+}
+
+fn text_ranks(table: &str, param: usize) -> (String, String) {
+    (
+        format!("ts_rank({table}.search_en, {})", text_query("english", param)),
+        format!("ts_rank({table}.search_fr, {})", text_query("french", param)),
+    )
+}
+
+/// Builds an order by term ranking the best full-text matches first, in
+/// whichever language matches best.
+pub fn text_rank(table: &str, param: usize) -> String {
+    let (rank_en, rank_fr) = text_ranks(table, param);
+    format!("greatest({rank_en}, {rank_fr}) desc")
+}
+
+/// Builds an expression highlighting the matches of the query in the
+/// `description` of the table aliased `table`, parsed in the language that
+/// matches best. Null when the description is empty.
+pub fn text_snippet(table: &str, param: usize) -> String {
+    let (rank_en, rank_fr) = text_ranks(table, param);
+    let headline = |config: &str| {
+        format!(
+            "ts_headline('{config}', coalesce({table}.description, ''), {}, '{HEADLINE_OPTIONS}')",
+            text_query(config, param)
+        )
+    };
+    format!(
+        "nullif(case when {rank_fr} > {rank_en} then {} else {} end, '')",
+        headline("french"),
+        headline("english")
+    )
+}
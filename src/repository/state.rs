@@ -1,12 +1,17 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use deadpool_postgres::Pool;
+use deadpool_postgres::{GenericClient, Pool};
+use redis::{AsyncCommands, Client};
 
 use tokio_postgres::{types::ToSql, Row};
+use crate::repository::{audit_log, search};
 use crate::domain::{
+    audit_log::model::AuditLogCreateModel,
     state::{
-        model::{StateCreateModel, StateModel, StateUpdateModel},
+        model::{
+            StateCityModel, StateCreateModel, StateDetailModel, StateModel, StateUpdateModel,
+        },
         repository::StateRepository,
     },
     error::DomainError,
@@ -44,6 +49,47 @@ const QUERY_FIND_STATE_BY_ID: &str = "
     where 
         stateid = $1;";
 
+const QUERY_FIND_STATE_BY_SYMBOL: &str = "
+    select
+        stateid,
+        name,
+        symbol,
+        highres_link,
+        photo_link,
+        thumb_link
+    from
+        state
+    where
+        lower(symbol) = lower($1);";
+
+const QUERY_FIND_CITIES_BY_STATEID: &str = "
+    select
+        c.cityid,
+        c.name,
+        c.slug,
+        c.highres_link,
+        c.photo_link,
+        c.thumb_link,
+        (
+            select count(1) from \"group\" g where g.cityid = c.cityid
+        )::OID as group_count,
+        (
+            select
+                count(1)
+            from
+                event e
+            join \"group\" g using(groupid)
+            where
+                g.cityid = c.cityid
+                and e.time >= now()
+        )::OID as upcoming_event_count
+    from
+        city c
+    where
+        c.stateid = $1
+    order by
+        c.name;";
+
 const QUERY_INSERT_STATE: &str = "
     insert into state(name,symbol,extid,highres_link,photo_link,thumb_link)
     values
@@ -86,14 +132,42 @@ const QUERY_DELETE_STATE_BY_ID: &str = "
             delete from
                 state 
             where
-                stateid = $1;";
+                stateid = $1
+            returning
+                symbol;";
+
+const CACHE_TTL_SECONDS: usize = 60 * 5;
 
 pub struct PgStateRepository {
     pool: Arc<Pool>,
+    redis_client: Arc<Client>,
 }
 impl PgStateRepository {
-    pub fn new(pool: Arc<Pool>) -> Self {
-        Self { pool }
+    pub fn new(pool: Arc<Pool>, redis_client: Arc<Client>) -> Self {
+        Self { pool, redis_client }
+    }
+
+    fn cache_key(symbol: &str) -> String {
+        format!("state:{}", symbol.to_lowercase())
+    }
+
+    async fn invalidate_cache(&self, symbol: &str) -> Result<(), DomainError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+        let _: () = conn.del(Self::cache_key(symbol)).await?;
+        Ok(())
+    }
+
+    async fn find_by_stateid_in<C: GenericClient>(
+        client: &C,
+        id: &i32,
+    ) -> Result<Option<StateModel>, DomainError> {
+        let stmt = client.prepare(QUERY_FIND_STATE_BY_ID).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
+            return Ok(Some((&result).into()));
+        }
+
+        Ok(None)
     }
 }
 
@@ -102,6 +176,7 @@ impl StateRepository for PgStateRepository {
     async fn find(
         &self,
         name: &Option<String>,
+        fuzzy: &bool,
         page: &u32,
         page_size: &u32,
     ) -> Result<Option<(Vec<StateModel>, u32)>, DomainError> {
@@ -110,11 +185,12 @@ impl StateRepository for PgStateRepository {
         let mut queries: Vec<String> = vec![];
         let mut params: Vec<&(dyn ToSql + Sync)> = Vec::new();
 
+        let mut rank = None;
         if let Some(name) = name {
-            queries.push(format!(
-                "state.name like '%' || ${} || '%'",
-                params.len() + 1
-            ));
+            queries.push(search::name_matches("state.name", params.len() + 1, *fuzzy));
+            if *fuzzy {
+                rank = Some(search::name_rank("state.name", params.len() + 1));
+            }
             params.push(name);
         }
 
@@ -123,6 +199,10 @@ impl StateRepository for PgStateRepository {
             query = format!("{} where {}", query, queries.join(" and "));
         }
 
+        if let Some(rank) = rank {
+            query = format!("{query} order by {rank}");
+        }
+
         let offset = page_size * (page - 1);
         query = format!("{query} limit {page_size} offset {offset}");
 
@@ -142,22 +222,62 @@ impl StateRepository for PgStateRepository {
 
     async fn find_by_stateid(&self, id: &i32) -> Result<Option<StateModel>, DomainError> {
         let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_FIND_STATE_BY_ID).await?;
+        Self::find_by_stateid_in(&client, id).await
+    }
 
-        if let Some(result) = client.query_opt(&stmt, &[id]).await? {
-            return Ok(Some((&result).into()));
+    async fn find_by_symbol(&self, symbol: String) -> Result<Option<StateDetailModel>, DomainError> {
+        let mut conn = self.redis_client.get_async_connection().await?;
+        let cache_key = Self::cache_key(&symbol);
+        let state: Option<String> = conn.get(&cache_key).await?;
+
+        if let Some(serialized_state) = state {
+            let state_detail: StateDetailModel = serde_json::from_str(&serialized_state)?;
+            return Ok(Some(state_detail));
         }
 
-        return Ok(None);
+        let client = self.pool.get().await?;
+        let stmt = client.prepare(QUERY_FIND_STATE_BY_SYMBOL).await?;
+
+        if let Some(result) = client.query_opt(&stmt, &[&symbol]).await? {
+            let stateid: i32 = result.get("stateid");
+
+            let stmt = client.prepare(QUERY_FIND_CITIES_BY_STATEID).await?;
+            let cities = client
+                .query(&stmt, &[&stateid])
+                .await?
+                .iter()
+                .map(|row| row.into())
+                .collect();
+
+            let state_detail = StateDetailModel {
+                stateid,
+                name: result.get("name"),
+                symbol: result.get("symbol"),
+                highres_link: result.get("highres_link"),
+                photo_link: result.get("photo_link"),
+                thumb_link: result.get("thumb_link"),
+                cities,
+            };
+
+            let _: () = conn
+                .set_ex(cache_key, serde_json::to_string(&state_detail)?, CACHE_TTL_SECONDS)
+                .await?;
+
+            return Ok(Some(state_detail));
+        }
+
+        Ok(None)
     }
 
     async fn insert(
         &self,
         state_create_model: &StateCreateModel,
+        actor: &str,
     ) -> Result<StateModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_INSERT_STATE).await?;
-        let result = &client
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let stmt = transaction.prepare(QUERY_INSERT_STATE).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -170,19 +290,32 @@ impl StateRepository for PgStateRepository {
                 ],
             )
             .await?;
+        let state: StateModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::created(actor.to_owned(), "state", state.stateid, &state),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        self.invalidate_cache(&state_create_model.symbol).await?;
 
-        Ok(result.into())
+        Ok(state)
     }
 
     async fn update_by_stateid(
         &self,
         stateid: &i32,
         state_update_model: &StateUpdateModel,
+        actor: &str,
     ) -> Result<StateModel, DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_UPDATE_STATE_BY_ID).await?;
-        let result = &client
-        
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let previous_state = Self::find_by_stateid_in(&transaction, stateid).await?;
+
+        let stmt = transaction.prepare(QUERY_UPDATE_STATE_BY_ID).await?;
+        let result = &transaction
             .query_one(
                 &stmt,
                 &[
@@ -195,14 +328,49 @@ impl StateRepository for PgStateRepository {
                 ],
             )
             .await?;
+        let state: StateModel = result.into();
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::updated(
+                actor.to_owned(),
+                "state",
+                *stateid,
+                &previous_state,
+                &state,
+            ),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        if let Some(previous_state) = previous_state {
+            self.invalidate_cache(&previous_state.symbol).await?;
+        }
+        self.invalidate_cache(&state_update_model.symbol).await?;
 
-        Ok(result.into())
+        Ok(state)
     }
 
-    async fn delete_by_stateid(&self, id: &i32) -> Result<(), DomainError> {
-        let client = self.pool.get().await?;
-        let stmt = client.prepare(QUERY_DELETE_STATE_BY_ID).await?;
-        client.execute(&stmt, &[id]).await?;
+    async fn delete_by_stateid(&self, id: &i32, actor: &str) -> Result<(), DomainError> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+        let previous_state = Self::find_by_stateid_in(&transaction, id).await?;
+
+        let stmt = transaction.prepare(QUERY_DELETE_STATE_BY_ID).await?;
+        let result = transaction.query_opt(&stmt, &[id]).await?;
+
+        audit_log::record(
+            &transaction,
+            &AuditLogCreateModel::deleted(actor.to_owned(), "state", *id, &previous_state),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        if let Some(result) = result {
+            let symbol: String = result.get("symbol");
+            self.invalidate_cache(&symbol).await?;
+        }
+
         Ok(())
     }
 }
@@ -222,3 +390,18 @@ impl From<&Row> for StateModel {
         }
     }
 }
+
+impl From<&Row> for StateCityModel {
+    fn from(row: &Row) -> Self {
+        Self {
+            cityid: row.get("cityid"),
+            name: row.get("name"),
+            slug: row.get("slug"),
+            highres_link: row.get("highres_link"),
+            photo_link: row.get("photo_link"),
+            thumb_link: row.get("thumb_link"),
+            group_count: row.get("group_count"),
+            upcoming_event_count: row.get("upcoming_event_count"),
+        }
+    }
+}